@@ -23,7 +23,7 @@
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, Env};
 
 // ============================================================================
 // Contract Initialization Event
@@ -113,7 +113,7 @@ pub fn emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
 /// ```rust
 /// // Lock 1000 XLM for bounty #42, deadline in 30 days
 /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60);
-/// escrow_client.lock_funds(&depositor, &42, &10_000_000_000, &deadline);
+/// escrow_client.lock_funds(&depositor, &42, &10_000_000_000, &deadline, &xlm_address);
 /// // → Emits FundsLocked event
 /// ```
 #[contracttype]
@@ -153,10 +153,11 @@ pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
 /// * `amount` - Amount transferred to recipient
 /// * `recipient` - Address receiving the funds (contributor)
 /// * `timestamp` - Unix timestamp of release
+/// * `receipt_id` - Off-chain invoice reference supplied with the release, if any
 ///
 /// # Event Topic
 /// Symbol: `f_rel`
-/// Indexed: `bounty_id`
+/// Indexed: `bounty_id`, `recipient`
 ///
 /// # State Transition
 /// ```text
@@ -181,7 +182,7 @@ pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
 /// # Example Usage
 /// ```rust
 /// // Admin releases 1000 XLM to contributor for bounty #42
-/// escrow_client.release_funds(&42, &contributor_address);
+/// escrow_client.release_funds(&42, &contributor_address, &None);
 /// // → Transfers tokens
 /// // → Updates state to Released
 /// // → Emits FundsReleased event
@@ -193,6 +194,7 @@ pub struct FundsReleased {
     pub amount: i128,
     pub recipient: Address,
     pub timestamp: u64,
+    pub receipt_id: Option<Bytes>,
 }
 
 /// Emits a FundsReleased event.
@@ -202,10 +204,18 @@ pub struct FundsReleased {
 /// * `event` - The funds released event data
 ///
 /// # Event Structure
-/// Topic: `(symbol_short!("f_rel"), event.bounty_id)`
+/// Topic: `(symbol_short!("f_rel"), event.bounty_id, event.recipient)`
 /// Data: Complete `FundsReleased` struct
+///
+/// The recipient topic lets contributors subscribe to every release paying
+/// them. Indexers matching on the older two-element topic must accept the
+/// third element.
 pub fn emit_funds_released(env: &Env, event: FundsReleased) {
-    let topics = (symbol_short!("f_rel"), event.bounty_id);
+    let topics = (
+        symbol_short!("f_rel"),
+        event.bounty_id,
+        event.recipient.clone(),
+    );
     env.events().publish(topics, event.clone());
 }
 
@@ -349,3 +359,170 @@ pub fn emit_batch_funds_released(env: &Env, event: BatchFundsReleased) {
     let topics = (symbol_short!("b_rel"),);
     env.events().publish(topics, event.clone());
 }
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FundsToppedUp {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub new_amount: i128,
+    pub funder: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_funds_topped_up(env: &Env, event: FundsToppedUp) {
+    let topics = (symbol_short!("f_top"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DeadlineExtended {
+    pub bounty_id: u64,
+    pub old_deadline: u64,
+    pub new_deadline: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_deadline_extended(env: &Env, event: DeadlineExtended) {
+    let topics = (symbol_short!("dl_ext"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountySplit {
+    pub source_id: u64,
+    pub new_id: u64,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_split(env: &Env, event: BountySplit) {
+    let topics = (symbol_short!("split"), event.source_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountiesMerged {
+    pub target_id: u64,
+    pub source_id: u64,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounties_merged(env: &Env, event: BountiesMerged) {
+    let topics = (symbol_short!("merge"), event.target_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StatusRepaired {
+    pub bounty_id: u64,
+    pub old_status: crate::EscrowStatus,
+    pub new_status: crate::EscrowStatus,
+    pub timestamp: u64,
+}
+
+pub fn emit_status_repaired(env: &Env, event: StatusRepaired) {
+    let topics = (symbol_short!("repair"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DepositorReassigned {
+    pub bounty_id: u64,
+    pub old_depositor: Address,
+    pub new_depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_depositor_reassigned(env: &Env, event: DepositorReassigned) {
+    let topics = (symbol_short!("reassign"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseRequested {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub executable_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_requested(env: &Env, event: ReleaseRequested) {
+    let topics = (symbol_short!("rel_req"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseObjected {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_objected(env: &Env, event: ReleaseObjected) {
+    let topics = (symbol_short!("rel_obj"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseResolved {
+    pub bounty_id: u64,
+    pub approved: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_resolved(env: &Env, event: ReleaseResolved) {
+    let topics = (symbol_short!("rel_res"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PauseChanged {
+    pub paused: bool,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_pause_changed(env: &Env, event: PauseChanged) {
+    let topics = (symbol_short!("pause"),);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EmergencyWithdrawal {
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_emergency_withdrawal(env: &Env, event: EmergencyWithdrawal) {
+    let topics = (symbol_short!("em_wd"),);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EscrowCancelled {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub refunded: i128,
+    pub penalty: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_cancelled(env: &Env, event: EscrowCancelled) {
+    let topics = (symbol_short!("cancel"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
@@ -0,0 +1,64 @@
+//! # Bounty Escrow Invariants
+//!
+//! Accounting checks that must hold after every state-changing call. They are
+//! compiled for this crate's unit tests and, with the `testutils` feature, for
+//! integration tests in other crates.
+//!
+//! ## Checked Invariants
+//!
+//! - `0 <= remaining_amount <= amount` for every escrow
+//! - `Locked` escrows have paid nothing out: `remaining_amount == amount`
+//! - `PartiallyReleased` / `PartiallyRefunded` escrows have paid something out
+//! - `Released` / `Refunded` escrows are empty
+//! - No reentrancy guard is left set between calls
+
+use crate::{registry, DataKey, Escrow, EscrowStatus};
+use soroban_sdk::{vec, Address, Env, Vec};
+
+/// Panics with the offending bounty ID on the first violated invariant.
+///
+/// Must run in the contract's storage context; use
+/// `assert_contract_invariants` from outside the contract.
+pub fn assert_invariants(env: &Env) {
+    assert!(
+        !env.storage().instance().has(&DataKey::ReentrancyGuard),
+        "reentrancy guard left set"
+    );
+
+    let mut bounty_ids: Vec<u64> = vec![env];
+    for page in 0..registry::page_count(env) {
+        bounty_ids.append(&registry::page(env, page));
+    }
+    for bounty_id in bounty_ids.iter() {
+        let escrow: Escrow = match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+            Some(escrow) => escrow,
+            None => continue,
+        };
+
+        assert!(
+            escrow.remaining_amount >= 0 && escrow.remaining_amount <= escrow.amount,
+            "bounty {}: remaining_amount {} outside 0..={}",
+            bounty_id,
+            escrow.remaining_amount,
+            escrow.amount
+        );
+
+        let consistent = match escrow.status {
+            EscrowStatus::Locked => escrow.remaining_amount == escrow.amount,
+            EscrowStatus::PartiallyReleased | EscrowStatus::PartiallyRefunded => {
+                escrow.remaining_amount < escrow.amount
+            }
+            EscrowStatus::Released | EscrowStatus::Refunded => escrow.remaining_amount == 0,
+        };
+        assert!(
+            consistent,
+            "bounty {}: status {:?} inconsistent with remaining_amount {} of {}",
+            bounty_id, escrow.status, escrow.remaining_amount, escrow.amount
+        );
+    }
+}
+
+/// Runs `assert_invariants` against the escrow contract registered at `contract_id`.
+pub fn assert_contract_invariants(env: &Env, contract_id: &Address) {
+    env.as_contract(contract_id, || assert_invariants(env));
+}
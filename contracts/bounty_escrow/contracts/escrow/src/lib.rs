@@ -73,11 +73,11 @@
 //! let depositor = Address::from_string("GDEPOSIT...");
 //! let amount = 1000_0000000; // 1000 USDC (7 decimals)
 //! let deadline = current_timestamp + (30 * 24 * 60 * 60); // 30 days
-//! escrow_client.lock_funds(&depositor, &42, &amount, &deadline);
+//! escrow_client.lock_funds(&depositor, &42, &amount, &deadline, &token);
 //!
 //! // 3a. Admin releases to contributor (happy path)
 //! let contributor = Address::from_string("GCONTRIB...");
-//! escrow_client.release_funds(&42, &contributor);
+//! escrow_client.release_funds(&42, &contributor, &None);
 //!
 //! // OR
 //!
@@ -88,16 +88,20 @@
 
 #![no_std]
 mod events;
+#[cfg(any(test, feature = "testutils"))]
+pub mod invariants;
 mod test_bounty_escrow;
 
 use events::{
-    emit_batch_funds_locked, emit_batch_funds_released, emit_bounty_initialized, emit_funds_locked,
-    emit_funds_refunded, emit_funds_released, BatchFundsLocked, BatchFundsReleased,
-    BountyEscrowInitialized, FundsLocked, FundsRefunded, FundsReleased,
+    emit_batch_funds_locked, emit_batch_funds_released, emit_bounties_merged,
+    emit_bounty_initialized, emit_bounty_split, emit_funds_locked, emit_funds_refunded,
+    emit_funds_released, emit_funds_topped_up, BatchFundsLocked, BatchFundsReleased,
+    BountiesMerged, BountyEscrowInitialized, BountySplit, FundsLocked, FundsRefunded,
+    FundsReleased, FundsToppedUp,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
 };
 
 // ==================== MONITORING MODULE ====================
@@ -108,6 +112,12 @@ mod monitoring {
     const OPERATION_COUNT: &str = "op_count";
     const USER_COUNT: &str = "usr_count";
     const ERROR_COUNT: &str = "err_count";
+    const USER_SEEN: &str = "user_seen";
+
+    // Ledgers a seen-user flag is extended to (~180 days at 5s per ledger)
+    const USER_SEEN_TTL: u32 = 3_110_400;
+    // Remaining ledgers below which the flag is extended again
+    const USER_SEEN_TTL_THRESHOLD: u32 = USER_SEEN_TTL / 2;
 
     // Event: Operation metric
     #[contracttype]
@@ -124,7 +134,7 @@ mod monitoring {
     #[derive(Clone, Debug)]
     pub struct PerformanceMetric {
         pub function: Symbol,
-        pub duration: u64,
+        pub call_count: u64,
         pub timestamp: u64,
     }
 
@@ -159,13 +169,16 @@ mod monitoring {
     }
 
     // Data: Performance stats
+    //
+    // The ledger timestamp is fixed for the whole transaction and contracts
+    // cannot read their own resource usage on-chain, so no duration is
+    // tracked. `call_count` counts completed calls and `last_called` is the
+    // ledger timestamp (seconds) of the most recent one.
     #[contracttype]
     #[derive(Clone, Debug)]
     pub struct PerformanceStats {
         pub function_name: Symbol,
         pub call_count: u64,
-        pub total_time: u64,
-        pub avg_time: u64,
         pub last_called: u64,
     }
 
@@ -181,6 +194,8 @@ mod monitoring {
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
 
+        track_user(env, &caller);
+
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
@@ -192,25 +207,41 @@ mod monitoring {
         );
     }
 
-    // Track performance
-    pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
-        let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
-        let time_key = (Symbol::new(env, "perf_time"), function.clone());
+    // Track unique user: one flag per address, counted the first time it is seen
+    pub fn track_user(env: &Env, caller: &Address) {
+        let seen_key = (Symbol::new(env, USER_SEEN), caller.clone());
+        if !env.storage().persistent().has(&seen_key) {
+            env.storage().persistent().set(&seen_key, &true);
 
-        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
-        let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
+            let usr_key = Symbol::new(env, USER_COUNT);
+            let users: u64 = env.storage().persistent().get(&usr_key).unwrap_or(0);
+            env.storage().persistent().set(&usr_key, &(users + 1));
+        }
 
-        env.storage().persistent().set(&count_key, &(count + 1));
+        // Keep the flag live while the user is active; once archived it has to
+        // be restored before any of this user's operations can run
         env.storage()
             .persistent()
-            .set(&time_key, &(total + duration));
+            .extend_ttl(&seen_key, USER_SEEN_TTL_THRESHOLD, USER_SEEN_TTL);
+    }
+
+    // Track performance
+    pub fn emit_performance(env: &Env, function: Symbol) {
+        let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
+        let last_key = (Symbol::new(env, "perf_last"), function.clone());
+
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0) + 1;
+        let now = env.ledger().timestamp();
+
+        env.storage().persistent().set(&count_key, &count);
+        env.storage().persistent().set(&last_key, &now);
 
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
                 function,
-                duration,
-                timestamp: env.ledger().timestamp(),
+                call_count: count,
+                timestamp: now,
             },
         );
     }
@@ -269,21 +300,12 @@ mod monitoring {
     // Get performance stats
     pub fn get_performance_stats(env: &Env, function_name: Symbol) -> PerformanceStats {
         let count_key = (Symbol::new(env, "perf_cnt"), function_name.clone());
-        let time_key = (Symbol::new(env, "perf_time"), function_name.clone());
         let last_key = (Symbol::new(env, "perf_last"), function_name.clone());
 
-        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
-        let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
-        let last: u64 = env.storage().persistent().get(&last_key).unwrap_or(0);
-
-        let avg = if count > 0 { total / count } else { 0 };
-
         PerformanceStats {
             function_name,
-            call_count: count,
-            total_time: total,
-            avg_time: avg,
-            last_called: last,
+            call_count: env.storage().persistent().get(&count_key).unwrap_or(0),
+            last_called: env.storage().persistent().get(&last_key).unwrap_or(0),
         }
     }
 }
@@ -291,7 +313,8 @@ mod monitoring {
 
 // ==================== ANTI-ABUSE MODULE ====================
 mod anti_abuse {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+    use crate::Error;
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -359,9 +382,16 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
-    pub fn check_rate_limit(env: &Env, address: Address) {
+    /// Enforces the cooldown and window limits for `address`.
+    ///
+    /// `operation` names the throttled entry point; it is published with the
+    /// abuse events so operators can tell which operation is being throttled.
+    ///
+    /// Returns `Error::InCooldown` or `Error::RateLimited` without touching
+    /// the address state when the operation is rejected.
+    pub fn check_rate_limit(env: &Env, address: Address, operation: Symbol) -> Result<(), Error> {
         if is_whitelisted(env, address.clone()) {
-            return;
+            return Ok(());
         }
 
         let config = get_config(env);
@@ -387,9 +417,9 @@ mod anti_abuse {
         {
             env.events().publish(
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
-                (address.clone(), now),
+                (address.clone(), operation.clone(), now),
             );
-            panic!("Operation in cooldown period");
+            return Err(Error::InCooldown);
         }
 
         // 2. Window check
@@ -406,9 +436,9 @@ mod anti_abuse {
             if state.operation_count >= config.max_operations {
                 env.events().publish(
                     (symbol_short!("abuse"), symbol_short!("limit")),
-                    (address.clone(), now),
+                    (address.clone(), operation.clone(), now),
                 );
-                panic!("Rate limit exceeded");
+                return Err(Error::RateLimited);
             }
             state.operation_count += 1;
         }
@@ -418,10 +448,81 @@ mod anti_abuse {
 
         // Extend TTL for state (approx 1 day)
         env.storage().persistent().extend_ttl(&key, 17280, 17280);
+
+        Ok(())
     }
 }
 // ==================== END ANTI-ABUSE MODULE ====================
 
+// ==================== REGISTRY MODULE ====================
+// Every bounty ID ever locked, in lock order, split across fixed-size pages so
+// no single storage entry grows with the number of bounties. A counter holds
+// the length and a per-ID position index makes registration idempotent.
+mod registry {
+    use crate::DataKey;
+    use soroban_sdk::{vec, Env, Vec};
+
+    // Bounty IDs stored per `DataKey::BountyRegistryPage` entry
+    pub const PAGE_SIZE: u32 = 100;
+
+    /// Number of registered bounty IDs.
+    pub fn len(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::BountyRegistryLen)
+            .unwrap_or(0)
+    }
+
+    /// Number of pages holding registered bounty IDs.
+    pub fn page_count(env: &Env) -> u32 {
+        len(env).div_ceil(PAGE_SIZE)
+    }
+
+    /// Bounty IDs stored on registry page `page`.
+    pub fn page(env: &Env, page: u32) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BountyRegistryPage(page))
+            .unwrap_or(vec![env])
+    }
+
+    /// Bounty IDs at registry positions `start..end`, clamped to the registry length.
+    pub fn range(env: &Env, start: u32, end: u32) -> Vec<u64> {
+        let end = end.min(len(env));
+        let mut ids = vec![env];
+        let mut position = start;
+        while position < end {
+            let ids_on_page = page(env, position / PAGE_SIZE);
+            let page_end = (position / PAGE_SIZE + 1) * PAGE_SIZE;
+            while position < end && position < page_end {
+                ids.push_back(ids_on_page.get(position % PAGE_SIZE).unwrap());
+                position += 1;
+            }
+        }
+        ids
+    }
+
+    /// Appends `bounty_id` to the registry. An ID that is already registered,
+    /// e.g. one reused after its escrow was merged away, keeps its slot.
+    pub fn register(env: &Env, bounty_id: u64) {
+        let position_key = DataKey::RegistryPosition(bounty_id);
+        if env.storage().persistent().has(&position_key) {
+            return;
+        }
+
+        let position = len(env);
+        let page_key = DataKey::BountyRegistryPage(position / PAGE_SIZE);
+        let mut ids = page(env, position / PAGE_SIZE);
+        ids.push_back(bounty_id);
+        env.storage().persistent().set(&page_key, &ids);
+        env.storage().persistent().set(&position_key, &position);
+        env.storage()
+            .instance()
+            .set(&DataKey::BountyRegistryLen, &(position + 1));
+    }
+}
+// ==================== END REGISTRY MODULE ====================
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -459,8 +560,115 @@ pub enum Error {
     InsufficientFunds = 16,
     /// Returned when refund is attempted without admin approval
     RefundNotApproved = 17,
+    /// Returned when a bounty is not tagged to the requested program
+    ProgramMismatch = 18,
+    /// Returned when tagging a bounty that is already linked to a program
+    BountyAlreadyTagged = 19,
+    /// Returned when escrow metadata exceeds the configured size limits
+    MetadataTooLarge = 20,
+    /// Returned when an emergency quorum configuration is missing or invalid
+    InvalidQuorum = 21,
+    /// Returned when emergency withdrawal lacks the required quorum approvals
+    InsufficientApprovals = 22,
+    /// Returned when a refund approval is older than the approval expiry window
+    RefundApprovalExpired = 23,
+    /// Returned when the token address does not behave like a token contract
+    InvalidToken = 24,
+    /// Returned when a release would exceed the bounty's per-window release limit
+    ReleaseLimitExceeded = 25,
+    /// Returned when a release percentage exceeds 10000 basis points
+    InvalidPercentage = 26,
+    /// Returned when a bounty already has a release awaiting confirmation
+    ReleasePending = 27,
+    /// Returned when a bounty has no release awaiting confirmation
+    PendingReleaseNotFound = 28,
+    /// Returned when a pending release is executed before its confirmation delay
+    ReleaseNotReady = 29,
+    /// Returned when the depositor objected to a pending release
+    ReleaseObjected = 30,
+    /// Returned when releasing a bounty before its release cliff
+    ReleaseLocked = 31,
+    /// Returned when releasing to someone other than the pre-registered contributor
+    ContributorMismatch = 32,
+    /// Returned when locking, releasing or refunding while the contract is paused
+    ContractPaused = 33,
+    /// Returned when an emergency withdrawal is attempted without pausing first
+    ContractNotPaused = 34,
+    /// Returned when merging escrows denominated in different tokens
+    TokenMismatch = 35,
+    /// Returned when a depositor already holds the maximum number of active escrows
+    TooManyActiveEscrows = 36,
+    /// Returned when the release queue already holds `MAX_RELEASE_QUEUE` entries
+    ReleaseQueueFull = 37,
+    /// Returned when an address exceeds its operations per rate-limit window
+    RateLimited = 38,
+    /// Returned when an address repeats an operation within the cooldown period
+    InCooldown = 39,
+    /// Returned when the refund policy does not allow refunds to this recipient
+    RefundDestinationNotAllowed = 40,
+    /// Returned when a release reuses a receipt ID already recorded for the bounty
+    DuplicateReceipt = 41,
+    /// Returned when a release path that pays out immediately is used while a
+    /// release confirmation delay is configured
+    ReleaseDelayActive = 42,
+    /// Returned when a tag already indexes `MAX_BOUNTIES_PER_TAG` bounties
+    TagIndexFull = 43,
 }
 
+/// Stable `(code, name)` pairs for every `Error` variant, exposed through
+/// `get_error_catalog` so clients can map numeric errors to names.
+/// New variants must be appended here as well.
+const ERROR_CATALOG: &[(u32, &str)] = &[
+    (Error::AlreadyInitialized as u32, "AlreadyInitialized"),
+    (Error::NotInitialized as u32, "NotInitialized"),
+    (Error::BountyExists as u32, "BountyExists"),
+    (Error::BountyNotFound as u32, "BountyNotFound"),
+    (Error::FundsNotLocked as u32, "FundsNotLocked"),
+    (Error::DeadlineNotPassed as u32, "DeadlineNotPassed"),
+    (Error::Unauthorized as u32, "Unauthorized"),
+    (Error::InvalidFeeRate as u32, "InvalidFeeRate"),
+    (Error::FeeRecipientNotSet as u32, "FeeRecipientNotSet"),
+    (Error::InvalidBatchSize as u32, "InvalidBatchSize"),
+    (Error::BatchSizeMismatch as u32, "BatchSizeMismatch"),
+    (Error::DuplicateBountyId as u32, "DuplicateBountyId"),
+    (Error::InvalidAmount as u32, "InvalidAmount"),
+    (Error::InvalidDeadline as u32, "InvalidDeadline"),
+    (Error::InsufficientFunds as u32, "InsufficientFunds"),
+    (Error::RefundNotApproved as u32, "RefundNotApproved"),
+    (Error::ProgramMismatch as u32, "ProgramMismatch"),
+    (Error::BountyAlreadyTagged as u32, "BountyAlreadyTagged"),
+    (Error::MetadataTooLarge as u32, "MetadataTooLarge"),
+    (Error::InvalidQuorum as u32, "InvalidQuorum"),
+    (Error::InsufficientApprovals as u32, "InsufficientApprovals"),
+    (Error::RefundApprovalExpired as u32, "RefundApprovalExpired"),
+    (Error::InvalidToken as u32, "InvalidToken"),
+    (Error::ReleaseLimitExceeded as u32, "ReleaseLimitExceeded"),
+    (Error::InvalidPercentage as u32, "InvalidPercentage"),
+    (Error::ReleasePending as u32, "ReleasePending"),
+    (
+        Error::PendingReleaseNotFound as u32,
+        "PendingReleaseNotFound",
+    ),
+    (Error::ReleaseNotReady as u32, "ReleaseNotReady"),
+    (Error::ReleaseObjected as u32, "ReleaseObjected"),
+    (Error::ReleaseLocked as u32, "ReleaseLocked"),
+    (Error::ContributorMismatch as u32, "ContributorMismatch"),
+    (Error::ContractPaused as u32, "ContractPaused"),
+    (Error::ContractNotPaused as u32, "ContractNotPaused"),
+    (Error::TokenMismatch as u32, "TokenMismatch"),
+    (Error::TooManyActiveEscrows as u32, "TooManyActiveEscrows"),
+    (Error::ReleaseQueueFull as u32, "ReleaseQueueFull"),
+    (Error::RateLimited as u32, "RateLimited"),
+    (Error::InCooldown as u32, "InCooldown"),
+    (
+        Error::RefundDestinationNotAllowed as u32,
+        "RefundDestinationNotAllowed",
+    ),
+    (Error::DuplicateReceipt as u32, "DuplicateReceipt"),
+    (Error::ReleaseDelayActive as u32, "ReleaseDelayActive"),
+    (Error::TagIndexFull as u32, "TagIndexFull"),
+];
+
 // ============================================================================
 // Data Structures
 // ============================================================================
@@ -478,6 +686,7 @@ pub enum Error {
 /// * `Locked` - Funds are held in escrow, awaiting release or refund
 /// * `Released` - Funds have been transferred to contributor (final state)
 /// * `Refunded` - Funds have been returned to depositor (final state)
+/// * `PartiallyReleased` - Part of the funds has been released via `release_percent`
 ///
 /// # Invariants
 /// - Once in Released or Refunded state, no further transitions allowed
@@ -489,6 +698,7 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    PartiallyReleased,
 }
 
 #[contracttype]
@@ -499,6 +709,34 @@ pub enum RefundMode {
     Custom,
 }
 
+/// Where refunds may be sent.
+///
+/// `DepositorOnly` (the default) disables custom refund recipients;
+/// `DepositorOrApproved` allows `RefundMode::Custom` refunds to other
+/// addresses, authorized by the depositor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RefundPolicy {
+    DepositorOnly,
+    DepositorOrApproved,
+}
+
+/// Which operations a pause halts.
+///
+/// * `All` - Locks, releases and refunds (the mode used by `pause`)
+/// * `InflowsOnly` - Locks; releases and refunds continue
+/// * `OutflowsOnly` - Releases to contributors; locks and refunds continue
+///
+/// Refunds to the depositor are only halted by `All`, so a partial pause
+/// never traps depositor funds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PauseMode {
+    All,
+    InflowsOnly,
+    OutflowsOnly,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RefundRecord {
@@ -508,6 +746,20 @@ pub struct RefundRecord {
     pub timestamp: u64,
 }
 
+/// A single release paid out of an escrow; `amount` is net of the release fee.
+///
+/// `receipt_id` is the off-chain invoice reference passed to `release_funds`, if
+/// any, widened to `Bytes`: `Option<BytesN<_>>` is not a valid `contracttype`
+/// field under the SDK's test utilities.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutRecord {
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub receipt_id: Option<Bytes>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RefundApproval {
@@ -526,6 +778,7 @@ pub struct RefundApproval {
 /// * `amount` - Token amount held in escrow (in smallest denomination)
 /// * `status` - Current state of the escrow (Locked/Released/Refunded)
 /// * `deadline` - Unix timestamp after which refunds are allowed
+/// * `token` - Token contract the funds are denominated in
 ///
 /// # Storage
 /// Stored in persistent storage with key `DataKey::Escrow(bounty_id)`.
@@ -538,6 +791,10 @@ pub struct RefundApproval {
 ///     amount: 1000_0000000, // 1000 tokens
 ///     status: EscrowStatus::Locked,
 ///     deadline: current_time + 2592000, // 30 days
+///     refund_history: vec![&env],
+///     remaining_amount: 1000_0000000,
+///     auto_refund_on_expiry: false,
+///     token: Some(usdc_address),
 /// };
 /// ```
 #[contracttype]
@@ -549,6 +806,56 @@ pub struct Escrow {
     pub deadline: u64,
     pub refund_history: Vec<RefundRecord>,
     pub remaining_amount: i128,
+    pub auto_refund_on_expiry: bool,
+    /// Token the escrow is denominated in. `None` for escrows locked before
+    /// per-escrow tokens existed, which use the contract's default token.
+    pub token: Option<Address>,
+}
+
+/// Descriptive metadata attached to an escrow for off-chain indexing.
+///
+/// Stored separately from the escrow record under `DataKey::Metadata(bounty_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowMetadata {
+    pub repo_id: String,
+    pub issue_id: String,
+    pub bounty_type: String,
+    pub tags: Vec<String>,
+    pub custom_fields: Map<String, String>,
+}
+
+/// An escrow together with its metadata, returned by `get_escrow_with_metadata`.
+///
+/// `metadata` is empty for escrows that never had metadata attached and
+/// otherwise holds exactly one entry. (`Option` of a struct is not a valid
+/// `contracttype` field under the SDK's test utilities.)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowMetadataWithEscrow {
+    pub escrow: Escrow,
+    pub metadata: Vec<EscrowMetadata>,
+}
+
+/// Size limits enforced on `EscrowMetadata`; exceeding any of them returns
+/// `Error::MetadataTooLarge`. String lengths are measured in bytes.
+impl EscrowMetadata {
+    /// Maximum number of entries in `tags`
+    pub const MAX_TAGS: u32 = 20;
+    /// Maximum length of a single tag
+    pub const MAX_TAG_LEN: u32 = 64;
+    /// Maximum number of entries in `custom_fields`
+    pub const MAX_CUSTOM_FIELDS: u32 = 20;
+    /// Maximum length of a `custom_fields` key
+    pub const MAX_FIELD_KEY_LEN: u32 = 64;
+    /// Maximum length of a `custom_fields` value
+    pub const MAX_FIELD_VALUE_LEN: u32 = 256;
+    /// Maximum length of `repo_id`
+    pub const MAX_REPO_ID_LEN: u32 = 128;
+    /// Maximum length of `issue_id`
+    pub const MAX_ISSUE_ID_LEN: u32 = 64;
+    /// Maximum length of `bounty_type`
+    pub const MAX_BOUNTY_TYPE_LEN: u32 = 32;
 }
 
 /// Storage keys for contract data.
@@ -568,6 +875,7 @@ pub struct LockFundsItem {
     pub depositor: Address,
     pub amount: i128,
     pub deadline: u64,
+    pub token: Address,
 }
 
 #[contracttype]
@@ -577,9 +885,21 @@ pub struct ReleaseFundsItem {
     pub contributor: Address,
 }
 
-// Maximum batch size to prevent gas limit issues
+// Default maximum batch size to prevent gas limit issues, overridable by the admin
 const MAX_BATCH_SIZE: u32 = 100;
 
+// Upper bound accepted by `set_max_batch_size`
+const MAX_BATCH_SIZE_LIMIT: u32 = 1000;
+
+// Maximum refund records kept when merging two escrow histories
+const MAX_MERGED_HISTORY: u32 = 50;
+
+// Default seconds a refund approval stays usable, see `set_refund_approval_expiry`
+const REFUND_APPROVAL_EXPIRY: u64 = 7 * 24 * 60 * 60; // 7 days
+
+// Emergency withdrawal approvals lapse this many seconds after being given
+const EMERGENCY_APPROVAL_EXPIRY: u64 = 24 * 60 * 60; // 1 day
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
@@ -589,19 +909,175 @@ pub struct FeeConfig {
     pub fee_enabled: bool,   // Global fee enable/disable flag
 }
 
+/// Optional filters for listing escrows. Unset fields match everything.
+///
+/// `status` uses stable numeric codes: 0 = Locked, 1 = Released,
+/// 2 = Refunded, 3 = PartiallyRefunded, 4 = PartiallyReleased, and
+/// `EXPIRED_CODE` for active escrows whose deadline has passed. Amount bounds apply to
+/// `remaining_amount`; time bounds apply to `deadline`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowFilter {
+    pub status: Option<u32>,
+    pub depositor: Option<Address>,
+    pub min_amount: Option<i128>,
+    pub max_amount: Option<i128>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+/// Page of results: skips `start_index` matches and returns at most `limit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pagination {
+    pub start_index: u32,
+    pub limit: u32,
+}
+
+/// Synthetic `EscrowFilter.status` value matching active escrows past their deadline.
+pub const EXPIRED_CODE: u32 = 100;
+
+// Maximum page size returned by paginated queries
+const MAX_QUERY_LIMIT: u32 = 100;
+
+// Maximum number of bounties listed under a single `DataKey::TagIndex` entry
+const MAX_BOUNTIES_PER_TAG: u32 = 1000;
+
+/// Lifetime volume for one token in this escrow contract, consumed by the core
+/// platform registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowStats {
+    pub total_locked: i128,
+    pub total_released: i128,
+}
+
+/// A release requested by the admin and executed later by `process_release_queue`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedRelease {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub requested_at: u64,
+}
+
+// Maximum number of releases waiting in the release queue
+const MAX_RELEASE_QUEUE: u32 = 100;
+
+/// N-of-M signer set required to approve an emergency withdrawal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyQuorum {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// A quorum signer's approval of an emergency withdrawal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyApproval {
+    pub signer: Address,
+    pub approved_at: u64,
+}
+
+/// Approvals collected for one recipient under a given quorum configuration.
+///
+/// `epoch` is bumped by every `set_emergency_quorum`, so approvals given to a
+/// replaced quorum no longer count.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyApprovalSet {
+    pub epoch: u32,
+    pub approvals: Vec<EmergencyApproval>,
+}
+
+/// Optional cap on how much may be released from a single bounty per window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseLimit {
+    pub max_amount: i128,
+    pub window: u64, // seconds
+}
+
+/// Release recorded by `release_funds` while a confirmation delay is configured.
+///
+/// Funds move once `executable_at` is reached unless the depositor objects,
+/// in which case the release waits for the admin to resolve it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRelease {
+    pub contributor: Address,
+    pub requested_at: u64,
+    pub executable_at: u64,
+    pub objected: bool,
+    pub receipt_id: Option<Bytes>,
+    pub drain_epoch: u32,
+}
+
+/// Tracks releases within the current per-bounty release window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseWindow {
+    pub window_start: u64,
+    pub released: i128,
+}
+
 // Fee rate is stored in basis points (1 basis point = 0.01%)
 // Example: 100 basis points = 1%, 1000 basis points = 10%
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
 
+// Tokens reporting more decimals than this are treated as misconfigured
+const MAX_TOKEN_DECIMALS: u32 = 18;
+
 #[contracttype]
 pub enum DataKey {
     Admin,
     Token,
-    Escrow(u64),         // bounty_id
-    FeeConfig,           // Fee configuration
-    RefundApproval(u64), // bounty_id -> RefundApproval
-    ReentrancyGuard,
+    Escrow(u64),             // bounty_id
+    FeeConfig,               // Fee configuration
+    FeesHeld,                // bool: route fees into the contract instead of fee_recipient
+    HeldFees(Address),       // token -> fees held in the contract awaiting a sweep
+    Obligations(Address),    // token -> remaining_amount owed to active escrows
+    RefundApproval(u64),     // bounty_id -> RefundApproval
+    ReentrancyGuard,         // Symbol of the state-mutating operation in progress
+    BountyProgram(u64),      // bounty_id -> program_id
+    ProgramBounties(String), // program_id -> Vec<bounty_id>
+    Metadata(u64),           // bounty_id -> EscrowMetadata
+    TagIndex(String),        // tag -> Vec<bounty_id> whose metadata carries the tag
+    EmergencyQuorum,
+    EmergencyApprovals(Address),     // recipient -> EmergencyApprovalSet
+    EmergencyQuorumEpoch,            // u32 bumped whenever the quorum changes
+    QuorumChange(EmergencyQuorum),   // proposed quorum -> EmergencyApprovalSet
+    ReleaseLimit(u64),               // bounty_id -> ReleaseLimit
+    ReleaseWindow(u64),              // bounty_id -> ReleaseWindow
+    BountyRegistryPage(u32),         // page -> Vec<bounty_id>, see the registry module
+    BountyRegistryLen,               // number of registered bounty IDs
+    RegistryPosition(u64),           // bounty_id -> position in the registry
+    FreeOperations,                  // fee-free operations granted per address
+    FeeWaiverUsage(Address),         // address -> fee-free operations used
+    ReleaseDelay,                    // seconds a release waits for depositor objections
+    PendingRelease(u64),             // bounty_id -> PendingRelease
+    DrainEpoch(Address),             // token -> u32 bumped by each emergency_withdraw
+    ReleaseCliff(u64),               // bounty_id -> timestamp before which release is blocked
+    IntendedContributor(u64),        // bounty_id -> only address releases may pay
+    Paused,                          // bool: operations selected by PauseMode are halted
+    PauseMode,                       // PauseMode applied while Paused is set
+    DepositorIndex(Address),         // depositor -> Vec<bounty_id> they currently own
+    ContributorCompletions(Address), // contributor -> count of fully released bounties
+    MaxActiveEscrows,                // u32 cap on a depositor's active escrows (0 = unlimited)
+    MaxBatchSize,                    // u32 batch size limit overriding MAX_BATCH_SIZE
+    ReleaseQueue,                    // Vec<QueuedRelease> awaiting process_release_queue
+    PayoutHistory(u64),              // bounty_id -> Vec<PayoutRecord>
+    Receipt(u64, Bytes),             // bounty_id, receipt_id -> bool (recorded on payout)
+    CancelPenaltyRate,               // basis points kept from early cancellations
+    SubmissionGrace,                 // u64 seconds before a deadline that trigger auto-extension
+    Submission(u64),                 // bounty_id -> timestamp of the deadline-extending submission
+    DepositorSeen(Address),          // depositor -> true once they have locked funds
+    UniqueDepositors,                // u64 count of distinct depositors that have locked funds
+    RefundPolicy,                    // RefundPolicy governing custom refund recipients
+    RefundApprovalExpiry,            // u64 seconds overriding REFUND_APPROVAL_EXPIRY
 }
 
 // ============================================================================
@@ -627,6 +1103,7 @@ impl BountyEscrowContract {
     /// # Returns
     /// * `Ok(())` - Contract successfully initialized
     /// * `Err(Error::AlreadyInitialized)` - Contract already initialized
+    /// * `Err(Error::InvalidToken)` - Token address does not report sane decimals
     ///
     /// # State Changes
     /// - Sets Admin address in instance storage
@@ -653,9 +1130,11 @@ impl BountyEscrowContract {
     /// Low - Only two storage writes
     pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
+        if let Err(err) = anti_abuse::check_rate_limit(&env, admin.clone(), symbol_short!("init")) {
+            monitoring::track_operation(&env, symbol_short!("init"), admin.clone(), false);
+            return Err(err);
+        }
 
-        let start = env.ledger().timestamp();
         let caller = admin.clone();
 
         // Prevent re-initialization
@@ -664,6 +1143,12 @@ impl BountyEscrowContract {
             return Err(Error::AlreadyInitialized);
         }
 
+        // Sanity-check the token contract to catch misconfiguration early
+        if let Err(err) = Self::validate_token(&env, &token) {
+            monitoring::track_operation(&env, symbol_short!("init"), caller, false);
+            return Err(err);
+        }
+
         // Store configuration
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
@@ -693,12 +1178,19 @@ impl BountyEscrowContract {
         monitoring::track_operation(&env, symbol_short!("init"), caller, true);
 
         // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("init"), duration);
+        monitoring::emit_performance(&env, symbol_short!("init"));
 
         Ok(())
     }
 
+    /// Rejects token addresses that do not report sane decimals
+    fn validate_token(env: &Env, token: &Address) -> Result<(), Error> {
+        match token::Client::new(env, token).try_decimals() {
+            Ok(Ok(decimals)) if decimals <= MAX_TOKEN_DECIMALS => Ok(()),
+            _ => Err(Error::InvalidToken),
+        }
+    }
+
     /// Calculate fee amount based on rate (in basis points)
     fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
         if fee_rate == 0 {
@@ -787,6 +1279,173 @@ impl BountyEscrowContract {
         Self::get_fee_config_internal(&env)
     }
 
+    /// Returns `(enabled, lock_rate, release_rate)` without the fee recipient,
+    /// for frontends that only need to know whether and how much to charge.
+    pub fn fees_active(env: Env) -> (bool, i128, i128) {
+        let config: Option<FeeConfig> = env.storage().instance().get(&DataKey::FeeConfig);
+        match config {
+            Some(config) => (
+                config.fee_enabled,
+                config.lock_fee_rate,
+                config.release_fee_rate,
+            ),
+            None => (false, 0, 0),
+        }
+    }
+
+    /// Keep collected fees in the contract instead of paying the fee recipient (admin only)
+    ///
+    /// Meant for a compromised recipient: hold fees, point `fee_recipient` at a
+    /// new address via `update_fee_config`, stop holding, then `sweep_held_fees`.
+    pub fn hold_fees(env: Env, hold: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::FeesHeld, &hold);
+        Ok(())
+    }
+
+    /// Whether collected fees are currently being held in the contract.
+    pub fn is_holding_fees(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeesHeld)
+            .unwrap_or(false)
+    }
+
+    /// Amount of `token` fees held in the contract awaiting a sweep.
+    pub fn get_held_fees(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::HeldFees(token))
+            .unwrap_or(0)
+    }
+
+    /// Pay out all held `token` fees to the current fee recipient (admin only)
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Amount swept (0 if nothing was held)
+    pub fn sweep_held_fees(env: Env, token: Address) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let key = DataKey::HeldFees(token.clone());
+        let held: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if held > 0 {
+            Self::enter_guard(&env, symbol_short!("sweep_fee"));
+            env.storage().persistent().remove(&key);
+            let recipient = Self::get_fee_config_internal(&env).fee_recipient;
+            token::Client::new(&env, &token).transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &held,
+            );
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        }
+        Ok(held)
+    }
+
+    /// Moves a collected fee from `from` to the fee recipient, or into the
+    /// contract's held balance while `hold_fees` is on. Returns the address
+    /// that ended up with the fee.
+    fn collect_fee(
+        env: &Env,
+        client: &token::Client,
+        from: &Address,
+        recipient: &Address,
+        amount: i128,
+    ) -> Address {
+        let contract = env.current_contract_address();
+        if !Self::is_holding_fees(env.clone()) {
+            client.transfer(from, recipient, &amount);
+            return recipient.clone();
+        }
+
+        if *from != contract {
+            client.transfer(from, &contract, &amount);
+        }
+        let key = DataKey::HeldFees(client.address.clone());
+        let held: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(held + amount));
+        contract
+    }
+
+    /// Waive lock/release fees for each address's first `count` fee-bearing operations (admin only)
+    pub fn set_free_operations(env: Env, count: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::FreeOperations, &count);
+
+        Ok(())
+    }
+
+    /// Get number of fee-free operations granted per address (view function)
+    pub fn get_free_operations(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::FreeOperations)
+            .unwrap_or(0)
+    }
+
+    /// Consumes one of the user's fee-free operations, returning true if the fee is waived
+    fn consume_fee_waiver(env: &Env, user: &Address) -> bool {
+        let free: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::FreeOperations)
+            .unwrap_or(0);
+        if free == 0 {
+            return false;
+        }
+
+        let key = DataKey::FeeWaiverUsage(user.clone());
+        let used: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        if used >= free {
+            return false;
+        }
+
+        env.storage().persistent().set(&key, &(used + 1));
+        true
+    }
+
+    /// Returns a SHA-256 digest of the contract configuration.
+    ///
+    /// The digest covers the admin, token, fee configuration and rate-limit
+    /// configuration. It is recomputed on every call, so off-chain monitors
+    /// can compare a single value to detect unexpected configuration drift.
+    ///
+    /// # Returns
+    /// * `Ok(BytesN<32>)` - Digest of the current configuration
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    pub fn get_config_hash(env: Env) -> Result<BytesN<32>, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let fee_config = Self::get_fee_config_internal(&env);
+        let rate_limit_config = anti_abuse::get_config(&env);
+
+        let payload = (admin, token_addr, fee_config, rate_limit_config).to_xdr(&env);
+        Ok(env.crypto().sha256(&payload).into())
+    }
+
     /// Lock funds for a specific bounty.
     // ========================================================================
     // Core Escrow Functions
@@ -800,11 +1459,13 @@ impl BountyEscrowContract {
     /// * `bounty_id` - Unique identifier for this bounty
     /// * `amount` - Token amount to lock (in smallest denomination)
     /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `token` - Token contract the bounty is denominated in
     ///
     /// # Returns
     /// * `Ok(())` - Funds successfully locked
     /// * `Err(Error::NotInitialized)` - Contract not initialized
     /// * `Err(Error::BountyExists)` - Bounty ID already in use
+    /// * `Err(Error::InvalidToken)` - Token does not report sane decimals
     ///
     /// # State Changes
     /// - Transfers `amount` tokens from depositor to contract
@@ -831,7 +1492,7 @@ impl BountyEscrowContract {
     /// let amount = 1000_0000000; // 1000 USDC
     /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
     ///
-    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
+    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline, &usdc_address)?;
     /// // Funds are now locked and can be released or refunded
     /// ```
     ///
@@ -848,23 +1509,37 @@ impl BountyEscrowContract {
         bounty_id: u64,
         amount: i128,
         deadline: u64,
+        token: Address,
+    ) -> Result<(), Error> {
+        Self::lock_funds_internal(env, depositor, bounty_id, amount, deadline, token)
+    }
+
+    fn lock_funds_internal(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token_addr: Address,
     ) -> Result<(), Error> {
+        if Self::inflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        if let Err(err) =
+            anti_abuse::check_rate_limit(&env, depositor.clone(), symbol_short!("lock"))
+        {
+            monitoring::track_operation(&env, symbol_short!("lock"), depositor.clone(), false);
+            return Err(err);
+        }
 
-        let start = env.ledger().timestamp();
         let caller = depositor.clone();
 
         // Verify depositor authorization
         depositor.require_auth();
 
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
-        }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
+        Self::enter_guard(&env, symbol_short!("lock"));
 
         if amount <= 0 {
             monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
@@ -883,6 +1558,12 @@ impl BountyEscrowContract {
             return Err(Error::NotInitialized);
         }
 
+        if let Err(err) = Self::validate_token(&env, &token_addr) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
         // Prevent duplicate bounty IDs
         if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
@@ -890,13 +1571,21 @@ impl BountyEscrowContract {
             return Err(Error::BountyExists);
         }
 
+        if let Err(err) = Self::check_active_escrow_cap(&env, &depositor) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
         // Get token contract and transfer funds
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
 
         // Calculate and collect fee if enabled
         let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
+        let fee_amount = if fee_config.fee_enabled
+            && fee_config.lock_fee_rate > 0
+            && !Self::consume_fee_waiver(&env, &depositor)
+        {
             Self::calculate_fee(amount, fee_config.lock_fee_rate)
         } else {
             0
@@ -908,14 +1597,20 @@ impl BountyEscrowContract {
 
         // Transfer fee to fee recipient if applicable
         if fee_amount > 0 {
-            client.transfer(&depositor, &fee_config.fee_recipient, &fee_amount);
-            events::emit_fee_collected(
+            let recipient = Self::collect_fee(
                 &env,
-                events::FeeCollected {
+                &client,
+                &depositor,
+                &fee_config.fee_recipient,
+                fee_amount,
+            );
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
                     operation_type: events::FeeOperationType::Lock,
                     amount: fee_amount,
                     fee_rate: fee_config.lock_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
+                    recipient,
                     timestamp: env.ledger().timestamp(),
                 },
             );
@@ -928,13 +1623,15 @@ impl BountyEscrowContract {
             status: EscrowStatus::Locked,
             deadline,
             refund_history: vec![&env],
-            remaining_amount: amount,
+            remaining_amount: net_amount,
+            auto_refund_on_expiry: false,
+            token: Some(token_addr),
         };
 
         // Store in persistent storage with extended TTL
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::store_escrow(&env, bounty_id, &escrow);
+        Self::register_bounty(&env, bounty_id, &depositor);
+        Self::record_depositor(&env, &depositor);
 
         // Emit event for off-chain indexing
         emit_funds_locked(
@@ -953,782 +1650,4616 @@ impl BountyEscrowContract {
         monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
 
         // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+        monitoring::emit_performance(&env, symbol_short!("lock"));
 
         Ok(())
     }
 
-    /// Releases escrowed funds to a contributor.
+    /// Locks funds and attaches metadata to the escrow in a single call.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to release funds for
-    /// * `contributor` - Address to receive the funds
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `token` - Token contract to lock funds in
+    /// * `metadata` - Descriptive metadata stored alongside the escrow
     ///
     /// # Returns
-    /// * `Ok(())` - Funds successfully released
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::Unauthorized)` - Caller is not the admin
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to contributor
-    /// - Updates escrow status to Released
-    /// - Emits FundsReleased event
-    ///
-    /// # Authorization
-    /// - **CRITICAL**: Only admin can call this function
-    /// - Admin address must match initialization value
+    /// * `Ok(())` - Funds locked and metadata stored
+    /// * `Err(Error::MetadataTooLarge)` - Metadata exceeds size limits
+    /// * `Err(Error::TagIndexFull)` - A tag already lists `MAX_BOUNTIES_PER_TAG` bounties
+    /// * Any error returned by `lock_funds`
     ///
     /// # Security Considerations
-    /// - This is the most security-critical function
-    /// - Admin should verify task completion off-chain before calling
-    /// - Once released, funds cannot be retrieved
-    /// - Recipient address should be verified carefully
-    /// - Consider implementing multi-sig for admin
-    ///
-    /// # Events
-    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
-    ///
-    /// # Example
-    /// ```rust
-    /// // After verifying task completion off-chain:
-    /// let contributor = Address::from_string("GCONTRIB...");
-    ///
-    /// // Admin calls release
-    /// escrow_client.release_funds(&42, &contributor)?;
-    /// // Funds transferred to contributor, escrow marked as Released
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage update + event emission
-    ///
-    /// # Best Practices
-    /// 1. Verify contributor identity off-chain
-    /// 2. Confirm task completion before release
-    /// 3. Log release decisions in backend system
-    /// 4. Monitor release events for anomalies
-    /// 5. Consider implementing release delays for high-value bounties
-    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
+    /// - Metadata is validated before any token transfer, so a rejected
+    ///   payload never leaves funds locked without metadata
+    pub fn lock_funds_with_metadata(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Address,
+        metadata: EscrowMetadata,
+    ) -> Result<(), Error> {
+        Self::validate_metadata(&metadata)?;
+        Self::check_tag_capacity(&env, bounty_id, &metadata)?;
 
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
+        Self::lock_funds_internal(env.clone(), depositor, bounty_id, amount, deadline, token)?;
+
+        Self::write_metadata(&env, bounty_id, Some(&metadata));
+
+        Ok(())
+    }
+
+    /// Stores or clears a bounty's metadata, keeping `DataKey::TagIndex` in
+    /// step: tags dropped from the previous metadata are unindexed and new
+    /// tags indexed.
+    fn write_metadata(env: &Env, bounty_id: u64, metadata: Option<&EscrowMetadata>) {
+        let key = DataKey::Metadata(bounty_id);
+        let previous: Option<EscrowMetadata> = env.storage().persistent().get(&key);
+        if let Some(previous) = previous {
+            for tag in previous.tags.iter() {
+                let kept = metadata.is_some_and(|m| m.tags.first_index_of(&tag).is_some());
+                if !kept {
+                    Self::unindex_tag(env, &tag, bounty_id);
+                }
+            }
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
-        if !env.storage().instance().has(&DataKey::Admin) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::NotInitialized);
+
+        match metadata {
+            Some(metadata) => {
+                for tag in metadata.tags.iter() {
+                    Self::index_tag(env, &tag, bounty_id);
+                }
+                env.storage().persistent().set(&key, metadata);
+            }
+            None => env.storage().persistent().remove(&key),
         }
+    }
 
-        // Verify admin authorization
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    fn index_tag(env: &Env, tag: &String, bounty_id: u64) {
+        let key = DataKey::TagIndex(tag.clone());
+        let mut index: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+        if index.first_index_of(bounty_id).is_none() {
+            index.push_back(bounty_id);
+            env.storage().persistent().set(&key, &index);
+        }
+    }
 
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
+    fn unindex_tag(env: &Env, tag: &String, bounty_id: u64) {
+        let key = DataKey::TagIndex(tag.clone());
+        let mut index: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+        if let Some(position) = index.first_index_of(bounty_id) {
+            index.remove(position);
+            if index.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &index);
+            }
+        }
+    }
 
-        admin.require_auth();
+    /// Rejects metadata that would add `bounty_id` to a tag index already
+    /// holding `MAX_BOUNTIES_PER_TAG` bounties.
+    fn check_tag_capacity(
+        env: &Env,
+        bounty_id: u64,
+        metadata: &EscrowMetadata,
+    ) -> Result<(), Error> {
+        for tag in metadata.tags.iter() {
+            let index: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::TagIndex(tag))
+                .unwrap_or(vec![env]);
+            if index.len() >= MAX_BOUNTIES_PER_TAG && index.first_index_of(bounty_id).is_none() {
+                return Err(Error::TagIndexFull);
+            }
+        }
+        Ok(())
+    }
 
-        // Verify bounty exists
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+    fn validate_metadata(metadata: &EscrowMetadata) -> Result<(), Error> {
+        if metadata.repo_id.len() > EscrowMetadata::MAX_REPO_ID_LEN
+            || metadata.issue_id.len() > EscrowMetadata::MAX_ISSUE_ID_LEN
+            || metadata.bounty_type.len() > EscrowMetadata::MAX_BOUNTY_TYPE_LEN
+            || metadata.tags.len() > EscrowMetadata::MAX_TAGS
+            || metadata.custom_fields.len() > EscrowMetadata::MAX_CUSTOM_FIELDS
+        {
+            return Err(Error::MetadataTooLarge);
         }
+        for tag in metadata.tags.iter() {
+            if tag.len() > EscrowMetadata::MAX_TAG_LEN {
+                return Err(Error::MetadataTooLarge);
+            }
+        }
+        for (key, value) in metadata.custom_fields.iter() {
+            if key.len() > EscrowMetadata::MAX_FIELD_KEY_LEN
+                || value.len() > EscrowMetadata::MAX_FIELD_VALUE_LEN
+            {
+                return Err(Error::MetadataTooLarge);
+            }
+        }
+        Ok(())
+    }
+
+    /// Locks funds and flags whether the escrow is refunded automatically on expiry.
+    ///
+    /// Flagged escrows are refunded to their depositor by `process_expirations`
+    /// once the deadline passes, without an individual `refund` call.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `token` - Token contract to lock funds in
+    /// * `auto_refund_on_expiry` - Whether keepers may refund this escrow after the deadline
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds locked
+    /// * Any error returned by `lock_funds`
+    pub fn lock_funds_with_auto_refund(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Address,
+        auto_refund_on_expiry: bool,
+    ) -> Result<(), Error> {
+        Self::lock_funds_internal(env.clone(), depositor, bounty_id, amount, deadline, token)?;
 
-        // Get and verify escrow state
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
+        escrow.auto_refund_on_expiry = auto_refund_on_expiry;
+        Self::store_escrow(&env, bounty_id, &escrow);
 
-        if escrow.status != EscrowStatus::Locked {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::FundsNotLocked);
-        }
-
-        // Transfer funds to contributor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        escrow.status = EscrowStatus::Released;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Ok(())
+    }
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
-            Self::calculate_fee(escrow.amount, fee_config.release_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = escrow.amount - fee_amount;
+    /// Locks funds with an optional release cliff.
+    ///
+    /// Before the cliff timestamp every release path (`release_funds`,
+    /// `release_percent`, `batch_release_funds`) fails with `ReleaseLocked`,
+    /// even for the admin. Refunds are unaffected.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `token` - Token contract to lock funds in
+    /// * `cliff` - Unix timestamp before which funds cannot be released, if any
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds locked
+    /// * `Err(Error::InvalidDeadline)` - Cliff is not in the future
+    /// * Any error returned by `lock_funds`
+    pub fn lock_funds_with_cliff(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Address,
+        cliff: Option<u64>,
+    ) -> Result<(), Error> {
+        if let Some(cliff) = cliff {
+            if cliff <= env.ledger().timestamp() {
+                return Err(Error::InvalidDeadline);
+            }
+        }
 
-        // Transfer net amount to contributor
-        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+        Self::lock_funds_internal(env.clone(), depositor, bounty_id, amount, deadline, token)?;
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            client.transfer(
-                &env.current_contract_address(),
-                &fee_config.fee_recipient,
-                &fee_amount,
-            );
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Release,
-                    amount: fee_amount,
-                    fee_rate: fee_config.release_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
+        if let Some(cliff) = cliff {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseCliff(bounty_id), &cliff);
         }
 
-        // Update escrow state - mark as released and set remaining_amount to 0
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
+        Ok(())
+    }
+
+    /// Returns the release cliff of a bounty, if one was set at lock time.
+    pub fn get_release_cliff(env: Env, bounty_id: u64) -> Option<u64> {
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Emit release event
-        emit_funds_released(
-            &env,
-            FundsReleased {
-                bounty_id,
-                amount: net_amount, // Emit net amount (after fee)
-                recipient: contributor.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
-
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
-
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+            .get(&DataKey::ReleaseCliff(bounty_id))
+    }
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("release"), duration);
-        Ok(())
+    fn check_release_cliff(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        let cliff: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseCliff(bounty_id));
+        match cliff {
+            Some(cliff) if env.ledger().timestamp() < cliff => Err(Error::ReleaseLocked),
+            _ => Ok(()),
+        }
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
+    /// Locks funds and optionally pins the contributor they may be released to.
+    ///
+    /// When `intended_contributor` is set, `release_funds`, `release_percent`
+    /// and `batch_release_funds` fail with `ContributorMismatch` for any other
+    /// recipient, so the admin cannot redirect the payout.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `token` - Token contract to lock funds in
+    /// * `intended_contributor` - The only address releases may pay, if any
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds locked
+    /// * Any error returned by `lock_funds`
+    pub fn lock_funds_for_contributor(
         env: Env,
+        depositor: Address,
         bounty_id: u64,
         amount: i128,
-        recipient: Address,
-        mode: RefundMode,
+        deadline: u64,
+        token: Address,
+        intended_contributor: Option<Address>,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        Self::lock_funds_internal(env.clone(), depositor, bounty_id, amount, deadline, token)?;
+
+        if let Some(contributor) = intended_contributor {
+            env.storage()
+                .persistent()
+                .set(&DataKey::IntendedContributor(bounty_id), &contributor);
         }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        Ok(())
+    }
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+    /// Returns the contributor a bounty is pinned to, if one was registered at lock time.
+    pub fn get_intended_contributor(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IntendedContributor(bounty_id))
+    }
 
-        let escrow: Escrow = env
+    fn check_intended_contributor(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+    ) -> Result<(), Error> {
+        let intended: Option<Address> = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
+            .get(&DataKey::IntendedContributor(bounty_id));
+        match intended {
+            Some(intended) if intended != *contributor => Err(Error::ContributorMismatch),
+            _ => Ok(()),
         }
+    }
 
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+    /// Adds funds to an existing active escrow.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `funder` - Original depositor or admin supplying the tokens (must authorize)
+    /// * `bounty_id` - The bounty to top up
+    /// * `amount` - Additional token amount to lock
+    ///
+    /// # Returns
+    /// * `Ok(())` - Escrow topped up
+    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative, or the new total overflows
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow is no longer active
+    /// * `Err(Error::Unauthorized)` - Funder is neither depositor nor admin
+    /// * `Err(Error::ContractPaused)` - Inflows are paused
+    ///
+    /// # State Changes
+    /// - Transfers `amount` tokens from funder to contract
+    /// - Increases both `amount` and `remaining_amount`
+    /// - Emits FundsToppedUp event
+    pub fn top_up(env: Env, funder: Address, bounty_id: u64, amount: i128) -> Result<(), Error> {
+        funder.require_auth();
+        if Self::inflows_paused(&env) {
+            return Err(Error::ContractPaused);
         }
 
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
-        };
+        Self::enter_guard(&env, symbol_short!("top_up"));
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+        let result = Self::top_up_internal(&env, &funder, bounty_id, amount);
 
-        Ok(())
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        monitoring::track_operation(&env, symbol_short!("top_up"), funder, result.is_ok());
+
+        monitoring::emit_performance(&env, symbol_short!("top_up"));
+
+        result
     }
 
-    /// Refund funds with support for Full, Partial, and Custom refunds.
-    /// - Full: refunds all remaining funds to depositor
-    /// - Partial: refunds specified amount to depositor
-    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
-    pub fn refund(
-        env: Env,
+    fn top_up_internal(
+        env: &Env,
+        funder: &Address,
         bounty_id: u64,
-        amount: Option<i128>,
-        recipient: Option<Address>,
-        mode: RefundMode,
+        amount: i128,
     ) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Get and verify escrow state
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        let caller = escrow.depositor.clone();
+            .ok_or(Error::BountyNotFound)?;
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
+        if !Self::is_active(&escrow.status) {
             return Err(Error::FundsNotLocked);
         }
 
-        // Verify deadline has passed
-        let now = env.ledger().timestamp();
-        let is_before_deadline = now < escrow.deadline;
-
-        // Determine refund amount and recipient
-        let refund_amount: i128;
-        let refund_recipient: Address;
-
-        match mode {
-            RefundMode::Full => {
-                refund_amount = escrow.remaining_amount;
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Partial => {
-                refund_amount = amount.unwrap_or(escrow.remaining_amount);
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Custom => {
-                refund_amount = amount.ok_or(Error::InvalidAmount)?;
-                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
-
-                // Custom refunds before deadline require admin approval
-                if is_before_deadline {
-                    if !env
-                        .storage()
-                        .persistent()
-                        .has(&DataKey::RefundApproval(bounty_id))
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-                    let approval: RefundApproval = env
-                        .storage()
-                        .persistent()
-                        .get(&DataKey::RefundApproval(bounty_id))
-                        .unwrap();
-
-                    // Verify approval matches request
-                    if approval.amount != refund_amount
-                        || approval.recipient != refund_recipient
-                        || approval.mode != mode
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-
-                    // Clear approval after use
-                    env.storage()
-                        .persistent()
-                        .remove(&DataKey::RefundApproval(bounty_id));
-                }
-            }
-        }
-
-        // Validate amount
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-
-        // Transfer funds back to depositor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Check contract balance
-        let contract_balance = client.balance(&env.current_contract_address());
-        if contract_balance < refund_amount {
-            return Err(Error::InsufficientFunds);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *funder != escrow.depositor && *funder != admin {
+            return Err(Error::Unauthorized);
         }
 
-        // Transfer funds
-        client.transfer(
-            &env.current_contract_address(),
-            &refund_recipient,
-            &refund_amount,
+        let new_amount = escrow
+            .amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        let new_remaining = escrow
+            .remaining_amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+
+        let token_addr = Self::escrow_token(env, &escrow);
+        let client = token::Client::new(env, &token_addr);
+        client.transfer(funder, &env.current_contract_address(), &amount);
+
+        escrow.amount = new_amount;
+        escrow.remaining_amount = new_remaining;
+        Self::store_escrow(env, bounty_id, &escrow);
+
+        emit_funds_topped_up(
+            env,
+            FundsToppedUp {
+                bounty_id,
+                amount,
+                new_amount: escrow.amount,
+                funder: funder.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
-        // Update escrow state
-        escrow.remaining_amount -= refund_amount;
+        Ok(())
+    }
 
-        // Add to refund history
-        let refund_record = RefundRecord {
-            amount: refund_amount,
-            recipient: refund_recipient.clone(),
-            mode: mode.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-        escrow.refund_history.push_back(refund_record);
+    /// Pushes a bounty's refund deadline further out (depositor only).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to extend
+    /// * `new_deadline` - New refund deadline, strictly after the current one
+    ///
+    /// # Returns
+    /// * `Ok(())` - Deadline extended
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow is not Locked or PartiallyReleased
+    /// * `Err(Error::InvalidDeadline)` - `new_deadline` is not after the current deadline
+    ///
+    /// # Events
+    /// Emits: `DeadlineExtended { bounty_id, old_deadline, new_deadline, timestamp }`
+    pub fn extend_deadline(env: Env, bounty_id: u64, new_deadline: u64) -> Result<(), Error> {
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
 
-        // Update status
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
+        escrow.depositor.require_auth();
+
+        if !matches!(
+            escrow.status,
+            EscrowStatus::Locked | EscrowStatus::PartiallyReleased
+        ) {
+            return Err(Error::FundsNotLocked);
+        }
+        if new_deadline <= escrow.deadline {
+            return Err(Error::InvalidDeadline);
         }
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        let old_deadline = escrow.deadline;
+        escrow.deadline = new_deadline;
+        Self::store_escrow(&env, bounty_id, &escrow);
 
-        // Emit refund event
-        emit_funds_refunded(
+        events::emit_deadline_extended(
             &env,
-            FundsRefunded {
+            events::DeadlineExtended {
                 bounty_id,
-                amount: refund_amount,
-                refund_to: refund_recipient,
+                old_deadline,
+                new_deadline,
                 timestamp: env.ledger().timestamp(),
-                refund_mode: mode.clone(),
-                remaining_amount: escrow.remaining_amount,
             },
         );
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        monitoring::track_operation(&env, symbol_short!("extend"), escrow.depositor, true);
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+        Ok(())
+    }
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+    /// Sets the grace window for submission-based deadline extensions (admin only).
+    ///
+    /// A submission registered less than `grace` seconds before a bounty's
+    /// deadline pushes that deadline out by `grace`, once per bounty. Zero
+    /// disables the extension.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    pub fn set_submission_grace(env: Env, grace: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SubmissionGrace, &grace);
 
         Ok(())
     }
 
-    // ========================================================================
-    // View Functions (Read-only)
-    // ========================================================================
+    /// Returns the submission grace window in seconds (0 when unset).
+    pub fn get_submission_grace(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SubmissionGrace)
+            .unwrap_or(0)
+    }
 
-    /// Retrieves escrow information for a specific bounty.
+    /// Records that work has been submitted for a bounty.
+    ///
+    /// Only the bounty's pinned contributor or the admin may register a
+    /// submission. If it arrives within the grace window before the deadline,
+    /// the deadline is extended by the grace window so the depositor cannot
+    /// refund while the work is being reviewed, and the submission's timestamp
+    /// is stored. The extension is one-time: submissions outside the window
+    /// leave no record, and none extend the deadline once one has.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
+    /// * `caller` - Pinned contributor or admin (must authorize)
+    /// * `bounty_id` - The bounty the work is for
     ///
     /// # Returns
-    /// * `Ok(Escrow)` - The complete escrow record
+    /// * `Ok(deadline)` - The bounty's (possibly extended) deadline
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow is not Locked or PartiallyReleased
+    /// * `Err(Error::Unauthorized)` - Caller is neither the pinned contributor nor admin
+    /// * `Err(Error::InvalidDeadline)` - The deadline has already passed
     ///
-    /// # Gas Cost
-    /// Very Low - Single storage read
-    ///
-    /// # Example
-    /// ```rust
-    /// let escrow_info = escrow_client.get_escrow_info(&42)?;
-    /// println!("Amount: {}", escrow_info.amount);
-    /// println!("Status: {:?}", escrow_info.status);
-    /// println!("Deadline: {}", escrow_info.deadline);
-    /// ```
-    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+    /// # Events
+    /// Emits: `DeadlineExtended` when the grace extension applies
+    pub fn register_submission(env: Env, caller: Address, bounty_id: u64) -> Result<u64, Error> {
+        caller.require_auth();
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
-        Ok(env
+
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
+            .ok_or(Error::BountyNotFound)?;
+
+        if !Self::is_active(&escrow.status) {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        let intended = Self::get_intended_contributor(env.clone(), bounty_id);
+        if caller != admin && intended != Some(caller.clone()) {
+            return Err(Error::Unauthorized);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= escrow.deadline {
+            return Err(Error::InvalidDeadline);
+        }
+
+        let submission_key = DataKey::Submission(bounty_id);
+        if env.storage().persistent().has(&submission_key) {
+            return Ok(escrow.deadline);
+        }
+
+        let grace = Self::get_submission_grace(env.clone());
+        if grace > 0 && escrow.deadline - now < grace {
+            env.storage().persistent().set(&submission_key, &now);
+
+            let old_deadline = escrow.deadline;
+            escrow.deadline = old_deadline.saturating_add(grace);
+            Self::store_escrow(&env, bounty_id, &escrow);
+
+            events::emit_deadline_extended(
+                &env,
+                events::DeadlineExtended {
+                    bounty_id,
+                    old_deadline,
+                    new_deadline: escrow.deadline,
+                    timestamp: now,
+                },
+            );
+        }
+
+        monitoring::track_operation(&env, symbol_short!("submit"), caller, true);
+
+        Ok(escrow.deadline)
+    }
+
+    /// Returns when the submission that extended a bounty's deadline was registered, if any.
+    pub fn get_submission(env: Env, bounty_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Submission(bounty_id))
     }
 
-    /// Returns the current token balance held by the contract.
+    /// Splits part of an escrow's remaining funds into a new escrow.
+    ///
+    /// The new escrow keeps the source's depositor, deadline, release cliff,
+    /// intended contributor and release limit, so both bounties can be
+    /// released or refunded independently afterwards.
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `caller` - Depositor of the source escrow or admin (must authorize)
+    /// * `source_id` - Escrow to take funds from
+    /// * `new_id` - Unused bounty ID for the new escrow
+    /// * `amount` - Amount to move into the new escrow
     ///
     /// # Returns
-    /// * `Ok(i128)` - Current contract token balance
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    ///
-    /// # Use Cases
-    /// - Monitoring total locked funds
-    /// - Verifying contract solvency
-    /// - Auditing and reconciliation
-    ///
-    /// # Gas Cost
-    /// Low - Token contract call
+    /// * `Ok(())` - Escrow split
+    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(Error::BountyNotFound)` - Source escrow doesn't exist
+    /// * `Err(Error::BountyExists)` - `new_id` is already in use
+    /// * `Err(Error::FundsNotLocked)` - Source escrow is no longer active
+    /// * `Err(Error::InsufficientFunds)` - Source has less than `amount` remaining
+    /// * `Err(Error::Unauthorized)` - Caller is neither depositor nor admin
+    /// * `Err(Error::TooManyActiveEscrows)` - Depositor is at the active escrow cap
+    pub fn split_bounty(
+        env: Env,
+        caller: Address,
+        source_id: u64,
+        new_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let mut source: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(source_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != source.depositor && caller != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if env.storage().persistent().has(&DataKey::Escrow(new_id)) {
+            return Err(Error::BountyExists);
+        }
+
+        if source.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount > source.amount || amount > source.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        Self::check_active_escrow_cap(&env, &source.depositor)?;
+
+        Self::enter_guard(&env, symbol_short!("split"));
+
+        source.amount -= amount;
+        source.remaining_amount -= amount;
+
+        let split = Escrow {
+            depositor: source.depositor.clone(),
+            amount,
+            status: EscrowStatus::Locked,
+            deadline: source.deadline,
+            refund_history: vec![&env],
+            remaining_amount: amount,
+            auto_refund_on_expiry: source.auto_refund_on_expiry,
+            token: source.token.clone(),
+        };
+
+        Self::store_escrow(&env, source_id, &source);
+        Self::store_escrow(&env, new_id, &split);
+        Self::register_bounty(&env, new_id, &source.depositor);
+
+        // The split funds stay behind the source's release cliff and pin
+        if let Some(cliff) = Self::get_release_cliff(env.clone(), source_id) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseCliff(new_id), &cliff);
+        }
+        if let Some(contributor) = Self::get_intended_contributor(env.clone(), source_id) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::IntendedContributor(new_id), &contributor);
+        }
+        if let Some(limit) = Self::get_release_limit(env.clone(), source_id) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseLimit(new_id), &limit);
+        }
+        if let Some(window) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ReleaseWindow>(&DataKey::ReleaseWindow(source_id))
+        {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseWindow(new_id), &window);
+        }
+
+        emit_bounty_split(
+            &env,
+            BountySplit {
+                source_id,
+                new_id,
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        monitoring::track_operation(&env, symbol_short!("split"), caller, true);
+
+        Ok(())
+    }
+
+    /// Merges the source escrow into the target escrow.
+    ///
+    /// Remaining funds are summed into the target and refund histories are
+    /// concatenated, keeping only the most recent `MAX_MERGED_HISTORY`
+    /// records. The source escrow is removed together with everything stored
+    /// for it: metadata, program tag, release limit and window, submission,
+    /// payout history and receipts.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Shared depositor or admin (must authorize)
+    /// * `target_id` - Escrow that receives the funds
+    /// * `source_id` - Escrow that is folded into the target
+    ///
+    /// # Returns
+    /// * `Ok(())` - Escrows merged
+    /// * `Err(Error::BountyNotFound)` - Either escrow doesn't exist
+    /// * `Err(Error::DuplicateBountyId)` - Target and source are the same bounty
+    /// * `Err(Error::FundsNotLocked)` - Either escrow is no longer active
+    /// * `Err(Error::Unauthorized)` - Depositors differ, or caller is neither depositor nor admin
+    /// * `Err(Error::InvalidDeadline)` - Target deadline is earlier than the source's
+    /// * `Err(Error::TokenMismatch)` - The escrows are denominated in different tokens
+    /// * `Err(Error::ContributorMismatch)` - The escrows are pinned to different contributors
+    /// * `Err(Error::ReleasePending)` - The target has a release awaiting confirmation
+    ///
+    /// # Security Considerations
+    /// - Only escrows denominated in the same token can be merged
+    /// - The target's deadline must not be earlier than the source's, so
+    ///   merging never makes the source funds refundable sooner
+    /// - The target takes the later of the two release cliffs
+    /// - The target takes the stricter of the two release limits, and the
+    ///   amounts already released in both current windows count against it
+    pub fn merge_bounties(
+        env: Env,
+        caller: Address,
+        target_id: u64,
+        source_id: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if target_id == source_id {
+            return Err(Error::DuplicateBountyId);
+        }
+
+        let mut target: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(target_id))
+            .ok_or(Error::BountyNotFound)?;
+        let source: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(source_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if target.depositor != source.depositor {
+            return Err(Error::Unauthorized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != target.depositor && caller != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if !Self::is_active(&target.status) || !Self::is_active(&source.status) {
+            return Err(Error::FundsNotLocked);
+        }
+        // A pending release was sized for the target alone
+        if Self::pending_release(&env, target_id).is_some() {
+            return Err(Error::ReleasePending);
+        }
+
+        if target.deadline < source.deadline {
+            return Err(Error::InvalidDeadline);
+        }
+        if Self::escrow_token(&env, &target) != Self::escrow_token(&env, &source) {
+            return Err(Error::TokenMismatch);
+        }
+        if Self::get_intended_contributor(env.clone(), target_id)
+            != Self::get_intended_contributor(env.clone(), source_id)
+        {
+            return Err(Error::ContributorMismatch);
+        }
+
+        Self::enter_guard(&env, symbol_short!("merge"));
+
+        target.amount += source.amount;
+        target.remaining_amount += source.remaining_amount;
+        if source.status == EscrowStatus::PartiallyRefunded {
+            target.status = EscrowStatus::PartiallyRefunded;
+        } else if target.status == EscrowStatus::Locked {
+            target.status = source.status.clone();
+        }
+
+        // Keep the later cliff so merged funds never become releasable sooner
+        let cliff = Self::get_release_cliff(env.clone(), target_id)
+            .max(Self::get_release_cliff(env.clone(), source_id));
+
+        // Keep the stricter release limit so merged funds never release faster
+        let limit = match (
+            Self::get_release_limit(env.clone(), target_id),
+            Self::get_release_limit(env.clone(), source_id),
+        ) {
+            (Some(a), Some(b)) => Some(ReleaseLimit {
+                max_amount: a.max_amount.min(b.max_amount),
+                window: a.window.max(b.window),
+            }),
+            (a, b) => a.or(b),
+        };
+        let window = match (
+            env.storage()
+                .persistent()
+                .get::<DataKey, ReleaseWindow>(&DataKey::ReleaseWindow(target_id)),
+            env.storage()
+                .persistent()
+                .get::<DataKey, ReleaseWindow>(&DataKey::ReleaseWindow(source_id)),
+        ) {
+            (Some(a), Some(b)) => Some(ReleaseWindow {
+                window_start: a.window_start.max(b.window_start),
+                released: a.released.saturating_add(b.released),
+            }),
+            (a, b) => a.or(b),
+        };
+
+        let mut history = target.refund_history.clone();
+        history.append(&source.refund_history);
+        while history.len() > MAX_MERGED_HISTORY {
+            history.pop_front();
+        }
+        target.refund_history = history;
+
+        Self::store_escrow(&env, target_id, &target);
+        Self::remove_escrow(&env, source_id);
+        Self::unregister_bounty(&env, source_id, &source.depositor);
+        Self::write_metadata(&env, source_id, None);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RefundApproval(source_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseCliff(source_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::IntendedContributor(source_id));
+        Self::clear_pending_release(&env, source_id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseLimit(source_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseWindow(source_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Submission(source_id));
+        for payout in Self::get_payout_history(env.clone(), source_id).iter() {
+            if let Some(receipt_id) = payout.receipt_id {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::Receipt(source_id, receipt_id));
+            }
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PayoutHistory(source_id));
+        if let Some(cliff) = cliff {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseCliff(target_id), &cliff);
+        }
+        if let Some(limit) = limit {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseLimit(target_id), &limit);
+        }
+        if let Some(window) = window {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseWindow(target_id), &window);
+        }
+
+        if let Some(program_id) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, String>(&DataKey::BountyProgram(source_id))
+        {
+            let bounties: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ProgramBounties(program_id.clone()))
+                .unwrap_or(vec![&env]);
+            let mut remaining = vec![&env];
+            for id in bounties.iter() {
+                if id != source_id {
+                    remaining.push_back(id);
+                }
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::ProgramBounties(program_id), &remaining);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::BountyProgram(source_id));
+        }
+
+        emit_bounties_merged(
+            &env,
+            BountiesMerged {
+                target_id,
+                source_id,
+                amount: source.remaining_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        monitoring::track_operation(&env, symbol_short!("merge"), caller, true);
+
+        Ok(())
+    }
+
+    /// Releases escrowed funds to a contributor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release funds for
+    /// * `contributor` - Address to receive the funds
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully released
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::Unauthorized)` - Caller is not the admin
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
+    ///
+    /// # State Changes
+    /// - Transfers tokens from contract to contributor
+    /// - Updates escrow status to Released
+    /// - Emits FundsReleased event
+    ///
+    /// # Authorization
+    /// - **CRITICAL**: Only admin can call this function
+    /// - Admin address must match initialization value
+    ///
+    /// # Security Considerations
+    /// - This is the most security-critical function
+    /// - Admin should verify task completion off-chain before calling
+    /// - Once released, funds cannot be retrieved
+    /// - Recipient address should be verified carefully
+    /// - Consider implementing multi-sig for admin
+    ///
+    /// # Events
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp, receipt_id }`
+    ///
+    /// # Receipts
+    /// `receipt_id` lets the backend tie the payout to an off-chain invoice. It
+    /// is stored on the `PayoutRecord` and emitted with `FundsReleased`, and a
+    /// receipt can only be used once per bounty (`Error::DuplicateReceipt`).
     ///
     /// # Example
     /// ```rust
-    /// let balance = escrow_client.get_balance()?;
-    /// println!("Total locked: {} stroops", balance);
+    /// // After verifying task completion off-chain:
+    /// let contributor = Address::from_string("GCONTRIB...");
+    ///
+    /// // Admin calls release
+    /// escrow_client.release_funds(&42, &contributor, &None)?;
+    /// // Funds transferred to contributor, escrow marked as Released
     /// ```
-    pub fn get_balance(env: Env) -> Result<i128, Error> {
-        if !env.storage().instance().has(&DataKey::Token) {
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage update + event emission
+    ///
+    /// # Best Practices
+    /// 1. Verify contributor identity off-chain
+    /// 2. Confirm task completion before release
+    /// 3. Log release decisions in backend system
+    /// 4. Monitor release events for anomalies
+    /// 5. Consider implementing release delays for high-value bounties
+    ///
+    /// # Optimistic Release
+    /// When a release delay is set via `set_release_delay`, this only records
+    /// a `PendingRelease`; funds move through `execute_release` once the delay
+    /// has passed without a depositor objection.
+    pub fn release_funds(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        receipt_id: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        let receipt_id = receipt_id.map(Bytes::from);
+        if Self::outflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        Self::enter_guard(&env, symbol_short!("release"));
+
+        // Ensure contract is initialized
+        if !env.storage().instance().has(&DataKey::Admin) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::NotInitialized);
         }
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        Ok(client.balance(&env.current_contract_address()))
+
+        // Verify admin authorization
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+
+        // Apply rate limiting
+        if let Err(err) =
+            anti_abuse::check_rate_limit(&env, admin.clone(), symbol_short!("release"))
+        {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            return Err(err);
+        }
+
+        admin.require_auth();
+
+        // Verify bounty exists
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        // A reused receipt means this payout already happened; report that
+        // rather than the escrow's current status
+        if let Err(err) = Self::check_receipt_unused(&env, bounty_id, &receipt_id) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
+        if escrow.status != EscrowStatus::Locked {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::FundsNotLocked);
+        }
+
+        if let Err(err) = Self::check_release_cliff(&env, bounty_id)
+            .and_then(|_| Self::check_intended_contributor(&env, bounty_id, &contributor))
+        {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
+        // With a confirmation delay configured, only record the release intent
+        let delay: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReleaseDelay)
+            .unwrap_or(0);
+        if delay > 0 {
+            let result =
+                Self::record_pending_release(&env, bounty_id, &contributor, delay, receipt_id);
+            monitoring::track_operation(&env, symbol_short!("release"), admin, result.is_ok());
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return result;
+        }
+
+        if let Err(err) = Self::complete_release(&env, bounty_id, escrow, &contributor, receipt_id)
+        {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+
+        // Track performance
+        monitoring::emit_performance(&env, symbol_short!("release"));
+        Ok(())
+    }
+
+    /// Pays out the full escrow to `contributor`, collecting the release fee if enabled.
+    fn complete_release(
+        env: &Env,
+        bounty_id: u64,
+        mut escrow: Escrow,
+        contributor: &Address,
+        receipt_id: Option<Bytes>,
+    ) -> Result<(), Error> {
+        Self::check_receipt_unused(env, bounty_id, &receipt_id)?;
+        Self::consume_release_allowance(env, bounty_id, escrow.amount)?;
+
+        // Transfer funds to contributor
+        let token_addr = Self::escrow_token(env, &escrow);
+        let client = token::Client::new(env, &token_addr);
+        escrow.status = EscrowStatus::Released;
+        Self::store_escrow(env, bounty_id, &escrow);
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = if fee_config.fee_enabled
+            && fee_config.release_fee_rate > 0
+            && !Self::consume_fee_waiver(env, contributor)
+        {
+            Self::calculate_fee(escrow.amount, fee_config.release_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = escrow.amount - fee_amount;
+
+        // Transfer net amount to contributor
+        client.transfer(&env.current_contract_address(), contributor, &net_amount);
+
+        // Transfer fee to fee recipient if applicable
+        if fee_amount > 0 {
+            let recipient = Self::collect_fee(
+                env,
+                &client,
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                fee_amount,
+            );
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: fee_amount,
+                    fee_rate: fee_config.release_fee_rate,
+                    recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        // Update escrow state - mark as released and set remaining_amount to 0
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        Self::store_escrow(env, bounty_id, &escrow);
+
+        // Emit release event
+        emit_funds_released(
+            env,
+            FundsReleased {
+                bounty_id,
+                amount: net_amount, // Emit net amount (after fee)
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp(),
+                receipt_id: receipt_id.clone(),
+            },
+        );
+
+        if let Some(receipt) = &receipt_id {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Receipt(bounty_id, receipt.clone()), &true);
+        }
+        Self::record_payout(env, bounty_id, contributor, net_amount, receipt_id);
+        Self::record_completion(env, contributor);
+
+        Ok(())
+    }
+
+    /// Rejects a receipt ID that an earlier payout of this bounty already recorded.
+    fn check_receipt_unused(
+        env: &Env,
+        bounty_id: u64,
+        receipt_id: &Option<Bytes>,
+    ) -> Result<(), Error> {
+        match receipt_id {
+            Some(receipt)
+                if env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::Receipt(bounty_id, receipt.clone())) =>
+            {
+                Err(Error::DuplicateReceipt)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // ========================================================================
+    // Optimistic Release
+    // ========================================================================
+
+    /// Sets how long a release waits for depositor objections (admin only).
+    ///
+    /// A delay of zero disables optimistic release, so `release_funds` pays
+    /// out immediately again. Releases already pending keep their original
+    /// `executable_at`. While a delay is set, `release_percent`,
+    /// `release_split`, `batch_release_funds` and `process_release_queue` fail
+    /// with `ReleaseDelayActive`.
+    pub fn set_release_delay(env: Env, delay: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::ReleaseDelay, &delay);
+
+        Ok(())
+    }
+
+    /// Returns the configured release confirmation delay in seconds.
+    pub fn get_release_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReleaseDelay)
+            .unwrap_or(0)
+    }
+
+    /// Returns the release awaiting confirmation for a bounty, if any.
+    pub fn get_pending_release(env: Env, bounty_id: u64) -> Option<PendingRelease> {
+        Self::pending_release(&env, bounty_id)
+    }
+
+    fn drain_epoch(env: &Env, token: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DrainEpoch(token.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Loads a bounty's pending release, ignoring one recorded before an
+    /// emergency withdrawal drained the escrow's token.
+    fn pending_release(env: &Env, bounty_id: u64) -> Option<PendingRelease> {
+        let pending: PendingRelease = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingRelease(bounty_id))?;
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))?;
+        let token = Self::escrow_token(env, &escrow);
+        if pending.drain_epoch != Self::drain_epoch(env, &token) {
+            return None;
+        }
+        Some(pending)
+    }
+
+    /// Only `release_funds` goes through the confirmation delay, so the other
+    /// release paths are refused while one is configured.
+    fn check_no_release_delay(env: &Env) -> Result<(), Error> {
+        if Self::get_release_delay(env.clone()) > 0 {
+            return Err(Error::ReleaseDelayActive);
+        }
+        Ok(())
+    }
+
+    /// Drops a bounty's pending release once its funds have left the escrow
+    /// some other way.
+    fn clear_pending_release(env: &Env, bounty_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingRelease(bounty_id));
+    }
+
+    fn record_pending_release(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+        delay: u64,
+        receipt_id: Option<Bytes>,
+    ) -> Result<(), Error> {
+        if Self::pending_release(env, bounty_id).is_some() {
+            return Err(Error::ReleasePending);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let token = Self::escrow_token(env, &escrow);
+        let now = env.ledger().timestamp();
+        let pending = PendingRelease {
+            contributor: contributor.clone(),
+            requested_at: now,
+            executable_at: now.saturating_add(delay),
+            objected: false,
+            receipt_id,
+            drain_epoch: Self::drain_epoch(env, &token),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingRelease(bounty_id), &pending);
+
+        events::emit_release_requested(
+            env,
+            events::ReleaseRequested {
+                bounty_id,
+                contributor: contributor.clone(),
+                executable_at: pending.executable_at,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Objects to a pending release (depositor only).
+    ///
+    /// The release is put on hold until the admin resolves it with
+    /// `resolve_release`.
+    ///
+    /// # Errors
+    /// * PendingReleaseNotFound - if no release is awaiting confirmation
+    /// * ReleaseObjected - if the depositor already objected
+    pub fn object_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        let key = DataKey::PendingRelease(bounty_id);
+        let mut pending =
+            Self::pending_release(&env, bounty_id).ok_or(Error::PendingReleaseNotFound)?;
+        if pending.objected {
+            return Err(Error::ReleaseObjected);
+        }
+
+        pending.objected = true;
+        env.storage().persistent().set(&key, &pending);
+
+        events::emit_release_objected(
+            &env,
+            events::ReleaseObjected {
+                bounty_id,
+                depositor: escrow.depositor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("object"), escrow.depositor, true);
+
+        Ok(())
+    }
+
+    /// Pays out a pending release once its confirmation delay has passed.
+    ///
+    /// Callable by anyone (e.g. a keeper bot), since the admin already
+    /// authorized the release when it was requested.
+    ///
+    /// # Errors
+    /// * PendingReleaseNotFound - if no release is awaiting confirmation
+    /// * ReleaseObjected - if the depositor objected
+    /// * ReleaseNotReady - if the delay has not passed yet
+    /// * FundsNotLocked - if the escrow was refunded in the meantime
+    /// * ContractPaused - if outflows are paused
+    pub fn execute_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::outflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let pending =
+            Self::pending_release(&env, bounty_id).ok_or(Error::PendingReleaseNotFound)?;
+        if pending.objected {
+            return Err(Error::ReleaseObjected);
+        }
+        if env.ledger().timestamp() < pending.executable_at {
+            return Err(Error::ReleaseNotReady);
+        }
+
+        Self::finish_pending_release(&env, bounty_id, &pending)
+    }
+
+    /// Settles a pending release, typically after a depositor objection (admin only).
+    ///
+    /// Approving pays the contributor immediately; rejecting drops the
+    /// pending release and leaves the funds locked. Approving is blocked while
+    /// outflows are paused (`ContractPaused`); rejecting is not.
+    pub fn resolve_release(env: Env, bounty_id: u64, approve: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if approve && Self::outflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let key = DataKey::PendingRelease(bounty_id);
+        let pending =
+            Self::pending_release(&env, bounty_id).ok_or(Error::PendingReleaseNotFound)?;
+
+        if approve {
+            Self::finish_pending_release(&env, bounty_id, &pending)?;
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        events::emit_release_resolved(
+            &env,
+            events::ReleaseResolved {
+                bounty_id,
+                approved: approve,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("resolve"), admin, true);
+
+        Ok(())
+    }
+
+    fn finish_pending_release(
+        env: &Env,
+        bounty_id: u64,
+        pending: &PendingRelease,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        Self::enter_guard(env, symbol_short!("release"));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingRelease(bounty_id));
+        let result = Self::complete_release(
+            env,
+            bounty_id,
+            escrow,
+            &pending.contributor,
+            pending.receipt_id.clone(),
+        );
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        result
+    }
+
+    /// Releases a percentage of the remaining escrowed amount to a contributor (admin only).
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The bounty to release from
+    /// * `contributor` - Recipient of the released funds
+    /// * `bps` - Share of `remaining_amount` to release, in basis points (10000 = 100%)
+    ///
+    /// The released amount is `remaining_amount * bps / 10000`, rounded down.
+    /// Releasing 10000 bps always pays out the exact remainder, so a series of
+    /// milestone payouts ending at 100% leaves no dust behind.
+    ///
+    /// # Errors
+    /// * `InvalidPercentage` - `bps` is zero or greater than 10000
+    /// * `FundsNotLocked` - Escrow no longer holds funds
+    /// * `InvalidAmount` - The computed amount rounds down to zero
+    /// * `ReleaseDelayActive` - A release confirmation delay is configured
+    ///
+    /// # Events
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
+    pub fn release_percent(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        bps: u32,
+    ) -> Result<i128, Error> {
+        if Self::outflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        Self::check_no_release_delay(&env)?;
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if let Err(err) =
+            anti_abuse::check_rate_limit(&env, admin.clone(), symbol_short!("rel_pct"))
+        {
+            monitoring::track_operation(&env, symbol_short!("rel_pct"), admin.clone(), false);
+            return Err(err);
+        }
+        admin.require_auth();
+
+        if bps == 0 || bps as i128 > BASIS_POINTS {
+            return Err(Error::InvalidPercentage);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !Self::is_active(&escrow.status) {
+            monitoring::track_operation(&env, symbol_short!("rel_pct"), admin, false);
+            return Err(Error::FundsNotLocked);
+        }
+        if let Err(err) = Self::check_release_cliff(&env, bounty_id)
+            .and_then(|_| Self::check_intended_contributor(&env, bounty_id, &contributor))
+        {
+            monitoring::track_operation(&env, symbol_short!("rel_pct"), admin, false);
+            return Err(err);
+        }
+
+        let amount = if bps as i128 == BASIS_POINTS {
+            escrow.remaining_amount
+        } else {
+            escrow
+                .remaining_amount
+                .checked_mul(bps as i128)
+                .and_then(|x| x.checked_div(BASIS_POINTS))
+                .ok_or(Error::InvalidAmount)?
+        };
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::consume_release_allowance(&env, bounty_id, amount)?;
+        Self::enter_guard(&env, symbol_short!("release"));
+        Self::release_amount(&env, bounty_id, escrow, &contributor, amount);
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        monitoring::track_operation(&env, symbol_short!("rel_pct"), admin, true);
+
+        Ok(amount)
+    }
+
+    /// Pays `amount` of an escrow's remainder to `contributor`, collecting the
+    /// release fee if enabled. Callers validate the amount and hold the guard.
+    fn release_amount(
+        env: &Env,
+        bounty_id: u64,
+        mut escrow: Escrow,
+        contributor: &Address,
+        amount: i128,
+    ) {
+        escrow.remaining_amount -= amount;
+        escrow.status = if escrow.remaining_amount == 0 {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::PartiallyReleased
+        };
+        Self::store_escrow(env, bounty_id, &escrow);
+        if escrow.status == EscrowStatus::Released {
+            Self::record_completion(env, contributor);
+        }
+
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = if fee_config.fee_enabled
+            && fee_config.release_fee_rate > 0
+            && !Self::consume_fee_waiver(env, contributor)
+        {
+            Self::calculate_fee(amount, fee_config.release_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = amount - fee_amount;
+
+        let token_addr = Self::escrow_token(env, &escrow);
+        let client = token::Client::new(env, &token_addr);
+        client.transfer(&env.current_contract_address(), contributor, &net_amount);
+        if fee_amount > 0 {
+            let recipient = Self::collect_fee(
+                env,
+                &client,
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                fee_amount,
+            );
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: fee_amount,
+                    fee_rate: fee_config.release_fee_rate,
+                    recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        emit_funds_released(
+            env,
+            FundsReleased {
+                bounty_id,
+                amount: net_amount,
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp(),
+                receipt_id: None,
+            },
+        );
+
+        Self::record_payout(env, bounty_id, contributor, net_amount, None);
+    }
+
+    fn record_payout(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+        amount: i128,
+        receipt_id: Option<Bytes>,
+    ) {
+        let key = DataKey::PayoutHistory(bounty_id);
+        let mut history: Vec<PayoutRecord> =
+            env.storage().persistent().get(&key).unwrap_or(vec![env]);
+        history.push_back(PayoutRecord {
+            recipient: recipient.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+            receipt_id,
+        });
+        env.storage().persistent().set(&key, &history);
+    }
+
+    /// Returns every release paid out of a bounty, oldest first.
+    pub fn get_payout_history(env: Env, bounty_id: u64) -> Vec<PayoutRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutHistory(bounty_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Splits a release of one bounty across several contributors (admin only).
+    ///
+    /// Each recipient is paid through the same path as `release_percent`, so
+    /// the release fee applies per transfer and one `FundsReleased` event and
+    /// one `PayoutRecord` are produced per recipient. The escrow ends up
+    /// `Released` when the amounts cover the whole remainder and
+    /// `PartiallyReleased` otherwise.
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The bounty to release from
+    /// * `recipients` - Co-authors to pay
+    /// * `amounts` - Gross amount for each recipient, in the same order
+    ///
+    /// # Errors
+    /// * `InvalidBatchSize` - No recipients, or more than the max batch size
+    /// * `BatchSizeMismatch` - `recipients` and `amounts` differ in length
+    /// * `InvalidAmount` - An amount is zero or negative
+    /// * `InsufficientFunds` - The amounts sum to more than `remaining_amount`
+    /// * `FundsNotLocked` - Escrow can no longer be released
+    /// * `ReleaseDelayActive` - A release confirmation delay is configured
+    pub fn release_split(
+        env: Env,
+        bounty_id: u64,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Result<(), Error> {
+        if Self::outflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        Self::check_no_release_delay(&env)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if let Err(err) =
+            anti_abuse::check_rate_limit(&env, admin.clone(), symbol_short!("rel_split"))
+        {
+            monitoring::track_operation(&env, symbol_short!("rel_split"), admin.clone(), false);
+            return Err(err);
+        }
+        admin.require_auth();
+
+        if recipients.is_empty() || recipients.len() > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+        if recipients.len() != amounts.len() {
+            return Err(Error::BatchSizeMismatch);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !Self::is_releasable(&escrow.status) {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::check_release_cliff(&env, bounty_id)?;
+
+        let mut total: i128 = 0;
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            Self::check_intended_contributor(&env, bounty_id, &recipient)?;
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+        }
+        if total > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        Self::consume_release_allowance(&env, bounty_id, total)?;
+        Self::enter_guard(&env, symbol_short!("release"));
+
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .unwrap();
+            Self::release_amount(&env, bounty_id, escrow, &recipient, amount);
+        }
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        monitoring::track_operation(&env, symbol_short!("rel_split"), admin, true);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Release Queue
+    // ========================================================================
+
+    /// Queues a release of `amount` from a bounty to `contributor` (admin only).
+    ///
+    /// Queued releases are paid strictly in request order by
+    /// `process_release_queue`, so the admin records intent now and any
+    /// keeper can execute it later.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Escrow can no longer be released
+    /// * `InvalidAmount` - Amount is zero or negative
+    /// * `InsufficientFunds` - Amount plus releases already queued for the bounty
+    ///   exceeds its remaining amount
+    /// * `ContributorMismatch` - Bounty is pinned to a different contributor
+    /// * `ReleaseQueueFull` - Queue already holds `MAX_RELEASE_QUEUE` entries
+    pub fn request_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !Self::is_releasable(&escrow.status) {
+            return Err(Error::FundsNotLocked);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::check_intended_contributor(&env, bounty_id, &contributor)?;
+
+        let mut queue: Vec<QueuedRelease> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseQueue)
+            .unwrap_or(vec![&env]);
+        if queue.len() >= MAX_RELEASE_QUEUE {
+            return Err(Error::ReleaseQueueFull);
+        }
+
+        let mut queued: i128 = amount;
+        for entry in queue.iter() {
+            if entry.bounty_id == bounty_id {
+                queued += entry.amount;
+            }
+        }
+        if queued > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        queue.push_back(QueuedRelease {
+            bounty_id,
+            contributor,
+            amount,
+            requested_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseQueue, &queue);
+
+        Ok(())
+    }
+
+    /// Executes up to `max` queued releases in request order. Callable by anyone.
+    ///
+    /// Entries whose escrow can no longer cover them (refunded, released or
+    /// drained in the meantime) are dropped. Processing stops at the first
+    /// entry that is only temporarily blocked, by a release cliff or a release
+    /// limit, so later requests never overtake it.
+    ///
+    /// # Returns
+    /// Number of releases paid out
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `ReleaseDelayActive` - A release confirmation delay is configured
+    pub fn process_release_queue(env: Env, max: u32) -> Result<u32, Error> {
+        if Self::outflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        Self::check_no_release_delay(&env)?;
+
+        let mut queue: Vec<QueuedRelease> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseQueue)
+            .unwrap_or(vec![&env]);
+
+        Self::enter_guard(&env, symbol_short!("rel_q"));
+
+        let mut examined = 0u32;
+        let mut released = 0u32;
+        while examined < max {
+            let entry = match queue.first() {
+                Some(entry) => entry,
+                None => break,
+            };
+            examined += 1;
+
+            let escrow: Option<Escrow> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(entry.bounty_id));
+            let escrow = match escrow {
+                Some(escrow)
+                    if Self::is_releasable(&escrow.status)
+                        && entry.amount <= escrow.remaining_amount =>
+                {
+                    escrow
+                }
+                _ => {
+                    queue.pop_front();
+                    continue;
+                }
+            };
+
+            if Self::check_release_cliff(&env, entry.bounty_id)
+                .and_then(|_| Self::consume_release_allowance(&env, entry.bounty_id, entry.amount))
+                .is_err()
+            {
+                break;
+            }
+
+            queue.pop_front();
+            Self::release_amount(
+                &env,
+                entry.bounty_id,
+                escrow,
+                &entry.contributor,
+                entry.amount,
+            );
+            released += 1;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseQueue, &queue);
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        Ok(released)
+    }
+
+    /// Returns the number of releases waiting in the release queue.
+    pub fn get_release_queue_length(env: Env) -> u32 {
+        let queue: Vec<QueuedRelease> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseQueue)
+            .unwrap_or(vec![&env]);
+        queue.len()
+    }
+
+    /// Sets where refunds may be sent (admin only).
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    pub fn set_refund_policy(env: Env, policy: RefundPolicy) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundPolicy, &policy);
+
+        Ok(())
+    }
+
+    /// Returns the refund destination policy (`DepositorOnly` when unset).
+    pub fn get_refund_policy(env: Env) -> RefundPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundPolicy)
+            .unwrap_or(RefundPolicy::DepositorOnly)
+    }
+
+    /// Rejects a refund to anyone other than the depositor unless the policy
+    /// allows it. Callers must have the depositor's authorization before
+    /// redirecting funds; it is not requested here so a caller that already
+    /// holds it does not ask twice.
+    fn check_refund_destination(
+        env: &Env,
+        escrow: &Escrow,
+        recipient: &Address,
+    ) -> Result<(), Error> {
+        if *recipient == escrow.depositor {
+            return Ok(());
+        }
+        match Self::get_refund_policy(env.clone()) {
+            RefundPolicy::DepositorOnly => Err(Error::RefundDestinationNotAllowed),
+            RefundPolicy::DepositorOrApproved => Ok(()),
+        }
+    }
+
+    /// Sets how long refund approvals stay usable after being granted (admin only).
+    ///
+    /// Applies to approvals already granted as well, since expiry is checked
+    /// against `approved_at` when the approval is used.
+    ///
+    /// # Errors
+    /// * NotInitialized - if contract is not initialized
+    /// * InvalidDeadline - if `seconds` is zero
+    pub fn set_refund_approval_expiry(env: Env, seconds: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if seconds == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundApprovalExpiry, &seconds);
+
+        Ok(())
+    }
+
+    /// Returns how long refund approvals stay usable, `REFUND_APPROVAL_EXPIRY` unless overridden.
+    pub fn get_refund_approval_expiry(env: Env) -> u64 {
+        Self::refund_approval_expiry(&env)
+    }
+
+    fn refund_approval_expiry(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundApprovalExpiry)
+            .unwrap_or(REFUND_APPROVAL_EXPIRY)
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval. The approval expires
+    /// `get_refund_approval_expiry` seconds after it is granted.
+    pub fn approve_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if !Self::is_active(&escrow.status) {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount <= 0 || amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: admin.clone(),
+            approved_at: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+
+        Ok(())
+    }
+
+    /// Refund funds with support for Full, Partial, and Custom refunds.
+    /// - Full: refunds all remaining funds to depositor
+    /// - Partial: refunds specified amount to depositor
+    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
+    pub fn refund(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        if Self::refunds_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        let caller = escrow.depositor.clone();
+
+        if !Self::is_active(&escrow.status) {
+            return Err(Error::FundsNotLocked);
+        }
+
+        // Verify deadline has passed
+        let now = env.ledger().timestamp();
+        let is_before_deadline = now < escrow.deadline;
+
+        // Determine refund amount and recipient
+        let refund_amount: i128;
+        let refund_recipient: Address;
+
+        match mode {
+            RefundMode::Full => {
+                refund_amount = escrow.remaining_amount;
+                refund_recipient = escrow.depositor.clone();
+                if is_before_deadline {
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            RefundMode::Partial => {
+                refund_amount = amount.unwrap_or(escrow.remaining_amount);
+                refund_recipient = escrow.depositor.clone();
+                if is_before_deadline {
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            RefundMode::Custom => {
+                refund_amount = amount.ok_or(Error::InvalidAmount)?;
+                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
+
+                // Custom refunds before deadline require admin approval
+                if is_before_deadline {
+                    if !env
+                        .storage()
+                        .persistent()
+                        .has(&DataKey::RefundApproval(bounty_id))
+                    {
+                        return Err(Error::RefundNotApproved);
+                    }
+                    let approval: RefundApproval = env
+                        .storage()
+                        .persistent()
+                        .get(&DataKey::RefundApproval(bounty_id))
+                        .unwrap();
+
+                    // Stale approvals must be re-issued by the admin
+                    if now
+                        > approval
+                            .approved_at
+                            .saturating_add(Self::refund_approval_expiry(&env))
+                    {
+                        return Err(Error::RefundApprovalExpired);
+                    }
+
+                    // Verify approval matches request
+                    if approval.amount != refund_amount
+                        || approval.recipient != refund_recipient
+                        || approval.mode != mode
+                    {
+                        return Err(Error::RefundNotApproved);
+                    }
+
+                    // Clear approval after use
+                    env.storage()
+                        .persistent()
+                        .remove(&DataKey::RefundApproval(bounty_id));
+                }
+
+                Self::check_refund_destination(&env, &escrow, &refund_recipient)?;
+                if refund_recipient != escrow.depositor {
+                    escrow.depositor.require_auth();
+                }
+            }
+        }
+
+        // Validate amount
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Transfer funds back to depositor
+        let token_addr = Self::escrow_token(&env, &escrow);
+        let client = token::Client::new(&env, &token_addr);
+
+        // Check contract balance
+        let contract_balance = client.balance(&env.current_contract_address());
+        if contract_balance < refund_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        Self::enter_guard(&env, symbol_short!("refund"));
+
+        // Transfer funds
+        client.transfer(
+            &env.current_contract_address(),
+            &refund_recipient,
+            &refund_amount,
+        );
+
+        // Update escrow state
+        escrow.remaining_amount -= refund_amount;
+
+        // Add to refund history
+        let refund_record = RefundRecord {
+            amount: refund_amount,
+            recipient: refund_recipient.clone(),
+            mode: mode.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        escrow.refund_history.push_back(refund_record);
+
+        // Update status
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+            Self::clear_pending_release(&env, bounty_id);
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
+
+        Self::store_escrow(&env, bounty_id, &escrow);
+
+        // Emit refund event
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                bounty_id,
+                amount: refund_amount,
+                refund_to: refund_recipient,
+                timestamp: env.ledger().timestamp(),
+                refund_mode: mode.clone(),
+                remaining_amount: escrow.remaining_amount,
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+
+        // Track performance
+        monitoring::emit_performance(&env, symbol_short!("refund"));
+
+        Ok(())
+    }
+
+    /// Refunds part of the remaining escrow to the depositor (admin only).
+    ///
+    /// Unlike `refund` with `RefundMode::Partial`, this does not wait for the
+    /// deadline, so the admin can hand back unused budget while the bounty is
+    /// still open. The escrow stays releasable until `remaining_amount` hits zero.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `amount` is not positive or exceeds `remaining_amount`
+    /// * `FundsNotLocked` - Escrow no longer holds funds
+    /// * `ContractPaused` - Refunds are paused
+    pub fn partial_refund(env: Env, bounty_id: u64, amount: i128) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if Self::refunds_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !Self::is_active(&escrow.status) {
+            monitoring::track_operation(&env, symbol_short!("part_ref"), admin, false);
+            return Err(Error::FundsNotLocked);
+        }
+        if amount <= 0 || amount > escrow.remaining_amount {
+            monitoring::track_operation(&env, symbol_short!("part_ref"), admin, false);
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::enter_guard(&env, symbol_short!("refund"));
+
+        escrow.remaining_amount -= amount;
+        escrow.refund_history.push_back(RefundRecord {
+            amount,
+            recipient: escrow.depositor.clone(),
+            mode: RefundMode::Partial,
+            timestamp: env.ledger().timestamp(),
+        });
+        escrow.status = if escrow.remaining_amount == 0 {
+            Self::clear_pending_release(&env, bounty_id);
+            EscrowStatus::Refunded
+        } else {
+            EscrowStatus::PartiallyRefunded
+        };
+        Self::store_escrow(&env, bounty_id, &escrow);
+
+        let token_addr = Self::escrow_token(&env, &escrow);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &escrow.depositor, &amount);
+
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                bounty_id,
+                amount,
+                refund_to: escrow.depositor.clone(),
+                timestamp: env.ledger().timestamp(),
+                refund_mode: RefundMode::Partial,
+                remaining_amount: escrow.remaining_amount,
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        monitoring::track_operation(&env, symbol_short!("part_ref"), admin, true);
+
+        Ok(())
+    }
+
+    /// Executes the refund previously approved with `approve_refund` (depositor only).
+    ///
+    /// The stored approval defines the amount, recipient and mode, and is
+    /// cleared once paid, so each approval can be executed once. Works before
+    /// the deadline, which is what distinguishes it from a plain `refund`.
+    ///
+    /// # Errors
+    /// * `RefundNotApproved` - No approval is stored, or a Full/Partial approval
+    ///   names a recipient other than the depositor, or a Full approval no longer
+    ///   matches the remaining amount
+    /// * `RefundApprovalExpired` - The approval is older than the expiry window
+    /// * `InvalidAmount` - The approved amount exceeds what is left in escrow
+    /// * `ContractPaused` - Refunds are paused
+    pub fn execute_approved_refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::refunds_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        let approval: RefundApproval = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundApproval(bounty_id))
+            .ok_or(Error::RefundNotApproved)?;
+
+        if !Self::is_active(&escrow.status) {
+            return Err(Error::FundsNotLocked);
+        }
+        let now = env.ledger().timestamp();
+        if now
+            > approval
+                .approved_at
+                .saturating_add(Self::refund_approval_expiry(&env))
+        {
+            return Err(Error::RefundApprovalExpired);
+        }
+        match approval.mode {
+            RefundMode::Full => {
+                if approval.recipient != escrow.depositor
+                    || approval.amount != escrow.remaining_amount
+                {
+                    return Err(Error::RefundNotApproved);
+                }
+            }
+            RefundMode::Partial => {
+                if approval.recipient != escrow.depositor {
+                    return Err(Error::RefundNotApproved);
+                }
+            }
+            RefundMode::Custom => {
+                Self::check_refund_destination(&env, &escrow, &approval.recipient)?;
+            }
+        }
+        if approval.amount <= 0 || approval.amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::enter_guard(&env, symbol_short!("refund"));
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RefundApproval(bounty_id));
+
+        escrow.remaining_amount -= approval.amount;
+        escrow.refund_history.push_back(RefundRecord {
+            amount: approval.amount,
+            recipient: approval.recipient.clone(),
+            mode: approval.mode.clone(),
+            timestamp: now,
+        });
+        escrow.status = if escrow.remaining_amount == 0 {
+            Self::clear_pending_release(&env, bounty_id);
+            EscrowStatus::Refunded
+        } else {
+            EscrowStatus::PartiallyRefunded
+        };
+        Self::store_escrow(&env, bounty_id, &escrow);
+
+        let token_addr = Self::escrow_token(&env, &escrow);
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &approval.recipient,
+            &approval.amount,
+        );
+
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                bounty_id,
+                amount: approval.amount,
+                refund_to: approval.recipient,
+                timestamp: now,
+                refund_mode: approval.mode,
+                remaining_amount: escrow.remaining_amount,
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        monitoring::track_operation(&env, symbol_short!("exec_ref"), escrow.depositor, true);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // View Functions (Read-only)
+    // ========================================================================
+
+    /// Retrieves escrow information for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Escrow)` - The complete escrow record
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage read
+    ///
+    /// # Example
+    /// ```rust
+    /// let escrow_info = escrow_client.get_escrow_info(&42)?;
+    /// println!("Amount: {}", escrow_info.amount);
+    /// println!("Status: {:?}", escrow_info.status);
+    /// println!("Deadline: {}", escrow_info.deadline);
+    /// ```
+    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap())
+    }
+
+    /// Returns the token a bounty is denominated in.
+    ///
+    /// Escrows locked before per-escrow tokens report the contract's default token.
+    pub fn get_escrow_token(env: Env, bounty_id: u64) -> Result<Address, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        Ok(Self::escrow_token(&env, &escrow))
+    }
+
+    fn escrow_token(env: &Env, escrow: &Escrow) -> Address {
+        match &escrow.token {
+            Some(token) => token.clone(),
+            None => env.storage().instance().get(&DataKey::Token).unwrap(),
+        }
+    }
+
+    /// Returns how often an operation has completed and when it last ran.
+    ///
+    /// `last_called` is a ledger timestamp in seconds; see
+    /// `monitoring::PerformanceStats` for why no duration is reported.
+    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
+        monitoring::get_performance_stats(&env, function_name)
+    }
+
+    /// Returns operation, unique-user and error counters for this contract.
+    pub fn get_analytics(env: Env) -> monitoring::Analytics {
+        monitoring::get_analytics(&env)
+    }
+
+    /// Attaches or replaces the metadata of an existing escrow (depositor or admin).
+    ///
+    /// # Returns
+    /// * `Ok(())` - Metadata stored
+    /// * `Err(Error::BountyNotFound)` - No escrow exists for this bounty
+    /// * `Err(Error::Unauthorized)` - Caller is neither depositor nor admin
+    /// * `Err(Error::MetadataTooLarge)` - Metadata exceeds size limits
+    /// * `Err(Error::TagIndexFull)` - A tag already lists `MAX_BOUNTIES_PER_TAG` bounties
+    pub fn set_escrow_metadata(
+        env: Env,
+        caller: Address,
+        bounty_id: u64,
+        metadata: EscrowMetadata,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if caller != escrow.depositor && Some(caller) != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        Self::validate_metadata(&metadata)?;
+        Self::check_tag_capacity(&env, bounty_id, &metadata)?;
+
+        Self::write_metadata(&env, bounty_id, Some(&metadata));
+        Ok(())
+    }
+
+    /// Retrieves the metadata attached to an escrow.
+    ///
+    /// # Returns
+    /// * `Ok(EscrowMetadata)` - The stored metadata
+    /// * `Err(Error::BountyNotFound)` - No metadata stored for this bounty
+    pub fn get_escrow_metadata(env: Env, bounty_id: u64) -> Result<EscrowMetadata, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Metadata(bounty_id))
+            .ok_or(Error::BountyNotFound)
+    }
+
+    /// Lists bounties whose metadata carries `tag`, in the order they were tagged.
+    ///
+    /// `page.limit` is capped at `MAX_QUERY_LIMIT`.
+    pub fn get_bounties_by_tag(env: Env, tag: String, page: Pagination) -> Vec<u64> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TagIndex(tag))
+            .unwrap_or(vec![&env]);
+        let end = page
+            .start_index
+            .saturating_add(page.limit.min(MAX_QUERY_LIMIT))
+            .min(index.len());
+
+        let mut result = vec![&env];
+        for position in page.start_index..end {
+            result.push_back(index.get(position).unwrap());
+        }
+        result
+    }
+
+    /// Returns an escrow and its metadata in one call.
+    ///
+    /// # Returns
+    /// * `Ok(EscrowMetadataWithEscrow)` - The escrow, with any stored metadata in `metadata`
+    /// * `Err(Error::BountyNotFound)` - No escrow exists for this bounty
+    pub fn get_escrow_with_metadata(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<EscrowMetadataWithEscrow, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let mut metadata = vec![&env];
+        if let Some(stored) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Metadata(bounty_id))
+        {
+            metadata.push_back(stored);
+        }
+        Ok(EscrowMetadataWithEscrow { escrow, metadata })
+    }
+
+    /// Returns whether `caller` would be authorized to release funds.
+    ///
+    /// Read-only helper for frontends; releases are admin-only.
+    pub fn can_release(env: Env, caller: Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        admin == Some(caller)
+    }
+
+    /// Returns whether `caller` could currently refund the given bounty.
+    ///
+    /// True when `caller` is the depositor, the escrow is still active, and
+    /// either the deadline has passed or an unexpired refund approval exists.
+    /// Does not perform the refund.
+    pub fn can_refund(env: Env, caller: Address, bounty_id: u64) -> bool {
+        let escrow: Escrow = match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+            Some(escrow) => escrow,
+            None => return false,
+        };
+
+        if caller != escrow.depositor {
+            return false;
+        }
+
+        match Self::get_refund_eligibility(env, bounty_id) {
+            Ok((can_refund, _, _, _)) => can_refund,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the contract's balance of `token`.
+    ///
+    /// Use `get_escrow_token` to find the token a bounty is denominated in.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token` - Token contract to query
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Current contract token balance
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    ///
+    /// # Use Cases
+    /// - Monitoring total locked funds
+    /// - Verifying contract solvency
+    /// - Auditing and reconciliation
+    ///
+    /// # Gas Cost
+    /// Low - Token contract call
+    ///
+    /// # Example
+    /// ```rust
+    /// let balance = escrow_client.get_balance(&usdc_address)?;
+    /// println!("Total locked: {} stroops", balance);
+    /// ```
+    pub fn get_balance(env: Env, token: Address) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let client = token::Client::new(&env, &token);
+        Ok(client.balance(&env.current_contract_address()))
+    }
+
+    /// Retrieves the refund history for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Vec<RefundRecord>)` - The refund history
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        Ok(escrow.refund_history)
+    }
+
+    /// Gets refund eligibility information for a bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
+    ///   - can_refund: Whether refund is possible
+    ///   - deadline_passed: Whether the deadline has passed
+    ///   - remaining: Remaining amount in escrow
+    ///   - approval: Optional refund approval if exists
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_eligibility(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<(bool, bool, i128, Option<RefundApproval>), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        let now = env.ledger().timestamp();
+        let deadline_passed = now >= escrow.deadline;
+
+        let approval: Option<RefundApproval> = if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RefundApproval(bounty_id))
+        {
+            Some(
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::RefundApproval(bounty_id))
+                    .unwrap(),
+            )
+        } else {
+            None
+        };
+        let approval_expiry = Self::refund_approval_expiry(&env);
+        let approval_valid = approval
+            .as_ref()
+            .is_some_and(|a| now <= a.approved_at.saturating_add(approval_expiry));
+
+        // can_refund is true if:
+        // 1. Escrow still holds funds (Locked, PartiallyRefunded or PartiallyReleased) AND
+        // 2. (deadline has passed OR there's an unexpired approval)
+        let can_refund = Self::is_active(&escrow.status) && (deadline_passed || approval_valid);
+
+        Ok((
+            can_refund,
+            deadline_passed,
+            escrow.remaining_amount,
+            approval,
+        ))
+    }
+
+    /// Sets the largest batch accepted by the batch functions (admin only).
+    ///
+    /// Integrators can lower it to keep transactions within their wallet's
+    /// size limits, or raise it up to `MAX_BATCH_SIZE_LIMIT` to save gas.
+    ///
+    /// This is also the guard against batches running out of resources
+    /// mid-loop: the host budget is only observable in tests, not on-chain,
+    /// so a batch cannot stop itself based on CPU cost. Simulate a batch at
+    /// the intended size and lower the limit if it approaches the budget.
+    ///
+    /// # Errors
+    /// * NotInitialized - if contract is not initialized
+    /// * InvalidBatchSize - if `size` is zero or above `MAX_BATCH_SIZE_LIMIT`
+    pub fn set_max_batch_size(env: Env, size: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if size == 0 || size > MAX_BATCH_SIZE_LIMIT {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        env.storage().instance().set(&DataKey::MaxBatchSize, &size);
+
+        Ok(())
+    }
+
+    /// Returns the largest batch accepted, `MAX_BATCH_SIZE` unless overridden.
+    pub fn get_max_batch_size(env: Env) -> u32 {
+        Self::max_batch_size(&env)
+    }
+
+    fn max_batch_size(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxBatchSize)
+            .unwrap_or(MAX_BATCH_SIZE)
+    }
+
+    /// Batch lock funds for multiple bounties in a single transaction.
+    /// This improves gas efficiency by reducing transaction overhead.
+    ///
+    /// # Arguments
+    /// * `items` - Vector of LockFundsItem containing bounty_id, depositor, amount, deadline and token
+    ///
+    /// # Returns
+    /// Number of successfully locked bounties
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds the max batch size or is zero
+    /// * BountyExists - if any bounty_id already exists
+    /// * DuplicateBountyId - if a bounty_id appears more than once in the batch
+    /// * InvalidAmount - if any amount is zero or negative
+    /// * InvalidDeadline - if any deadline is not in the future
+    /// * NotInitialized - if contract is not initialized
+    /// * InvalidToken - if any token does not report sane decimals
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
+        if Self::inflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        // Validate batch size
+        let batch_size = items.len() as u32;
+        if batch_size == 0 {
+            return Err(Error::InvalidBatchSize);
+        }
+        if batch_size > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let contract_address = env.current_contract_address();
+        let timestamp = env.ledger().timestamp();
+
+        // Validate all items before processing (all-or-nothing approach)
+        for item in items.iter() {
+            // Check if bounty already exists
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::Escrow(item.bounty_id))
+            {
+                return Err(Error::BountyExists);
+            }
+
+            // Validate amount
+            if item.amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            if item.deadline <= timestamp {
+                return Err(Error::InvalidDeadline);
+            }
+
+            Self::validate_token(&env, &item.token)?;
+
+            // Check for duplicate bounty_ids in the batch
+            let mut count = 0u32;
+            for other_item in items.iter() {
+                if other_item.bounty_id == item.bounty_id {
+                    count += 1;
+                }
+            }
+            if count > 1 {
+                return Err(Error::DuplicateBountyId);
+            }
+        }
+
+        // Collect unique depositors and require auth once for each
+        // This prevents "frame is already authorized" errors when same depositor appears multiple times
+        let mut seen_depositors: Vec<Address> = Vec::new(&env);
+        for item in items.iter() {
+            let mut found = false;
+            for seen in seen_depositors.iter() {
+                if seen.clone() == item.depositor {
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                seen_depositors.push_back(item.depositor.clone());
+                item.depositor.require_auth();
+            }
+        }
+
+        Self::enter_guard(&env, symbol_short!("b_lock"));
+
+        // Process all items (atomic - all succeed or all fail)
+        let mut locked_count = 0u32;
+        for item in items.iter() {
+            // Checked per item so escrows locked earlier in the batch count too
+            if let Err(err) = Self::check_active_escrow_cap(&env, &item.depositor) {
+                env.storage().instance().remove(&DataKey::ReentrancyGuard);
+                return Err(err);
+            }
+
+            // Transfer funds from depositor to contract
+            let client = token::Client::new(&env, &item.token);
+            client.transfer(&item.depositor, &contract_address, &item.amount);
+
+            // Create escrow record
+            let escrow = Escrow {
+                depositor: item.depositor.clone(),
+                amount: item.amount,
+                status: EscrowStatus::Locked,
+                deadline: item.deadline,
+                refund_history: vec![&env],
+                remaining_amount: item.amount,
+                auto_refund_on_expiry: false,
+                token: Some(item.token.clone()),
+            };
+
+            // Store escrow
+            Self::store_escrow(&env, item.bounty_id, &escrow);
+            Self::register_bounty(&env, item.bounty_id, &item.depositor);
+            Self::record_depositor(&env, &item.depositor);
+
+            // Emit individual event for each locked bounty
+            emit_funds_locked(
+                &env,
+                FundsLocked {
+                    bounty_id: item.bounty_id,
+                    amount: item.amount,
+                    depositor: item.depositor.clone(),
+                    deadline: item.deadline,
+                },
+            );
+
+            locked_count += 1;
+        }
+
+        // Emit batch event
+        emit_batch_funds_locked(
+            &env,
+            BatchFundsLocked {
+                count: locked_count,
+                total_amount: items.iter().map(|i| i.amount).sum(),
+                timestamp,
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        Ok(locked_count)
+    }
+
+    /// Batch release funds to multiple contributors in a single transaction.
+    /// This improves gas efficiency by reducing transaction overhead.
+    ///
+    /// Each listed bounty has its full remaining amount paid to its contributor,
+    /// less the release fee when one applies.
+    ///
+    /// # Arguments
+    /// * `items` - Vector of ReleaseFundsItem containing bounty_id and contributor address
+    /// * `strict` - Fail the whole batch on a bounty that is not `Locked` or
+    ///   `PartiallyReleased`; when false such bounties are skipped
+    ///
+    /// # Returns
+    /// Number of bounties actually released
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds the max batch size or is zero
+    /// * BountyNotFound - if any bounty_id doesn't exist
+    /// * FundsNotLocked - in strict mode, if any bounty cannot be released
+    /// * DuplicateBountyId - if a bounty_id appears more than once in the batch
+    /// * Unauthorized - if caller is not admin
+    /// * ReleaseDelayActive - if a release confirmation delay is configured
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    pub fn batch_release_funds(
+        env: Env,
+        items: Vec<ReleaseFundsItem>,
+        strict: bool,
+    ) -> Result<u32, Error> {
+        if Self::outflows_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        Self::check_no_release_delay(&env)?;
+
+        // Validate batch size
+        let batch_size = items.len() as u32;
+        if batch_size == 0 {
+            return Err(Error::InvalidBatchSize);
+        }
+        if batch_size > Self::max_batch_size(&env) {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let timestamp = env.ledger().timestamp();
+
+        // Validate all items before processing (all-or-nothing approach)
+        for item in items.iter() {
+            // Check if bounty exists
+            if !env
+                .storage()
+                .persistent()
+                .has(&DataKey::Escrow(item.bounty_id))
+            {
+                return Err(Error::BountyNotFound);
+            }
+
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(item.bounty_id))
+                .unwrap();
+
+            // Check if funds are still releasable
+            if strict && !Self::is_releasable(&escrow.status) {
+                return Err(Error::FundsNotLocked);
+            }
+            Self::check_release_cliff(&env, item.bounty_id)?;
+            Self::check_intended_contributor(&env, item.bounty_id, &item.contributor)?;
+
+            // Check for duplicate bounty_ids in the batch
+            let mut count = 0u32;
+            for other_item in items.iter() {
+                if other_item.bounty_id == item.bounty_id {
+                    count += 1;
+                }
+            }
+            if count > 1 {
+                return Err(Error::DuplicateBountyId);
+            }
+        }
+
+        Self::enter_guard(&env, symbol_short!("b_release"));
+
+        // Process all items (atomic - all succeed or all fail)
+        let mut released_count = 0u32;
+        let mut total_amount: i128 = 0;
+        for item in items.iter() {
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(item.bounty_id))
+                .unwrap();
+            if !Self::is_releasable(&escrow.status) {
+                continue;
+            }
+
+            let amount = escrow.remaining_amount;
+            if let Err(err) = Self::consume_release_allowance(&env, item.bounty_id, amount) {
+                env.storage().instance().remove(&DataKey::ReentrancyGuard);
+                return Err(err);
+            }
+
+            // Pays the contributor net of the release fee and emits the release event
+            Self::release_amount(&env, item.bounty_id, escrow, &item.contributor, amount);
+
+            total_amount = match total_amount.checked_add(amount) {
+                Some(total) => total,
+                None => {
+                    env.storage().instance().remove(&DataKey::ReentrancyGuard);
+                    return Err(Error::InvalidAmount);
+                }
+            };
+            released_count += 1;
+        }
+
+        // Emit batch event
+        emit_batch_funds_released(
+            &env,
+            BatchFundsReleased {
+                count: released_count,
+                total_amount,
+                timestamp,
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        Ok(released_count)
+    }
+
+    // ========================================================================
+    // Program Linking
+    // ========================================================================
+
+    /// Tags a bounty as belonging to a program-escrow program (admin only).
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The bounty to tag
+    /// * `program_id` - Identifier of the program in the program-escrow contract
+    ///
+    /// # Errors
+    /// * NotInitialized - if contract is not initialized
+    /// * BountyNotFound - if the bounty doesn't exist
+    /// * BountyAlreadyTagged - if the bounty is already linked to a program
+    pub fn set_bounty_program(env: Env, bounty_id: u64, program_id: String) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::BountyProgram(bounty_id))
+        {
+            return Err(Error::BountyAlreadyTagged);
+        }
+
+        let mut bounties: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProgramBounties(program_id.clone()))
+            .unwrap_or(vec![&env]);
+        bounties.push_back(bounty_id);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::BountyProgram(bounty_id), &program_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramBounties(program_id), &bounties);
+
+        Ok(())
+    }
+
+    /// Returns the program a bounty is tagged to, if any.
+    pub fn get_bounty_program(env: Env, bounty_id: u64) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BountyProgram(bounty_id))
+    }
+
+    /// Returns all bounty IDs tagged to a program, in tagging order.
+    pub fn get_program_bounties(env: Env, program_id: String) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramBounties(program_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Releases a group of bounties that all belong to the same program.
+    ///
+    /// Every item must reference a bounty tagged to `program_id`; the release
+    /// itself follows the same rules as `batch_release_funds`.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program the bounties are tagged to
+    /// * `items` - Bounty/contributor pairs to release
+    ///
+    /// # Returns
+    /// Number of released bounties
+    ///
+    /// # Errors
+    /// * ProgramMismatch - if any bounty is not tagged to `program_id`
+    /// * Any error returned by `batch_release_funds` in strict mode
+    pub fn release_program_bounties(
+        env: Env,
+        program_id: String,
+        items: Vec<ReleaseFundsItem>,
+    ) -> Result<u32, Error> {
+        for item in items.iter() {
+            let tagged: Option<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::BountyProgram(item.bounty_id));
+            if tagged != Some(program_id.clone()) {
+                return Err(Error::ProgramMismatch);
+            }
+        }
+
+        Self::batch_release_funds(env, items, true)
+    }
+    // ========================================================================
+    // Pause Control
+    // ========================================================================
+
+    /// Halts locks, releases and refunds until `unpause` is called (admin only).
+    ///
+    /// # Events
+    /// Emits: `PauseChanged { paused: true, admin, timestamp }`
+    pub fn pause(env: Env) -> Result<(), Error> {
+        Self::pause_with_mode(env, PauseMode::All)
+    }
+
+    /// Halts only the operations selected by `mode` until `unpause` is called
+    /// (admin only). Calling it while paused switches the mode.
+    ///
+    /// # Events
+    /// Emits: `PauseChanged { paused: true, admin, timestamp }`
+    pub fn pause_with_mode(env: Env, mode: PauseMode) -> Result<(), Error> {
+        Self::set_paused(&env, true)?;
+        env.storage().instance().set(&DataKey::PauseMode, &mode);
+        Ok(())
+    }
+
+    /// Returns the active pause mode, or `None` when the contract is not paused.
+    pub fn get_pause_mode(env: Env) -> Option<PauseMode> {
+        Self::active_pause_mode(&env)
+    }
+
+    /// Resumes normal operation after `pause` (admin only).
+    ///
+    /// # Events
+    /// Emits: `PauseChanged { paused: false, admin, timestamp }`
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        Self::set_paused(&env, false)
+    }
+
+    /// Returns whether the contract is paused.
+    pub fn is_paused(env: Env) -> bool {
+        Self::is_paused_internal(&env)
+    }
+
+    fn is_paused_internal(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    fn active_pause_mode(env: &Env) -> Option<PauseMode> {
+        if !Self::is_paused_internal(env) {
+            return None;
+        }
+        Some(
+            env.storage()
+                .instance()
+                .get(&DataKey::PauseMode)
+                .unwrap_or(PauseMode::All),
+        )
+    }
+
+    /// Whether new funds are blocked from entering escrow.
+    fn inflows_paused(env: &Env) -> bool {
+        matches!(
+            Self::active_pause_mode(env),
+            Some(PauseMode::All) | Some(PauseMode::InflowsOnly)
+        )
+    }
+
+    /// Whether releases to contributors are blocked.
+    fn outflows_paused(env: &Env) -> bool {
+        matches!(
+            Self::active_pause_mode(env),
+            Some(PauseMode::All) | Some(PauseMode::OutflowsOnly)
+        )
+    }
+
+    /// Whether refunds to depositors are blocked.
+    fn refunds_paused(env: &Env) -> bool {
+        Self::active_pause_mode(env) == Some(PauseMode::All)
+    }
+
+    fn set_paused(env: &Env, paused: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &paused);
+
+        events::emit_pause_changed(
+            env,
+            events::PauseChanged {
+                paused,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Emergency Withdrawal
+    // ========================================================================
+
+    /// Configures the N-of-M signer quorum required for emergency withdrawal (admin only).
+    ///
+    /// Once a quorum is configured, `emergency_withdraw` no longer accepts a
+    /// single admin signature and instead requires `threshold` signer approvals.
+    /// Replacing an existing quorum additionally needs `threshold` of its
+    /// signers to approve the new configuration via `approve_quorum_change`.
+    /// Changing the quorum discards every approval collected so far.
+    ///
+    /// # Errors
+    /// * NotInitialized - if contract is not initialized
+    /// * InvalidQuorum - if threshold is zero or exceeds the number of signers
+    /// * InsufficientApprovals - if the current quorum has not approved the change
+    pub fn set_emergency_quorum(
+        env: Env,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if threshold == 0 || threshold > signers.len() {
+            return Err(Error::InvalidQuorum);
+        }
+
+        let proposed = EmergencyQuorum { signers, threshold };
+        let current: Option<EmergencyQuorum> =
+            env.storage().instance().get(&DataKey::EmergencyQuorum);
+        if let Some(current) = current {
+            let key = DataKey::QuorumChange(proposed.clone());
+            let approvals = Self::valid_emergency_approvals(&env, &current, &key);
+            if approvals.len() < current.threshold {
+                return Err(Error::InsufficientApprovals);
+            }
+            env.storage().instance().remove(&key);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::EmergencyQuorum, &proposed);
+        let epoch = Self::emergency_quorum_epoch(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::EmergencyQuorumEpoch, &epoch.wrapping_add(1));
+
+        Ok(())
+    }
+
+    fn emergency_quorum_epoch(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::EmergencyQuorumEpoch)
+            .unwrap_or(0)
+    }
+
+    /// Approvals stored under `key` that still count: given under the current
+    /// quorum, by one of its signers, within `EMERGENCY_APPROVAL_EXPIRY`.
+    fn valid_emergency_approvals(
+        env: &Env,
+        quorum: &EmergencyQuorum,
+        key: &DataKey,
+    ) -> Vec<EmergencyApproval> {
+        let mut valid = vec![env];
+        let stored: Option<EmergencyApprovalSet> = env.storage().instance().get(key);
+        let stored = match stored {
+            Some(stored) if stored.epoch == Self::emergency_quorum_epoch(env) => stored,
+            _ => return valid,
+        };
+
+        let now = env.ledger().timestamp();
+        for approval in stored.approvals.iter() {
+            if quorum.signers.contains(&approval.signer)
+                && now
+                    <= approval
+                        .approved_at
+                        .saturating_add(EMERGENCY_APPROVAL_EXPIRY)
+            {
+                valid.push_back(approval);
+            }
+        }
+        valid
+    }
+
+    /// Returns the emergency withdrawal quorum, if one is configured.
+    pub fn get_emergency_quorum(env: Env) -> Option<EmergencyQuorum> {
+        env.storage().instance().get(&DataKey::EmergencyQuorum)
+    }
+
+    /// Records a quorum signer's approval to withdraw all funds to `recipient`.
+    ///
+    /// Approvals are tracked per recipient, so signers must agree on the
+    /// destination of the funds. An approval lapses after one day or when the
+    /// quorum is reconfigured; approving again refreshes it.
+    ///
+    /// # Returns
+    /// Number of valid approvals collected for `recipient`
+    ///
+    /// # Errors
+    /// * InvalidQuorum - if no quorum is configured
+    /// * Unauthorized - if `signer` is not a quorum signer
+    pub fn approve_emergency_withdraw(
+        env: Env,
+        signer: Address,
+        recipient: Address,
+    ) -> Result<u32, Error> {
+        signer.require_auth();
+
+        let quorum: EmergencyQuorum = env
+            .storage()
+            .instance()
+            .get(&DataKey::EmergencyQuorum)
+            .ok_or(Error::InvalidQuorum)?;
+
+        Self::record_emergency_approval(
+            &env,
+            &quorum,
+            signer,
+            &DataKey::EmergencyApprovals(recipient),
+        )
+    }
+
+    /// Records a quorum signer's approval to replace the quorum with
+    /// `signers` and `threshold`.
+    ///
+    /// Approvals are tracked per proposed configuration and lapse like
+    /// emergency withdrawal approvals.
+    ///
+    /// # Returns
+    /// Number of valid approvals collected for the proposed quorum
+    ///
+    /// # Errors
+    /// * InvalidQuorum - if no quorum is configured or the proposal is invalid
+    /// * Unauthorized - if `signer` is not a current quorum signer
+    pub fn approve_quorum_change(
+        env: Env,
+        signer: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<u32, Error> {
+        signer.require_auth();
+
+        let quorum: EmergencyQuorum = env
+            .storage()
+            .instance()
+            .get(&DataKey::EmergencyQuorum)
+            .ok_or(Error::InvalidQuorum)?;
+
+        if threshold == 0 || threshold > signers.len() {
+            return Err(Error::InvalidQuorum);
+        }
+
+        Self::record_emergency_approval(
+            &env,
+            &quorum,
+            signer,
+            &DataKey::QuorumChange(EmergencyQuorum { signers, threshold }),
+        )
+    }
+
+    /// Adds or refreshes `signer`'s approval under `key`, dropping lapsed ones.
+    fn record_emergency_approval(
+        env: &Env,
+        quorum: &EmergencyQuorum,
+        signer: Address,
+        key: &DataKey,
+    ) -> Result<u32, Error> {
+        if !quorum.signers.contains(&signer) {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut approvals = vec![env];
+        for approval in Self::valid_emergency_approvals(env, quorum, key).iter() {
+            if approval.signer != signer {
+                approvals.push_back(approval);
+            }
+        }
+        approvals.push_back(EmergencyApproval {
+            signer,
+            approved_at: env.ledger().timestamp(),
+        });
+        env.storage().instance().set(
+            key,
+            &EmergencyApprovalSet {
+                epoch: Self::emergency_quorum_epoch(env),
+                approvals: approvals.clone(),
+            },
+        );
+
+        Ok(approvals.len())
+    }
+
+    /// Transfers the contract's entire balance of `token` to `recipient`.
+    ///
+    /// This is a break-glass mechanism: the contract must be paused first, and
+    /// escrow records are intentionally left untouched, so their amounts and
+    /// statuses no longer match the tokens held once it runs. Pending releases
+    /// of escrows in `token` are dropped.
+    ///
+    /// When an emergency quorum is configured, at least `threshold` signers must
+    /// have approved this recipient via `approve_emergency_withdraw`; approvals
+    /// are consumed on success, and `caller` must be the admin or one of the
+    /// signers. Without a quorum `caller` must be the admin.
+    ///
+    /// # Returns
+    /// The withdrawn amount
+    ///
+    /// # Errors
+    /// * NotInitialized - if contract is not initialized
+    /// * ContractNotPaused - if the contract is not paused with `PauseMode::All`
+    /// * Unauthorized - if `caller` is neither the admin nor a quorum signer
+    /// * InsufficientApprovals - if the configured quorum has not been reached
+    ///
+    /// # Events
+    /// Emits: `EmergencyWithdrawal { token, recipient, amount, timestamp }`
+    pub fn emergency_withdraw(
+        env: Env,
+        caller: Address,
+        token: Address,
+        recipient: Address,
+    ) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        // A partial pause leaves some flows running, so draining needs a full pause
+        if Self::active_pause_mode(&env) != Some(PauseMode::All) {
+            return Err(Error::ContractNotPaused);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        caller.require_auth();
+        let quorum: Option<EmergencyQuorum> =
+            env.storage().instance().get(&DataKey::EmergencyQuorum);
+
+        match quorum {
+            Some(quorum) => {
+                if caller != admin && !quorum.signers.contains(&caller) {
+                    return Err(Error::Unauthorized);
+                }
+                let key = DataKey::EmergencyApprovals(recipient.clone());
+                let approvals = Self::valid_emergency_approvals(&env, &quorum, &key);
+                if approvals.len() < quorum.threshold {
+                    return Err(Error::InsufficientApprovals);
+                }
+                env.storage().instance().remove(&key);
+            }
+            None => {
+                if caller != admin {
+                    return Err(Error::Unauthorized);
+                }
+            }
+        }
+
+        Self::enter_guard(&env, symbol_short!("em_wd"));
+
+        let client = token::Client::new(&env, &token);
+        let balance = client.balance(&env.current_contract_address());
+
+        if balance > 0 {
+            client.transfer(&env.current_contract_address(), &recipient, &balance);
+        }
+
+        // The drained escrows can no longer pay out; bumping the epoch voids
+        // every pending release recorded against this token
+        let epoch_key = DataKey::DrainEpoch(token.clone());
+        let epoch: u32 = env.storage().persistent().get(&epoch_key).unwrap_or(0);
+        env.storage().persistent().set(&epoch_key, &(epoch + 1));
+
+        events::emit_emergency_withdrawal(
+            &env,
+            events::EmergencyWithdrawal {
+                token,
+                recipient,
+                amount: balance,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        monitoring::track_operation(&env, symbol_short!("em_wd"), caller, true);
+
+        Ok(balance)
+    }
+
+    // ========================================================================
+    // Per-Bounty Release Limits
+    // ========================================================================
+
+    /// Caps how much can be released from a bounty per time window.
+    ///
+    /// Independent of the global anti-abuse limits, this bounds the damage a
+    /// compromised admin key can do to a single escrow. Only the depositor
+    /// can set or change the limit.
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The bounty to limit
+    /// * `max_amount` - Maximum amount released within one window
+    /// * `window` - Window length in seconds
+    ///
+    /// # Errors
+    /// * BountyNotFound - if the bounty doesn't exist
+    /// * InvalidAmount - if `max_amount` or `window` is zero or negative
+    pub fn set_release_limit(
+        env: Env,
+        bounty_id: u64,
+        max_amount: i128,
+        window: u64,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        if max_amount <= 0 || window == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().persistent().set(
+            &DataKey::ReleaseLimit(bounty_id),
+            &ReleaseLimit { max_amount, window },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the release limit configured for a bounty, if any.
+    pub fn get_release_limit(env: Env, bounty_id: u64) -> Option<ReleaseLimit> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseLimit(bounty_id))
+    }
+
+    /// Records `amount` against the bounty's current release window.
+    ///
+    /// Bounties without a configured limit are not restricted.
+    fn consume_release_allowance(env: &Env, bounty_id: u64, amount: i128) -> Result<(), Error> {
+        let limit: ReleaseLimit = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseLimit(bounty_id))
+        {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+
+        let now = env.ledger().timestamp();
+        let key = DataKey::ReleaseWindow(bounty_id);
+        let mut state: ReleaseWindow =
+            env.storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(ReleaseWindow {
+                    window_start: now,
+                    released: 0,
+                });
+
+        if now >= state.window_start.saturating_add(limit.window) {
+            state.window_start = now;
+            state.released = 0;
+        }
+
+        let released = state
+            .released
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        if released > limit.max_amount {
+            return Err(Error::ReleaseLimitExceeded);
+        }
+
+        state.released = released;
+        env.storage().persistent().set(&key, &state);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Expiration Processing
+    // ========================================================================
+
+    fn register_bounty(env: &Env, bounty_id: u64, depositor: &Address) {
+        registry::register(env, bounty_id);
+        Self::index_depositor(env, depositor, bounty_id);
+    }
+
+    /// Drops a bounty whose escrow was removed from its depositor's index.
+    /// Its registry slot stays; registry scans skip IDs without an escrow.
+    fn unregister_bounty(env: &Env, bounty_id: u64, depositor: &Address) {
+        Self::unindex_depositor(env, depositor, bounty_id);
+    }
+
+    fn index_depositor(env: &Env, depositor: &Address, bounty_id: u64) {
+        let key = DataKey::DepositorIndex(depositor.clone());
+        let mut index: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+        index.push_back(bounty_id);
+        env.storage().persistent().set(&key, &index);
+    }
+
+    fn unindex_depositor(env: &Env, depositor: &Address, bounty_id: u64) {
+        let key = DataKey::DepositorIndex(depositor.clone());
+        let mut index: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+        if let Some(position) = index.first_index_of(bounty_id) {
+            index.remove(position);
+            env.storage().persistent().set(&key, &index);
+        }
+    }
+
+    /// Whether the escrow still holds funds that can be released or refunded.
+    fn is_active(status: &EscrowStatus) -> bool {
+        matches!(
+            status,
+            EscrowStatus::Locked
+                | EscrowStatus::PartiallyRefunded
+                | EscrowStatus::PartiallyReleased
+        )
+    }
+
+    /// Whether the escrow can still be paid out to a contributor.
+    fn is_releasable(status: &EscrowStatus) -> bool {
+        matches!(
+            status,
+            EscrowStatus::Locked | EscrowStatus::PartiallyReleased
+        )
+    }
+
+    fn is_expired(env: &Env, escrow: &Escrow) -> bool {
+        Self::is_active(&escrow.status) && env.ledger().timestamp() >= escrow.deadline
+    }
+
+    /// Returns the IDs of active escrows whose deadline has passed.
+    pub fn get_expired_bounties(env: Env) -> Vec<u64> {
+        let mut expired = vec![&env];
+        for page in 0..registry::page_count(&env) {
+            for bounty_id in registry::page(&env, page).iter() {
+                let escrow: Option<Escrow> =
+                    env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+                if let Some(escrow) = escrow {
+                    if Self::is_expired(&env, &escrow) {
+                        expired.push_back(bounty_id);
+                    }
+                }
+            }
+        }
+        expired
+    }
+
+    /// Refunds expired escrows flagged with `auto_refund_on_expiry`.
+    ///
+    /// Callable by anyone (e.g. a keeper bot). Each flagged escrow past its
+    /// deadline has its remaining amount returned to the depositor. At most
+    /// `get_max_batch_size` escrows are refunded per call.
+    ///
+    /// # Returns
+    /// Number of escrows refunded
+    ///
+    /// # Errors
+    /// * ContractPaused - if refunds are paused
+    /// * NotInitialized - if contract is not initialized
+    pub fn process_expirations(env: Env) -> Result<u32, Error> {
+        if Self::refunds_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let now = env.ledger().timestamp();
+        let max_refunds = Self::max_batch_size(&env);
+
+        Self::enter_guard(&env, symbol_short!("expire"));
+
+        let mut refunded = 0u32;
+        'scan: for page in 0..registry::page_count(&env) {
+            for bounty_id in registry::page(&env, page).iter() {
+                if refunded >= max_refunds {
+                    break 'scan;
+                }
+
+                let escrow: Escrow =
+                    match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                        Some(escrow) => escrow,
+                        None => continue,
+                    };
+                if !escrow.auto_refund_on_expiry || !Self::is_expired(&env, &escrow) {
+                    continue;
+                }
+                if Self::refund_remaining_to_depositor(&env, bounty_id, escrow, now) {
+                    refunded += 1;
+                }
+            }
+        }
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        Ok(refunded)
+    }
+
+    /// Refunds every expired escrow in a page of the bounty registry.
+    ///
+    /// Callable by anyone (e.g. a cron-driven keeper). Scans registry
+    /// positions `page.start_index` onwards, examining at most `page.limit`
+    /// entries (capped at `MAX_QUERY_LIMIT`), and returns the remaining amount
+    /// of each `Locked` or `PartiallyReleased` escrow whose deadline has
+    /// passed to its depositor. Because paging is by registry position, a
+    /// keeper can walk the registry without knowing which bounties expired.
+    ///
+    /// # Returns
+    /// Number of escrows refunded
+    ///
+    /// # Errors
+    /// * ContractPaused - if the contract is paused
+    /// * NotInitialized - if contract is not initialized
+    pub fn sweep_expired(env: Env, page: Pagination) -> Result<u32, Error> {
+        if Self::refunds_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let now = env.ledger().timestamp();
+        let end = page
+            .start_index
+            .saturating_add(page.limit.min(MAX_QUERY_LIMIT));
+        let bounty_ids = registry::range(&env, page.start_index, end);
+
+        Self::enter_guard(&env, symbol_short!("sweep"));
+
+        let mut refunded = 0u32;
+        for bounty_id in bounty_ids.iter() {
+            let escrow: Escrow = match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                Some(escrow) => escrow,
+                None => continue,
+            };
+            if !Self::is_releasable(&escrow.status) || now < escrow.deadline {
+                continue;
+            }
+            if Self::refund_remaining_to_depositor(&env, bounty_id, escrow, now) {
+                refunded += 1;
+            }
+        }
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        Ok(refunded)
+    }
+
+    /// Returns an expired escrow's whole remaining amount to its depositor.
+    ///
+    /// Returns false without changing anything when the escrow is empty or the
+    /// contract holds too little of its token to cover the refund.
+    fn refund_remaining_to_depositor(
+        env: &Env,
+        bounty_id: u64,
+        mut escrow: Escrow,
+        now: u64,
+    ) -> bool {
+        if escrow.remaining_amount <= 0 {
+            return false;
+        }
+        let contract_address = env.current_contract_address();
+        let client = token::Client::new(env, &Self::escrow_token(env, &escrow));
+        if client.balance(&contract_address) < escrow.remaining_amount {
+            return false;
+        }
+
+        let amount = escrow.remaining_amount;
+        client.transfer(&contract_address, &escrow.depositor, &amount);
+
+        escrow.refund_history.push_back(RefundRecord {
+            amount,
+            recipient: escrow.depositor.clone(),
+            mode: RefundMode::Full,
+            timestamp: now,
+        });
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
+        Self::store_escrow(env, bounty_id, &escrow);
+        Self::clear_pending_release(env, bounty_id);
+
+        emit_funds_refunded(
+            env,
+            FundsRefunded {
+                bounty_id,
+                amount,
+                refund_to: escrow.depositor.clone(),
+                timestamp: now,
+                refund_mode: RefundMode::Full,
+                remaining_amount: 0,
+            },
+        );
+        true
+    }
+
+    // ========================================================================
+    // Error Catalog
+    // ========================================================================
+
+    /// Returns every contract error as a `(code, name)` pair.
+    ///
+    /// Codes match the `#[repr(u32)]` discriminants of `Error` and are stable,
+    /// so frontends can map `Error(Contract, #N)` to a readable name.
+    pub fn get_error_catalog(env: Env) -> Vec<(u32, Symbol)> {
+        let mut catalog = vec![&env];
+        for (code, name) in ERROR_CATALOG.iter() {
+            catalog.push_back((*code, Symbol::new(&env, name)));
+        }
+        catalog
     }
 
-    /// Retrieves the refund history for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
-    ///
-    /// # Returns
-    /// * `Ok(Vec<RefundRecord>)` - The refund history
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+    /// Returns the numeric code for an error name, if it exists.
+    pub fn error_code(env: Env, name: Symbol) -> Option<u32> {
+        ERROR_CATALOG
+            .iter()
+            .find(|(_, error_name)| Symbol::new(&env, error_name) == name)
+            .map(|(code, _)| *code)
+    }
+
+    // ========================================================================
+    // Token Rescue
+    // ========================================================================
+
+    /// Amount an escrow contributes to its token's obligations.
+    fn escrow_obligation(escrow: &Escrow) -> i128 {
+        if Self::is_active(&escrow.status) {
+            escrow.remaining_amount
+        } else {
+            0
         }
-        let escrow: Escrow = env
+    }
+
+    fn adjust_obligations(env: &Env, token: &Address, delta: i128) {
+        if delta == 0 {
+            return;
+        }
+        let key = DataKey::Obligations(token.clone());
+        let total: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(total + delta));
+    }
+
+    /// Persists an escrow and keeps `DataKey::Obligations` in step with the
+    /// change in its active remaining amount.
+    fn store_escrow(env: &Env, bounty_id: u64, escrow: &Escrow) {
+        let key = DataKey::Escrow(bounty_id);
+        if let Some(previous) = env.storage().persistent().get::<_, Escrow>(&key) {
+            let token = Self::escrow_token(env, &previous);
+            Self::adjust_obligations(env, &token, -Self::escrow_obligation(&previous));
+        }
+        let token = Self::escrow_token(env, escrow);
+        Self::adjust_obligations(env, &token, Self::escrow_obligation(escrow));
+        env.storage().persistent().set(&key, escrow);
+    }
+
+    fn remove_escrow(env: &Env, bounty_id: u64) {
+        let key = DataKey::Escrow(bounty_id);
+        if let Some(previous) = env.storage().persistent().get::<_, Escrow>(&key) {
+            let token = Self::escrow_token(env, &previous);
+            Self::adjust_obligations(env, &token, -Self::escrow_obligation(&previous));
+        }
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Sum of remaining amounts across all active escrows denominated in
+    /// `token`, plus any `token` fees held for the fee recipient.
+    fn total_obligations(env: &Env, token: &Address) -> i128 {
+        let held: i128 = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        Ok(escrow.refund_history)
+            .get(&DataKey::HeldFees(token.clone()))
+            .unwrap_or(0);
+        let owed: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Obligations(token.clone()))
+            .unwrap_or(0);
+        held + owed
     }
 
-    /// Gets refund eligibility information for a bounty.
+    /// Transfers tokens that were sent to the contract outside of `lock_funds` (admin only).
+    ///
+    /// Any token may be rescued, but only the excess above the funds owed to
+    /// active escrows and held fees denominated in that token can be withdrawn.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
+    /// * `token` - Token contract to rescue
+    /// * `to` - Recipient of the rescued tokens
+    /// * `amount` - Amount to transfer
     ///
-    /// # Returns
-    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
-    ///   - can_refund: Whether refund is possible
-    ///   - deadline_passed: Whether the deadline has passed
-    ///   - remaining: Remaining amount in escrow
-    ///   - approval: Optional refund approval if exists
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_eligibility(
-        env: Env,
-        bounty_id: u64,
-    ) -> Result<(bool, bool, i128, Option<RefundApproval>), Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+    /// # Errors
+    /// * NotInitialized - if contract is not initialized
+    /// * InvalidAmount - if amount is zero or negative
+    /// * InsufficientFunds - if amount exceeds the rescuable balance
+    pub fn rescue_tokens(env: Env, token: Address, to: Address, amount: i128) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
 
-        let now = env.ledger().timestamp();
-        let deadline_passed = now >= escrow.deadline;
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        let approval = if env
-            .storage()
-            .persistent()
-            .has(&DataKey::RefundApproval(bounty_id))
-        {
-            Some(
-                env.storage()
-                    .persistent()
-                    .get(&DataKey::RefundApproval(bounty_id))
-                    .unwrap(),
-            )
-        } else {
-            None
-        };
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
-        // can_refund is true if:
-        // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
-        let can_refund = (escrow.status == EscrowStatus::Locked
-            || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+        let client = token::Client::new(&env, &token);
+        let balance = client.balance(&env.current_contract_address());
 
-        Ok((
-            can_refund,
-            deadline_passed,
-            escrow.remaining_amount,
-            approval,
-        ))
+        let rescuable = balance - Self::total_obligations(&env, &token);
+
+        if amount > rescuable {
+            return Err(Error::InsufficientFunds);
+        }
+
+        Self::enter_guard(&env, symbol_short!("rescue"));
+        client.transfer(&env.current_contract_address(), &to, &amount);
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        monitoring::track_operation(&env, symbol_short!("rescue"), admin, true);
+
+        Ok(())
     }
 
-    /// Batch lock funds for multiple bounties in a single transaction.
-    /// This improves gas efficiency by reducing transaction overhead.
+    // ========================================================================
+    // Escrow Listing
+    // ========================================================================
+
+    fn status_code(status: &EscrowStatus) -> u32 {
+        match status {
+            EscrowStatus::Locked => 0,
+            EscrowStatus::Released => 1,
+            EscrowStatus::Refunded => 2,
+            EscrowStatus::PartiallyRefunded => 3,
+            EscrowStatus::PartiallyReleased => 4,
+        }
+    }
+
+    fn escrow_matches(env: &Env, escrow: &Escrow, filter: &EscrowFilter) -> bool {
+        if let Some(status) = filter.status {
+            let matched = if status == EXPIRED_CODE {
+                Self::is_expired(env, escrow)
+            } else {
+                Self::status_code(&escrow.status) == status
+            };
+            if !matched {
+                return false;
+            }
+        }
+        if let Some(depositor) = &filter.depositor {
+            if escrow.depositor != *depositor {
+                return false;
+            }
+        }
+        if let Some(min_amount) = filter.min_amount {
+            if escrow.remaining_amount < min_amount {
+                return false;
+            }
+        }
+        if let Some(max_amount) = filter.max_amount {
+            if escrow.remaining_amount > max_amount {
+                return false;
+            }
+        }
+        if let Some(start_time) = filter.start_time {
+            if escrow.deadline < start_time {
+                return false;
+            }
+        }
+        if let Some(end_time) = filter.end_time {
+            if escrow.deadline > end_time {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns the status code of each requested bounty, for list views that
+    /// only render status badges.
     ///
-    /// # Arguments
-    /// * `items` - Vector of LockFundsItem containing bounty_id, depositor, amount, and deadline
+    /// Codes follow the stable `EscrowFilter.status` mapping (0 = Locked,
+    /// 1 = Released, 2 = Refunded, 3 = PartiallyRefunded, 4 = PartiallyReleased).
+    /// Unknown bounties are skipped, and at most `MAX_QUERY_LIMIT` IDs are read.
+    pub fn get_statuses(env: Env, bounty_ids: Vec<u64>) -> Vec<(u64, u32)> {
+        let mut result = vec![&env];
+        for bounty_id in bounty_ids.iter().take(MAX_QUERY_LIMIT as usize) {
+            let escrow: Option<Escrow> =
+                env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+            if let Some(escrow) = escrow {
+                result.push_back((bounty_id, Self::status_code(&escrow.status)));
+            }
+        }
+        result
+    }
+
+    /// Lists bounty IDs matching `filter`, in lock order.
     ///
-    /// # Returns
-    /// Number of successfully locked bounties
+    /// Filtering by `status == Some(EXPIRED_CODE)` returns active escrows
+    /// whose deadline has passed, even though their stored status is still
+    /// `Locked`. `page.limit` is capped at `MAX_QUERY_LIMIT`.
+    pub fn list_escrows(env: Env, filter: EscrowFilter, page: Pagination) -> Vec<u64> {
+        let limit = page.limit.min(MAX_QUERY_LIMIT);
+
+        let mut result = vec![&env];
+        let mut skipped = 0u32;
+        'scan: for registry_page in 0..registry::page_count(&env) {
+            for bounty_id in registry::page(&env, registry_page).iter() {
+                if result.len() >= limit {
+                    break 'scan;
+                }
+                let escrow: Option<Escrow> =
+                    env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+                if let Some(escrow) = escrow {
+                    if Self::escrow_matches(&env, &escrow, &filter) {
+                        if skipped < page.start_index {
+                            skipped += 1;
+                        } else {
+                            result.push_back(bounty_id);
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns escrow records matching `filter`, in lock order.
     ///
-    /// # Errors
-    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
-    /// * BountyExists - if any bounty_id already exists
-    /// * NotInitialized - if contract is not initialized
+    /// Same filtering as `list_escrows`, but each entry carries the full
+    /// escrow so indexers don't need a `get_escrow_info` call per bounty.
+    /// `page.limit` is capped at `MAX_QUERY_LIMIT`, and bounty IDs whose
+    /// escrow has been removed from storage are skipped.
+    pub fn query_escrows(env: Env, filter: EscrowFilter, page: Pagination) -> Vec<(u64, Escrow)> {
+        let limit = page.limit.min(MAX_QUERY_LIMIT);
+
+        let mut result = vec![&env];
+        let mut skipped = 0u32;
+        'scan: for registry_page in 0..registry::page_count(&env) {
+            for bounty_id in registry::page(&env, registry_page).iter() {
+                if result.len() >= limit {
+                    break 'scan;
+                }
+                let escrow: Escrow =
+                    match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                        Some(escrow) => escrow,
+                        None => continue,
+                    };
+                if !Self::escrow_matches(&env, &escrow, &filter) {
+                    continue;
+                }
+                if skipped < page.start_index {
+                    skipped += 1;
+                } else {
+                    result.push_back((bounty_id, escrow));
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns a depositor's escrows, in lock order.
     ///
-    /// # Note
-    /// This operation is atomic - if any item fails, the entire transaction reverts.
-    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
-        // Validate batch size
-        let batch_size = items.len() as u32;
-        if batch_size == 0 {
-            return Err(Error::InvalidBatchSize);
+    /// Reads the per-depositor index maintained on lock, split, merge and
+    /// reassignment, so the cost scales with the depositor's own bounties
+    /// rather than the whole registry. `page.limit` is capped at `MAX_QUERY_LIMIT`.
+    pub fn get_escrows_by_depositor(
+        env: Env,
+        depositor: Address,
+        page: Pagination,
+    ) -> Vec<(u64, Escrow)> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor))
+            .unwrap_or(vec![&env]);
+        let limit = page.limit.min(MAX_QUERY_LIMIT);
+
+        let mut result = vec![&env];
+        for bounty_id in index.iter().skip(page.start_index as usize) {
+            if result.len() >= limit {
+                break;
+            }
+            if let Some(escrow) = env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                result.push_back((bounty_id, escrow));
+            }
         }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(Error::InvalidBatchSize);
+        result
+    }
+
+    /// Returns how many distinct addresses have ever locked funds as a depositor.
+    ///
+    /// Unlike `get_analytics().unique_users`, which counts every caller, this
+    /// only counts depositors, and a depositor is counted once no matter how
+    /// many bounties they fund.
+    pub fn get_unique_depositor_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::UniqueDepositors)
+            .unwrap_or(0)
+    }
+
+    fn record_depositor(env: &Env, depositor: &Address) {
+        let seen_key = DataKey::DepositorSeen(depositor.clone());
+        if env.storage().persistent().has(&seen_key) {
+            return;
         }
+        env.storage().persistent().set(&seen_key, &true);
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        let count = Self::get_unique_depositor_count(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::UniqueDepositors, &(count + 1));
+    }
+
+    /// Returns bounties that need operator attention as of `as_of`.
+    ///
+    /// Each entry is tagged with the suggested action:
+    /// * `refund` - active escrow past its deadline, refundable to the depositor
+    /// * `exec_ref` - active escrow with an unexpired admin refund approval
+    ///
+    /// Scans the registry in lock order and returns at most `limit` entries.
+    pub fn get_actionable(env: Env, as_of: u64, limit: u32) -> Vec<(u64, Symbol)> {
+        let approval_expiry = Self::refund_approval_expiry(&env);
+        let mut actionable = vec![&env];
+        'scan: for page in 0..registry::page_count(&env) {
+            for bounty_id in registry::page(&env, page).iter() {
+                if actionable.len() >= limit {
+                    break 'scan;
+                }
+                let escrow: Escrow =
+                    match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                        Some(escrow) => escrow,
+                        None => continue,
+                    };
+                if !Self::is_active(&escrow.status) {
+                    continue;
+                }
+
+                if as_of >= escrow.deadline {
+                    actionable.push_back((bounty_id, symbol_short!("refund")));
+                    continue;
+                }
+
+                let approval: Option<RefundApproval> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::RefundApproval(bounty_id));
+                if let Some(approval) = approval {
+                    if as_of <= approval.approved_at.saturating_add(approval_expiry) {
+                        actionable.push_back((bounty_id, symbol_short!("exec_ref")));
+                    }
+                }
+            }
         }
+        actionable
+    }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
-        let timestamp = env.ledger().timestamp();
+    /// Returns the soonest future timestamp at which something can happen to a bounty.
+    ///
+    /// Considers the refund deadline, the release cliff and the execution time
+    /// of an unobjected pending release, so keepers can sleep until then.
+    ///
+    /// # Returns
+    /// * `Ok(Some(timestamp))` - Earliest upcoming action time
+    /// * `Ok(None)` - Escrow is terminal or nothing is scheduled in the future
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn next_action_time(env: Env, bounty_id: u64) -> Result<Option<u64>, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !Self::is_active(&escrow.status) {
+            return Ok(None);
+        }
 
-        // Validate all items before processing (all-or-nothing approach)
-        for item in items.iter() {
-            // Check if bounty already exists
-            if env
+        let now = env.ledger().timestamp();
+        let mut candidates = vec![&env, escrow.deadline];
+        if Self::is_releasable(&escrow.status) {
+            if let Some(cliff) = env
                 .storage()
                 .persistent()
-                .has(&DataKey::Escrow(item.bounty_id))
+                .get::<DataKey, u64>(&DataKey::ReleaseCliff(bounty_id))
             {
-                return Err(Error::BountyExists);
-            }
-
-            // Validate amount
-            if item.amount <= 0 {
-                return Err(Error::InvalidAmount);
+                candidates.push_back(cliff);
             }
-
-            // Check for duplicate bounty_ids in the batch
-            let mut count = 0u32;
-            for other_item in items.iter() {
-                if other_item.bounty_id == item.bounty_id {
-                    count += 1;
+            if let Some(pending) = Self::pending_release(&env, bounty_id) {
+                if !pending.objected {
+                    candidates.push_back(pending.executable_at);
                 }
             }
-            if count > 1 {
-                return Err(Error::DuplicateBountyId);
-            }
         }
 
-        // Collect unique depositors and require auth once for each
-        // This prevents "frame is already authorized" errors when same depositor appears multiple times
-        let mut seen_depositors: Vec<Address> = Vec::new(&env);
-        for item in items.iter() {
-            let mut found = false;
-            for seen in seen_depositors.iter() {
-                if seen.clone() == item.depositor {
-                    found = true;
-                    break;
-                }
-            }
-            if !found {
-                seen_depositors.push_back(item.depositor.clone());
-                item.depositor.require_auth();
+        Ok(candidates.iter().filter(|t| *t > now).min())
+    }
+
+    // ========================================================================
+    // Status Repair
+    // ========================================================================
+
+    /// Derives the status an escrow should have from its balances and refund history.
+    ///
+    /// With funds remaining, any refund makes it `PartiallyRefunded`, otherwise
+    /// any payout makes it `PartiallyReleased`. Once empty, it is `Refunded`
+    /// when refunds cover the whole amount and `Released` otherwise.
+    fn derive_status(escrow: &Escrow) -> EscrowStatus {
+        let refunded: i128 = escrow.refund_history.iter().map(|r| r.amount).sum();
+        if escrow.remaining_amount > 0 {
+            if refunded > 0 {
+                EscrowStatus::PartiallyRefunded
+            } else if escrow.remaining_amount < escrow.amount {
+                EscrowStatus::PartiallyReleased
+            } else {
+                EscrowStatus::Locked
             }
+        } else if !escrow.refund_history.is_empty() && refunded >= escrow.amount {
+            EscrowStatus::Refunded
+        } else {
+            EscrowStatus::Released
         }
+    }
 
-        // Process all items (atomic - all succeed or all fail)
-        let mut locked_count = 0u32;
-        for item in items.iter() {
-            // Transfer funds from depositor to contract
-            client.transfer(&item.depositor, &contract_address, &item.amount);
+    /// Recomputes a bounty's status from its remaining amount and refund history (admin only).
+    ///
+    /// Fixes status drift left behind by older releases and refunds. Only the
+    /// status field is rewritten; no tokens move and amounts are untouched.
+    ///
+    /// # Returns
+    /// The repaired status
+    ///
+    /// # Events
+    /// Emits: `StatusRepaired { bounty_id, old_status, new_status, timestamp }`
+    /// when the status changes
+    pub fn repair_status(env: Env, bounty_id: u64) -> Result<EscrowStatus, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-            // Create escrow record
-            let escrow = Escrow {
-                depositor: item.depositor.clone(),
-                amount: item.amount,
-                status: EscrowStatus::Locked,
-                deadline: item.deadline,
-                refund_history: vec![&env],
-                remaining_amount: item.amount,
-            };
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
 
-            // Store escrow
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+        let old_status = escrow.status.clone();
+        let new_status = Self::derive_status(&escrow);
+        if new_status != old_status {
+            escrow.status = new_status.clone();
+            Self::store_escrow(&env, bounty_id, &escrow);
 
-            // Emit individual event for each locked bounty
-            emit_funds_locked(
+            events::emit_status_repaired(
                 &env,
-                FundsLocked {
-                    bounty_id: item.bounty_id,
-                    amount: item.amount,
-                    depositor: item.depositor.clone(),
-                    deadline: item.deadline,
+                events::StatusRepaired {
+                    bounty_id,
+                    old_status,
+                    new_status: new_status.clone(),
+                    timestamp: env.ledger().timestamp(),
                 },
             );
-
-            locked_count += 1;
         }
 
-        // Emit batch event
-        emit_batch_funds_locked(
-            &env,
-            BatchFundsLocked {
-                count: locked_count,
-                total_amount: items.iter().map(|i| i.amount).sum(),
-                timestamp,
-            },
-        );
+        monitoring::track_operation(&env, symbol_short!("repair"), admin, true);
 
-        Ok(locked_count)
+        Ok(new_status)
     }
 
-    /// Batch release funds to multiple contributors in a single transaction.
-    /// This improves gas efficiency by reducing transaction overhead.
+    // ========================================================================
+    // Cancellation
+    // ========================================================================
+
+    /// Sets the share of an escrow kept as a penalty on early cancellation (admin only).
     ///
     /// # Arguments
-    /// * `items` - Vector of ReleaseFundsItem containing bounty_id and contributor address
-    ///
-    /// # Returns
-    /// Number of successfully released bounties
+    /// * `rate` - Penalty in basis points (10000 = 100%)
     ///
     /// # Errors
-    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
-    /// * BountyNotFound - if any bounty_id doesn't exist
-    /// * FundsNotLocked - if any bounty is not in Locked status
-    /// * Unauthorized - if caller is not admin
+    /// * `NotInitialized` - Contract not initialized
+    /// * `InvalidFeeRate` - `rate` is negative or above 10000
+    pub fn set_cancel_penalty_rate(env: Env, rate: i128) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !(0..=BASIS_POINTS).contains(&rate) {
+            return Err(Error::InvalidFeeRate);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::CancelPenaltyRate, &rate);
+
+        Ok(())
+    }
+
+    /// Returns the early cancellation penalty in basis points (0 when unset).
+    pub fn get_cancel_penalty_rate(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CancelPenaltyRate)
+            .unwrap_or(0)
+    }
+
+    /// Cancels an untouched escrow before its deadline (depositor only).
     ///
-    /// # Note
-    /// This operation is atomic - if any item fails, the entire transaction reverts.
-    pub fn batch_release_funds(env: Env, items: Vec<ReleaseFundsItem>) -> Result<u32, Error> {
-        // Validate batch size
-        let batch_size = items.len() as u32;
-        if batch_size == 0 {
-            return Err(Error::InvalidBatchSize);
+    /// The cancellation penalty is sent to the fee recipient and the rest is
+    /// refunded to the depositor. After the deadline the depositor should use
+    /// `refund`, which carries no penalty.
+    ///
+    /// # Returns
+    /// * `Ok(penalty)` - Amount kept as the penalty
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow is not `Locked`, i.e. funds were
+    ///   already released or refunded
+    /// * `Err(Error::ReleasePending)` - A release is awaiting confirmation
+    /// * `Err(Error::InvalidDeadline)` - The deadline has already passed
+    ///
+    /// # Events
+    /// Emits: `FundsRefunded` for the refunded part and
+    /// `EscrowCancelled { bounty_id, depositor, refunded, penalty, timestamp }`
+    pub fn cancel_with_penalty(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        if Self::refunds_paused(&env) {
+            return Err(Error::ContractPaused);
         }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(Error::InvalidBatchSize);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if Self::pending_release(&env, bounty_id).is_some() {
+            return Err(Error::ReleasePending);
+        }
+        let now = env.ledger().timestamp();
+        if now >= escrow.deadline {
+            return Err(Error::InvalidDeadline);
+        }
+
+        Self::enter_guard(&env, symbol_short!("cancel"));
+
+        let rate: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CancelPenaltyRate)
+            .unwrap_or(0);
+        let amount = escrow.remaining_amount;
+        let penalty = Self::calculate_fee(amount, rate);
+        let refunded = amount - penalty;
+
+        escrow.refund_history.push_back(RefundRecord {
+            amount: refunded,
+            recipient: escrow.depositor.clone(),
+            mode: RefundMode::Full,
+            timestamp: now,
+        });
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
+        Self::store_escrow(&env, bounty_id, &escrow);
+        Self::clear_pending_release(&env, bounty_id);
+
+        let client = token::Client::new(&env, &Self::escrow_token(&env, &escrow));
+        if refunded > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &escrow.depositor,
+                &refunded,
+            );
+        }
+        if penalty > 0 {
+            let fee_recipient = Self::get_fee_config_internal(&env).fee_recipient;
+            Self::collect_fee(
+                &env,
+                &client,
+                &env.current_contract_address(),
+                &fee_recipient,
+                penalty,
+            );
         }
 
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                bounty_id,
+                amount: refunded,
+                refund_to: escrow.depositor.clone(),
+                timestamp: now,
+                refund_mode: RefundMode::Full,
+                remaining_amount: 0,
+            },
+        );
+        events::emit_escrow_cancelled(
+            &env,
+            events::EscrowCancelled {
+                bounty_id,
+                depositor: escrow.depositor.clone(),
+                refunded,
+                penalty,
+                timestamp: now,
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        monitoring::track_operation(&env, symbol_short!("cancel"), escrow.depositor, true);
+
+        Ok(penalty)
+    }
+
+    // ========================================================================
+    // Depositor Limits
+    // ========================================================================
+
+    /// Caps how many active escrows a single depositor may hold at once (admin only).
+    ///
+    /// Zero removes the cap. Lowering the cap never touches existing escrows;
+    /// it only blocks new locks until the depositor is back under it.
+    pub fn set_max_active_escrows(env: Env, max: u32) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
-
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
-        let timestamp = env.ledger().timestamp();
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxActiveEscrows, &max);
 
-        // Validate all items before processing (all-or-nothing approach)
-        let mut total_amount: i128 = 0;
-        for item in items.iter() {
-            // Check if bounty exists
-            if !env
-                .storage()
-                .persistent()
-                .has(&DataKey::Escrow(item.bounty_id))
-            {
-                return Err(Error::BountyNotFound);
-            }
+        Ok(())
+    }
 
-            let escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
-                .unwrap();
+    /// Returns the per-depositor active escrow cap (0 when unlimited).
+    pub fn get_max_active_escrows(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxActiveEscrows)
+            .unwrap_or(0)
+    }
 
-            // Check if funds are locked
-            if escrow.status != EscrowStatus::Locked {
-                return Err(Error::FundsNotLocked);
-            }
+    fn check_active_escrow_cap(env: &Env, depositor: &Address) -> Result<(), Error> {
+        let max: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxActiveEscrows)
+            .unwrap_or(0);
+        if max == 0 {
+            return Ok(());
+        }
 
-            // Check for duplicate bounty_ids in the batch
-            let mut count = 0u32;
-            for other_item in items.iter() {
-                if other_item.bounty_id == item.bounty_id {
-                    count += 1;
-                }
-            }
-            if count > 1 {
-                return Err(Error::DuplicateBountyId);
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor.clone()))
+            .unwrap_or(vec![env]);
+        let mut active = 0u32;
+        for bounty_id in index.iter() {
+            let escrow: Option<Escrow> =
+                env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+            if escrow.is_some_and(|escrow| Self::is_active(&escrow.status)) {
+                active += 1;
             }
+        }
 
-            total_amount = total_amount
-                .checked_add(escrow.amount)
-                .ok_or(Error::InvalidAmount)?;
+        if active >= max {
+            return Err(Error::TooManyActiveEscrows);
         }
+        Ok(())
+    }
 
-        // Process all items (atomic - all succeed or all fail)
-        let mut released_count = 0u32;
-        for item in items.iter() {
-            let mut escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
-                .unwrap();
+    // ========================================================================
+    // Contributor Reputation
+    // ========================================================================
 
-            // Transfer funds to contributor
-            client.transfer(&contract_address, &item.contributor, &escrow.amount);
+    /// Returns how many bounties have been fully released to `contributor`.
+    ///
+    /// Counts `release_funds`, `execute_release`, `batch_release_funds` and the
+    /// `release_percent` call that empties an escrow. Partial releases don't count.
+    pub fn get_contributor_completions(env: Env, contributor: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ContributorCompletions(contributor))
+            .unwrap_or(0)
+    }
 
-            // Update escrow status
-            escrow.status = EscrowStatus::Released;
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+    fn record_completion(env: &Env, contributor: &Address) {
+        let key = DataKey::ContributorCompletions(contributor.clone());
+        let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(count + 1));
+    }
 
-            // Emit individual event for each released bounty
-            emit_funds_released(
-                &env,
-                FundsReleased {
-                    bounty_id: item.bounty_id,
-                    amount: escrow.amount,
-                    recipient: item.contributor.clone(),
-                    timestamp,
-                },
-            );
+    // ========================================================================
+    // Depositor Reassignment
+    // ========================================================================
 
-            released_count += 1;
+    /// Moves a bounty's refund rights to a new depositor (admin only).
+    ///
+    /// Intended for recovering from a compromised depositor wallet. Every later
+    /// refund of the bounty is paid to `new_depositor`.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Bounty has no remaining funds to refund
+    /// * `TooManyActiveEscrows` - `new_depositor` is at the active escrow cap
+    ///
+    /// # Events
+    /// Emits: `DepositorReassigned { bounty_id, old_depositor, new_depositor, timestamp }`
+    pub fn reassign_depositor(
+        env: Env,
+        bounty_id: u64,
+        new_depositor: Address,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        // Emit batch event
-        emit_batch_funds_released(
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if !Self::is_active(&escrow.status) {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let old_depositor = escrow.depositor.clone();
+        if new_depositor != old_depositor {
+            Self::check_active_escrow_cap(&env, &new_depositor)?;
+        }
+        escrow.depositor = new_depositor.clone();
+        Self::store_escrow(&env, bounty_id, &escrow);
+        Self::unindex_depositor(&env, &old_depositor, bounty_id);
+        Self::index_depositor(&env, &new_depositor, bounty_id);
+
+        events::emit_depositor_reassigned(
             &env,
-            BatchFundsReleased {
-                count: released_count,
-                total_amount,
-                timestamp,
+            events::DepositorReassigned {
+                bounty_id,
+                old_depositor,
+                new_depositor,
+                timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(released_count)
+        monitoring::track_operation(&env, symbol_short!("reassign"), admin, true);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Reentrancy Guard
+    // ========================================================================
+
+    /// Marks a state-mutating operation as in progress, panicking on reentry.
+    ///
+    /// Only mutating entry points take the guard. Read-only views never check
+    /// it, so nested read-only calls (e.g. a notifier callback reading escrow
+    /// state mid-operation) are allowed while mutating reentry is blocked.
+    fn enter_guard(env: &Env, operation: Symbol) {
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &operation);
+    }
+
+    /// Returns the state-mutating operation currently in progress, if any.
+    pub fn get_active_operation(env: Env) -> Option<Symbol> {
+        env.storage().instance().get(&DataKey::ReentrancyGuard)
+    }
+
+    // ========================================================================
+    // Volume Statistics
+    // ========================================================================
+
+    /// Returns the total amount locked and released across all tracked bounties
+    /// denominated in `token`.
+    ///
+    /// `total_locked` sums the escrowed amount of every such bounty, whatever
+    /// its status; `total_released` sums the bounties paid out to contributors.
+    pub fn get_stats(env: Env, token: Address) -> EscrowStats {
+        let mut stats = EscrowStats {
+            total_locked: 0,
+            total_released: 0,
+        };
+        for page in 0..registry::page_count(&env) {
+            for bounty_id in registry::page(&env, page).iter() {
+                let escrow: Option<Escrow> =
+                    env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+                if let Some(escrow) =
+                    escrow.filter(|escrow| Self::escrow_token(&env, escrow) == token)
+                {
+                    let refunded: i128 = escrow.refund_history.iter().map(|r| r.amount).sum();
+                    stats.total_locked += escrow.amount;
+                    stats.total_released += match escrow.status {
+                        EscrowStatus::Released => escrow.amount - refunded,
+                        _ => escrow.amount - escrow.remaining_amount - refunded,
+                    };
+                }
+            }
+        }
+        stats
     }
 }
 
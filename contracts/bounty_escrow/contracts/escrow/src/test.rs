@@ -2,8 +2,8 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    token, vec, Address, Env, Vec,
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    token, vec, Address, Env, IntoVal, TryFromVal, Val, Vec,
 };
 
 fn create_token_contract<'a>(
@@ -64,6 +64,11 @@ impl<'a> TestSetup<'a> {
             escrow_address,
         }
     }
+
+    /// Checks the escrow accounting invariants against the current state.
+    fn assert_invariants(&self) {
+        invariants::assert_contract_invariants(&self.env, &self.escrow_address);
+    }
 }
 
 #[test]
@@ -74,9 +79,13 @@ fn test_lock_funds_success() {
     let deadline = setup.env.ledger().timestamp() + 1000;
 
     // Lock funds
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Verify stored escrow data
     // Note: amount stores net_amount (after fee), but fees are disabled by default
@@ -89,6 +98,8 @@ fn test_lock_funds_success() {
 
     // Verify contract balance
     assert_eq!(setup.token.balance(&setup.escrow_address), amount);
+
+    setup.assert_invariants();
 }
 
 #[test]
@@ -99,14 +110,22 @@ fn test_lock_funds_duplicate() {
     let amount = 1000;
     let deadline = setup.env.ledger().timestamp() + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Try to lock again with same bounty_id
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 }
 
 #[test]
@@ -117,9 +136,13 @@ fn test_lock_funds_negative_amount() {
     let amount = -100;
     let deadline = setup.env.ledger().timestamp() + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 }
 
 #[test]
@@ -129,9 +152,13 @@ fn test_get_escrow_info() {
     let amount = 1000;
     let deadline = setup.env.ledger().timestamp() + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     let escrow = setup.escrow.get_escrow_info(&bounty_id);
     assert_eq!(escrow.amount, amount);
@@ -147,16 +174,22 @@ fn test_release_funds_success() {
     let amount = 1000;
     let deadline = setup.env.ledger().timestamp() + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Verify initial balances
     assert_eq!(setup.token.balance(&setup.escrow_address), amount);
     assert_eq!(setup.token.balance(&setup.contributor), 0);
 
     // Release funds
-    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    setup
+        .escrow
+        .release_funds(&bounty_id, &setup.contributor, &None);
 
     // Verify updated state
     let stored_escrow = setup.escrow.get_escrow_info(&bounty_id);
@@ -165,6 +198,8 @@ fn test_release_funds_success() {
     // Verify balances after release (fees disabled by default, so net_amount = amount)
     assert_eq!(setup.token.balance(&setup.escrow_address), 0);
     assert_eq!(setup.token.balance(&setup.contributor), amount);
+
+    setup.assert_invariants();
 }
 
 #[test]
@@ -175,13 +210,21 @@ fn test_release_funds_already_released() {
     let amount = 1000;
     let deadline = setup.env.ledger().timestamp() + 1000;
 
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
-    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+        .release_funds(&bounty_id, &setup.contributor, &None);
 
     // Try to release again
-    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    setup
+        .escrow
+        .release_funds(&bounty_id, &setup.contributor, &None);
 }
 
 #[test]
@@ -189,7 +232,69 @@ fn test_release_funds_already_released() {
 fn test_release_funds_not_found() {
     let setup = TestSetup::new();
     let bounty_id = 1;
-    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    setup
+        .escrow
+        .release_funds(&bounty_id, &setup.contributor, &None);
+}
+
+#[test]
+fn test_release_funds_records_receipt_id() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let receipt = BytesN::from_array(&setup.env, &[7u8; 32]);
+    setup
+        .escrow
+        .release_funds(&1, &setup.contributor, &Some(receipt.clone()));
+
+    let topics: Vec<Val> =
+        (symbol_short!("f_rel"), 1u64, setup.contributor.clone()).into_val(&setup.env);
+    let (_, _, data) = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .unwrap();
+    let event = events::FundsReleased::try_from_val(&setup.env, &data).unwrap();
+    assert_eq!(event.receipt_id, Some(receipt.clone().into()));
+
+    let payouts = setup.escrow.get_payout_history(&1);
+    assert_eq!(payouts.len(), 1);
+    assert_eq!(payouts.get(0).unwrap().receipt_id, Some(receipt.into()));
+}
+
+#[test]
+fn test_release_funds_rejects_duplicate_receipt() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+
+    let receipt = BytesN::from_array(&setup.env, &[9u8; 32]);
+    setup
+        .escrow
+        .release_funds(&1, &setup.contributor, &Some(receipt.clone()));
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&1, &setup.contributor, &Some(receipt.clone())),
+        Err(Ok(Error::DuplicateReceipt))
+    );
+
+    // Receipts are unique per bounty, so another bounty may reuse the ID
+    setup
+        .escrow
+        .release_funds(&2, &setup.contributor, &Some(receipt));
+    assert_eq!(setup.token.balance(&setup.contributor), 2000);
 }
 
 // ============================================================================
@@ -204,9 +309,13 @@ fn test_refund_full_after_deadline() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Advance time past deadline
     setup.env.ledger().set_timestamp(deadline + 1);
@@ -242,6 +351,52 @@ fn test_refund_full_after_deadline() {
     assert_eq!(refund_history.get(0).unwrap().mode, RefundMode::Full);
 }
 
+#[test]
+fn test_refund_full_twice_rejected() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.env.ledger().set_timestamp(deadline + 1);
+
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+    let res = setup
+        .escrow
+        .try_refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+    assert_eq!(res, Err(Ok(Error::FundsNotLocked)));
+    assert_eq!(setup.escrow.get_refund_history(&1).len(), 1);
+}
+
+#[test]
+fn test_refund_full_after_partial_release() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.release_percent(&1, &setup.contributor, &4000);
+    setup.env.ledger().set_timestamp(deadline);
+
+    let depositor_before = setup.token.balance(&setup.depositor);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        depositor_before + 600
+    );
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+
+    setup.assert_invariants();
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")] // DeadlineNotPassed
 fn test_refund_full_before_deadline() {
@@ -251,9 +406,13 @@ fn test_refund_full_before_deadline() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Attempt full refund before deadline (should fail)
     setup.escrow.refund(
@@ -277,9 +436,13 @@ fn test_refund_partial_after_deadline() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &total_amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &total_amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Advance time past deadline
     setup.env.ledger().set_timestamp(deadline + 1);
@@ -328,9 +491,13 @@ fn test_refund_partial_multiple_times() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &total_amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &total_amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // First partial refund
@@ -362,6 +529,8 @@ fn test_refund_partial_multiple_times() {
     assert_eq!(refund_history.len(), 2);
     assert_eq!(refund_history.get(0).unwrap().amount, refund1);
     assert_eq!(refund_history.get(1).unwrap().amount, refund2);
+
+    setup.assert_invariants();
 }
 
 #[test]
@@ -374,9 +543,13 @@ fn test_refund_partial_before_deadline() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Attempt partial refund before deadline (should fail)
     setup.escrow.refund(
@@ -394,6 +567,9 @@ fn test_refund_partial_before_deadline() {
 #[test]
 fn test_refund_custom_after_deadline() {
     let setup = TestSetup::new();
+    setup
+        .escrow
+        .set_refund_policy(&RefundPolicy::DepositorOrApproved);
     let bounty_id = 1;
     let amount = 1000;
     let refund_amount = 500;
@@ -401,9 +577,13 @@ fn test_refund_custom_after_deadline() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // Initial balances
@@ -447,9 +627,13 @@ fn test_refund_custom_before_deadline_without_approval() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Attempt custom refund before deadline without approval (should fail)
     setup.escrow.refund(
@@ -467,6 +651,9 @@ fn test_refund_custom_before_deadline_without_approval() {
 #[test]
 fn test_refund_approval_workflow() {
     let setup = TestSetup::new();
+    setup
+        .escrow
+        .set_refund_policy(&RefundPolicy::DepositorOrApproved);
     let bounty_id = 1;
     let amount = 1000;
     let refund_amount = 500;
@@ -474,9 +661,13 @@ fn test_refund_approval_workflow() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Admin approves refund before deadline
     setup.escrow.approve_refund(
@@ -538,9 +729,13 @@ fn test_refund_approval_mismatch() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Admin approves refund for 500
     setup.escrow.approve_refund(
@@ -572,9 +767,13 @@ fn test_refund_approval_non_admin() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Note: With mock_all_auths(), we can't easily test unauthorized access
     // The contract's require_auth() will enforce admin-only access in production
@@ -596,9 +795,13 @@ fn test_refund_history_tracking() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &total_amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &total_amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // First refund (Partial)
@@ -657,6 +860,9 @@ fn test_refund_history_tracking() {
 #[test]
 fn test_refund_history_with_custom_recipients() {
     let setup = TestSetup::new();
+    setup
+        .escrow
+        .set_refund_policy(&RefundPolicy::DepositorOrApproved);
     let bounty_id = 1;
     let total_amount = 1000;
     let recipient1 = Address::generate(&setup.env);
@@ -666,9 +872,13 @@ fn test_refund_history_with_custom_recipients() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &total_amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &total_amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // First custom refund
@@ -692,6 +902,8 @@ fn test_refund_history_with_custom_recipients() {
     assert_eq!(refund_history.len(), 2);
     assert_eq!(refund_history.get(0).unwrap().recipient, recipient1);
     assert_eq!(refund_history.get(1).unwrap().recipient, recipient2);
+
+    setup.assert_invariants();
 }
 
 // ============================================================================
@@ -707,9 +919,13 @@ fn test_refund_invalid_amount_zero() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // Try to refund zero amount
@@ -728,9 +944,13 @@ fn test_refund_invalid_amount_exceeds_remaining() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // Try to refund more than available
@@ -752,9 +972,13 @@ fn test_refund_custom_missing_amount() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // Custom refund requires amount
@@ -776,9 +1000,13 @@ fn test_refund_custom_missing_recipient() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // Custom refund requires recipient
@@ -798,9 +1026,13 @@ fn test_get_refund_eligibility() {
     let current_time = setup.env.ledger().timestamp();
     let deadline = current_time + 1000;
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Before deadline, no approval
     let (can_refund, deadline_passed, remaining, approval) =
@@ -842,14 +1074,18 @@ fn test_get_balance() {
     let deadline = setup.env.ledger().timestamp() + 1000;
 
     // Initial balance should be 0
-    assert_eq!(setup.escrow.get_balance(), 0);
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), 0);
 
-    setup
-        .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &setup.token.address,
+    );
 
     // Balance should be updated
-    assert_eq!(setup.escrow.get_balance(), amount);
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), amount);
 }
 
 // ============================================================================
@@ -869,18 +1105,21 @@ fn test_batch_lock_funds_success() {
             depositor: setup.depositor.clone(),
             amount: 1000,
             deadline,
+            token: setup.token.address.clone(),
         },
         LockFundsItem {
             bounty_id: 2,
             depositor: setup.depositor.clone(),
             amount: 2000,
             deadline,
+            token: setup.token.address.clone(),
         },
         LockFundsItem {
             bounty_id: 3,
             depositor: setup.depositor.clone(),
             amount: 3000,
             deadline,
+            token: setup.token.address.clone(),
         },
     ];
 
@@ -898,7 +1137,7 @@ fn test_batch_lock_funds_success() {
     }
 
     // Verify contract balance
-    assert_eq!(setup.escrow.get_balance(), 6000);
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), 6000);
 }
 
 #[test]
@@ -918,7 +1157,7 @@ fn test_batch_lock_funds_duplicate_bounty_id() {
     // Lock a bounty first
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
 
     // Try to batch lock with duplicate bounty_id
     let items = vec![
@@ -928,12 +1167,14 @@ fn test_batch_lock_funds_duplicate_bounty_id() {
             depositor: setup.depositor.clone(),
             amount: 2000,
             deadline,
+            token: setup.token.address.clone(),
         },
         LockFundsItem {
             bounty_id: 2,
             depositor: setup.depositor.clone(),
             amount: 3000,
             deadline,
+            token: setup.token.address.clone(),
         },
     ];
 
@@ -953,18 +1194,119 @@ fn test_batch_lock_funds_duplicate_in_batch() {
             depositor: setup.depositor.clone(),
             amount: 1000,
             deadline,
+            token: setup.token.address.clone(),
         },
         LockFundsItem {
             bounty_id: 1, // Duplicate in same batch
             depositor: setup.depositor.clone(),
             amount: 2000,
             deadline,
+            token: setup.token.address.clone(),
         },
     ];
 
     setup.escrow.batch_lock_funds(&items);
 }
 
+#[test]
+fn test_batch_lock_funds_over_max_size() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let mut items = Vec::new(&setup.env);
+    for bounty_id in 0..(MAX_BATCH_SIZE as u64 + 1) {
+        items.push_back(LockFundsItem {
+            bounty_id,
+            depositor: setup.depositor.clone(),
+            amount: 10,
+            deadline,
+            token: setup.token.address.clone(),
+        });
+    }
+
+    let res = setup.escrow.try_batch_lock_funds(&items);
+    assert_eq!(res, Err(Ok(Error::InvalidBatchSize)));
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), 0);
+}
+
+#[test]
+fn test_batch_lock_funds_existing_bounty_rolls_back() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+    let depositor_before = setup.token.balance(&setup.depositor);
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline,
+            token: setup.token.address.clone(),
+        },
+        LockFundsItem {
+            bounty_id: 2, // Already exists
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline,
+            token: setup.token.address.clone(),
+        },
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline,
+            token: setup.token.address.clone(),
+        },
+    ];
+
+    let res = setup.escrow.try_batch_lock_funds(&items);
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+
+    // Nothing from the batch was locked
+    assert_eq!(
+        setup.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        setup.escrow.try_get_escrow_info(&3),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), depositor_before);
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), 1000);
+}
+
+#[test]
+fn test_batch_lock_funds_rejects_past_deadline() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline: 2000,
+            token: setup.token.address.clone(),
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline: 1000,
+            token: setup.token.address.clone(),
+        },
+    ];
+
+    let res = setup.escrow.try_batch_lock_funds(&items);
+    assert_eq!(res, Err(Ok(Error::InvalidDeadline)));
+}
+
 #[test]
 fn test_batch_release_funds_success() {
     let setup = TestSetup::new();
@@ -973,13 +1315,13 @@ fn test_batch_release_funds_success() {
     // Lock multiple bounties
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &setup.token.address);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &3, &3000, &deadline);
+        .lock_funds(&setup.depositor, &3, &3000, &deadline, &setup.token.address);
 
     // Create contributors
     let contributor1 = Address::generate(&setup.env);
@@ -1004,7 +1346,7 @@ fn test_batch_release_funds_success() {
     ];
 
     // Batch release funds
-    let count = setup.escrow.batch_release_funds(&items);
+    let count = setup.escrow.batch_release_funds(&items, &true);
     assert_eq!(count, 3);
 
     // Verify all bounties are released
@@ -1017,32 +1359,76 @@ fn test_batch_release_funds_success() {
     assert_eq!(setup.token.balance(&contributor1), 1000);
     assert_eq!(setup.token.balance(&contributor2), 2000);
     assert_eq!(setup.token.balance(&contributor3), 3000);
-    assert_eq!(setup.escrow.get_balance(), 0);
-}
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), 0);
 
-#[test]
-#[should_panic(expected = "Error(Contract, #10)")] // InvalidBatchSize
-fn test_batch_release_funds_empty() {
-    let setup = TestSetup::new();
-    let items: Vec<ReleaseFundsItem> = vec![&setup.env];
-    setup.escrow.batch_release_funds(&items);
+    setup.assert_invariants();
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #4)")] // BountyNotFound
-fn test_batch_release_funds_not_found() {
+fn test_batch_release_funds_charges_release_fee_on_net_amount() {
     let setup = TestSetup::new();
-    let contributor = Address::generate(&setup.env);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .update_fee_config(&Some(1_000), &Some(1_000), &None, &Some(true));
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &setup.token.address);
+
+    // Each contributor's first fee-bearing operation is free
+    setup.escrow.set_free_operations(&1);
+    let contributor1 = Address::generate(&setup.env);
+    let contributor2 = Address::generate(&setup.env);
+    setup.escrow.release_percent(&2, &contributor2, &5000);
 
     let items = vec![
         &setup.env,
         ReleaseFundsItem {
-            bounty_id: 999, // Doesn't exist
-            contributor: contributor.clone(),
+            bounty_id: 1,
+            contributor: contributor1.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: contributor2.clone(),
+        },
+    ];
+    assert_eq!(setup.escrow.batch_release_funds(&items, &true), 2);
+
+    // Lock fees leave 900 and 1800 escrowed; bounty 2 already paid 900 fee-free
+    assert_eq!(setup.token.balance(&contributor1), 900);
+    assert_eq!(setup.token.balance(&contributor2), 900 + 810);
+    assert_eq!(setup.token.balance(&setup.admin), 100 + 200 + 90);
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), 0);
+    setup.assert_invariants();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")] // InvalidBatchSize
+fn test_batch_release_funds_empty() {
+    let setup = TestSetup::new();
+    let items: Vec<ReleaseFundsItem> = vec![&setup.env];
+    setup.escrow.batch_release_funds(&items, &true);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")] // BountyNotFound
+fn test_batch_release_funds_not_found() {
+    let setup = TestSetup::new();
+    let contributor = Address::generate(&setup.env);
+
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 999, // Doesn't exist
+            contributor: contributor.clone(),
         },
     ];
 
-    setup.escrow.batch_release_funds(&items);
+    setup.escrow.batch_release_funds(&items, &true);
 }
 
 #[test]
@@ -1054,13 +1440,13 @@ fn test_batch_release_funds_already_released() {
     // Lock and release one bounty
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
-    setup.escrow.release_funds(&1, &setup.contributor);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
 
     // Lock another bounty
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &setup.token.address);
 
     let contributor2 = Address::generate(&setup.env);
 
@@ -1077,7 +1463,62 @@ fn test_batch_release_funds_already_released() {
         },
     ];
 
-    setup.escrow.batch_release_funds(&items);
+    setup.escrow.batch_release_funds(&items, &true);
+}
+
+#[test]
+fn test_batch_release_funds_non_strict_skips_inactive() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &4000, &deadline, &setup.token.address);
+    setup.escrow.partial_refund(&1, &1000);
+    setup.escrow.release_percent(&3, &setup.contributor, &2500);
+
+    let contributor2 = Address::generate(&setup.env);
+    let contributor3 = Address::generate(&setup.env);
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1, // Already refunded
+            contributor: setup.contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: contributor2.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 3, // Partially released
+            contributor: contributor3.clone(),
+        },
+    ];
+
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&items, &true),
+        Err(Ok(Error::FundsNotLocked))
+    );
+
+    let count = setup.escrow.batch_release_funds(&items, &false);
+    assert_eq!(count, 2);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(setup.token.balance(&contributor2), 2000);
+    // The remaining 75% of bounty 3 is paid out
+    assert_eq!(setup.token.balance(&contributor3), 3000);
+    let escrow3 = setup.escrow.get_escrow_info(&3);
+    assert_eq!(escrow3.status, EscrowStatus::Released);
+    assert_eq!(escrow3.remaining_amount, 0);
 }
 
 #[test]
@@ -1088,7 +1529,7 @@ fn test_batch_release_funds_duplicate_in_batch() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
 
     let contributor = Address::generate(&setup.env);
 
@@ -1104,7 +1545,7 @@ fn test_batch_release_funds_duplicate_in_batch() {
         },
     ];
 
-    setup.escrow.batch_release_funds(&items);
+    setup.escrow.batch_release_funds(&items, &true);
 }
 
 #[test]
@@ -1116,7 +1557,7 @@ fn test_batch_operations_atomicity() {
     // Lock one bounty successfully
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
 
     // Try to batch lock with one valid and one that would fail (duplicate)
     // This should fail entirely due to atomicity
@@ -1127,12 +1568,14 @@ fn test_batch_operations_atomicity() {
             depositor: setup.depositor.clone(),
             amount: 2000,
             deadline,
+            token: setup.token.address.clone(),
         },
         LockFundsItem {
             bounty_id: 1, // Already exists - should cause entire batch to fail
             depositor: setup.depositor.clone(),
             amount: 3000,
             deadline,
+            token: setup.token.address.clone(),
         },
     ];
 
@@ -1153,6 +1596,7 @@ fn test_batch_operations_large_batch() {
             depositor: setup.depositor.clone(),
             amount: (i * 100) as i128,
             deadline,
+            token: setup.token.address.clone(),
         });
     }
 
@@ -1179,6 +1623,4536 @@ fn test_batch_operations_large_batch() {
     }
 
     // Batch release
-    let release_count = setup.escrow.batch_release_funds(&release_items);
+    let release_count = setup.escrow.batch_release_funds(&release_items, &true);
     assert_eq!(release_count, 10);
 }
+
+#[test]
+fn test_config_hash_stable_without_config_changes() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let hash_before = setup.escrow.get_config_hash();
+    assert_eq!(setup.escrow.get_config_hash(), hash_before);
+
+    // Escrow activity does not touch configuration
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    assert_eq!(setup.escrow.get_config_hash(), hash_before);
+}
+
+#[test]
+fn test_config_hash_changes_after_fee_update() {
+    let setup = TestSetup::new();
+
+    let hash_before = setup.escrow.get_config_hash();
+
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &None, &None, &Some(true));
+
+    let hash_after = setup.escrow.get_config_hash();
+    assert_ne!(hash_after, hash_before);
+    assert_eq!(setup.escrow.get_config_hash(), hash_after);
+}
+
+// ============================================================================
+// PROGRAM LINKING TESTS
+// ============================================================================
+
+#[test]
+fn test_release_program_bounties_as_group() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let program_id = String::from_str(&setup.env, "Hackathon2024");
+    let contributor2 = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &3000, &deadline, &setup.token.address);
+
+    setup.escrow.set_bounty_program(&1, &program_id);
+    setup.escrow.set_bounty_program(&2, &program_id);
+
+    assert_eq!(
+        setup.escrow.get_program_bounties(&program_id),
+        vec![&setup.env, 1u64, 2u64]
+    );
+    assert_eq!(setup.escrow.get_bounty_program(&3), None);
+
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: setup.contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: contributor2.clone(),
+        },
+    ];
+    let count = setup.escrow.release_program_bounties(&program_id, &items);
+    assert_eq!(count, 2);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(setup.token.balance(&contributor2), 2000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&3).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")] // ProgramMismatch
+fn test_release_program_bounties_rejects_untagged_bounty() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let program_id = String::from_str(&setup.env, "Hackathon2024");
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &setup.token.address);
+    setup.escrow.set_bounty_program(&1, &program_id);
+
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: setup.contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: setup.contributor.clone(),
+        },
+    ];
+    setup.escrow.release_program_bounties(&program_id, &items);
+}
+
+// ============================================================================
+// METADATA TESTS
+// ============================================================================
+
+fn sample_metadata(env: &Env, tag_count: u32) -> EscrowMetadata {
+    let mut tags = Vec::new(env);
+    for _ in 0..tag_count {
+        tags.push_back(String::from_str(env, "rust"));
+    }
+    EscrowMetadata {
+        repo_id: String::from_str(env, "grainlify/grainlify"),
+        issue_id: String::from_str(env, "42"),
+        bounty_type: String::from_str(env, "bug"),
+        tags,
+        custom_fields: Map::new(env),
+    }
+}
+
+#[test]
+fn test_lock_funds_with_metadata_success() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let metadata = sample_metadata(&setup.env, 3);
+
+    setup.escrow.lock_funds_with_metadata(
+        &setup.depositor,
+        &1,
+        &1000,
+        &deadline,
+        &setup.token.address,
+        &metadata,
+    );
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1000);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(setup.escrow.get_escrow_metadata(&1), metadata);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+}
+
+#[test]
+fn test_lock_funds_with_metadata_too_large_rejects_lock() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let metadata = sample_metadata(&setup.env, EscrowMetadata::MAX_TAGS + 1);
+
+    let result = setup.escrow.try_lock_funds_with_metadata(
+        &setup.depositor,
+        &1,
+        &1000,
+        &deadline,
+        &setup.token.address,
+        &metadata,
+    );
+    assert_eq!(result, Err(Ok(Error::MetadataTooLarge)));
+
+    // Nothing was locked and no tokens moved
+    assert!(setup.escrow.try_get_escrow_info(&1).is_err());
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_set_escrow_metadata_and_read_with_escrow() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let combined = setup.escrow.get_escrow_with_metadata(&1);
+    assert_eq!(combined.escrow.amount, 1000);
+    assert_eq!(combined.metadata.len(), 0);
+
+    let metadata = sample_metadata(&setup.env, 2);
+    setup
+        .escrow
+        .set_escrow_metadata(&setup.depositor, &1, &metadata);
+    assert_eq!(setup.escrow.get_escrow_metadata(&1), metadata);
+    assert_eq!(
+        setup.escrow.get_escrow_with_metadata(&1).metadata,
+        vec![&setup.env, metadata]
+    );
+
+    let too_large = sample_metadata(&setup.env, EscrowMetadata::MAX_TAGS + 1);
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_escrow_metadata(&setup.depositor, &1, &too_large),
+        Err(Ok(Error::MetadataTooLarge))
+    );
+}
+
+#[test]
+fn test_set_escrow_metadata_rejects_too_many_custom_fields() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let mut metadata = sample_metadata(&setup.env, 1);
+    for i in 0..EscrowMetadata::MAX_CUSTOM_FIELDS {
+        let key = String::from_bytes(&setup.env, &[b'k', i as u8]);
+        metadata
+            .custom_fields
+            .set(key, String::from_str(&setup.env, "v"));
+    }
+    setup
+        .escrow
+        .set_escrow_metadata(&setup.depositor, &1, &metadata);
+
+    let extra = String::from_bytes(&setup.env, &[b'k', 0xff]);
+    metadata
+        .custom_fields
+        .set(extra, String::from_str(&setup.env, "v"));
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_escrow_metadata(&setup.depositor, &1, &metadata),
+        Err(Ok(Error::MetadataTooLarge))
+    );
+}
+
+#[test]
+fn test_set_escrow_metadata_rejects_over_length_strings() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let long_value = [b'a'; EscrowMetadata::MAX_FIELD_VALUE_LEN as usize + 1];
+    let mut metadata = sample_metadata(&setup.env, 1);
+    metadata.custom_fields.set(
+        String::from_str(&setup.env, "notes"),
+        String::from_bytes(&setup.env, &long_value),
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_escrow_metadata(&setup.depositor, &1, &metadata),
+        Err(Ok(Error::MetadataTooLarge))
+    );
+
+    let long_type = [b'b'; EscrowMetadata::MAX_BOUNTY_TYPE_LEN as usize + 1];
+    let mut metadata = sample_metadata(&setup.env, 1);
+    metadata.bounty_type = String::from_bytes(&setup.env, &long_type);
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_escrow_metadata(&setup.depositor, &1, &metadata),
+        Err(Ok(Error::MetadataTooLarge))
+    );
+}
+
+#[test]
+fn test_escrow_metadata_authorization() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let outsider = Address::generate(&setup.env);
+    let metadata = sample_metadata(&setup.env, 1);
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_escrow_metadata(&outsider, &1, &metadata),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        setup.escrow.try_get_escrow_metadata(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    setup
+        .escrow
+        .set_escrow_metadata(&setup.depositor, &1, &metadata);
+    let updated = sample_metadata(&setup.env, 2);
+    setup.escrow.set_escrow_metadata(&setup.admin, &1, &updated);
+    assert_eq!(setup.escrow.get_escrow_metadata(&1), updated);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_escrow_metadata_requires_caller_signature() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    // A third party naming the depositor as caller cannot sign for them
+    let metadata = sample_metadata(&setup.env, 1);
+    let stranger = Address::generate(&setup.env);
+    setup.env.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &setup.escrow_address,
+            fn_name: "set_escrow_metadata",
+            args: (setup.depositor.clone(), 1u64, metadata.clone()).into_val(&setup.env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    setup
+        .escrow
+        .set_escrow_metadata(&setup.depositor, &1, &metadata);
+}
+
+#[test]
+fn test_get_bounties_by_tag_tracks_metadata_changes() {
+    let setup = TestSetup::new();
+    let env = &setup.env;
+    let deadline = env.ledger().timestamp() + 1000;
+    for bounty_id in 1..=2u64 {
+        setup.escrow.lock_funds(
+            &setup.depositor,
+            &bounty_id,
+            &1000,
+            &deadline,
+            &setup.token.address,
+        );
+    }
+
+    let rust = String::from_str(env, "rust");
+    let wasm = String::from_str(env, "wasm");
+    let docs = String::from_str(env, "docs");
+    let page = Pagination {
+        start_index: 0,
+        limit: 10,
+    };
+
+    let mut first = sample_metadata(env, 0);
+    first.tags = vec![env, rust.clone(), wasm.clone(), rust.clone()];
+    setup
+        .escrow
+        .set_escrow_metadata(&setup.depositor, &1, &first);
+    let mut second = sample_metadata(env, 0);
+    second.tags = vec![env, rust.clone(), docs.clone()];
+    setup
+        .escrow
+        .set_escrow_metadata(&setup.depositor, &2, &second);
+
+    assert_eq!(
+        setup.escrow.get_bounties_by_tag(&rust, &page),
+        vec![env, 1u64, 2]
+    );
+    assert_eq!(
+        setup.escrow.get_bounties_by_tag(&wasm, &page),
+        vec![env, 1u64]
+    );
+    assert_eq!(
+        setup.escrow.get_bounties_by_tag(&docs, &page),
+        vec![env, 2u64]
+    );
+    assert_eq!(
+        setup.escrow.get_bounties_by_tag(
+            &rust,
+            &Pagination {
+                start_index: 1,
+                limit: 10,
+            }
+        ),
+        vec![env, 2u64]
+    );
+
+    // Replacing metadata drops the bounty from tags it no longer carries.
+    first.tags = vec![env, docs.clone()];
+    setup
+        .escrow
+        .set_escrow_metadata(&setup.depositor, &1, &first);
+
+    assert_eq!(
+        setup.escrow.get_bounties_by_tag(&rust, &page),
+        vec![env, 2u64]
+    );
+    assert_eq!(setup.escrow.get_bounties_by_tag(&wasm, &page).len(), 0);
+    assert_eq!(
+        setup.escrow.get_bounties_by_tag(&docs, &page),
+        vec![env, 2u64, 1]
+    );
+}
+
+#[test]
+fn test_full_tag_index_rejects_new_bounties() {
+    let setup = TestSetup::new();
+    let env = &setup.env;
+    let deadline = env.ledger().timestamp() + 1000;
+    let rust = String::from_str(env, "rust");
+
+    // Bounty 1 already holds a slot in the full index
+    let mut full: Vec<u64> = vec![env, 1u64];
+    for bounty_id in 1000..1000 + u64::from(MAX_BOUNTIES_PER_TAG) - 1 {
+        full.push_back(bounty_id);
+    }
+    env.as_contract(&setup.escrow_address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::TagIndex(rust.clone()), &full);
+    });
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .set_escrow_metadata(&setup.depositor, &1, &sample_metadata(env, 1));
+
+    assert_eq!(
+        setup.escrow.try_lock_funds_with_metadata(
+            &setup.depositor,
+            &2,
+            &1000,
+            &deadline,
+            &setup.token.address,
+            &sample_metadata(env, 1),
+        ),
+        Err(Ok(Error::TagIndexFull))
+    );
+    assert!(setup.escrow.try_get_escrow_info(&2).is_err());
+}
+
+#[test]
+fn test_metadata_accessors_reject_unknown_bounty() {
+    let setup = TestSetup::new();
+    let metadata = sample_metadata(&setup.env, 1);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_escrow_metadata(&setup.depositor, &99, &metadata),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        setup.escrow.try_get_escrow_metadata(&99),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        setup.escrow.try_get_escrow_with_metadata(&99),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+// ============================================================================
+// TOP-UP TESTS
+// ============================================================================
+
+#[test]
+fn test_top_up_increases_locked_amount() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.top_up(&setup.depositor, &1, &500);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1500);
+    assert_eq!(escrow.remaining_amount, 1500);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1500);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 1500);
+
+    // The topped-up amount is paid out in full on release
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 1500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")] // FundsNotLocked
+fn test_top_up_rejected_on_refunded_escrow() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    setup.escrow.top_up(&setup.depositor, &1, &500);
+}
+
+#[test]
+fn test_top_up_partially_refunded_escrow_and_overflow() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.partial_refund(&1, &400);
+    setup.escrow.top_up(&setup.depositor, &1, &500);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1500);
+    assert_eq!(escrow.remaining_amount, 1100);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+
+    assert_eq!(
+        setup.escrow.try_top_up(&setup.depositor, &1, &i128::MAX),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&1).amount, 1500);
+    setup.assert_invariants();
+}
+
+// ============================================================================
+// SPLIT TESTS
+// ============================================================================
+
+#[test]
+fn test_split_bounty_conserves_balance() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let contributor2 = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.split_bounty(&setup.depositor, &1, &2, &400);
+
+    let source = setup.escrow.get_escrow_info(&1);
+    let split = setup.escrow.get_escrow_info(&2);
+    assert_eq!(source.amount, 600);
+    assert_eq!(source.remaining_amount, 600);
+    assert_eq!(split.amount, 400);
+    assert_eq!(split.remaining_amount, 400);
+    assert_eq!(split.depositor, setup.depositor);
+    assert_eq!(split.deadline, deadline);
+    assert_eq!(split.status, EscrowStatus::Locked);
+
+    // No tokens moved, the contract still holds the original deposit
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    setup.escrow.release_funds(&2, &contributor2, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 600);
+    assert_eq!(setup.token.balance(&contributor2), 400);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")] // InsufficientFunds
+fn test_split_bounty_exceeding_remaining() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.split_bounty(&setup.depositor, &1, &2, &1001);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")] // BountyExists
+fn test_split_bounty_into_existing_id() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+    setup.escrow.split_bounty(&setup.depositor, &1, &2, &400);
+}
+
+// ============================================================================
+// MERGE TESTS
+// ============================================================================
+
+#[test]
+fn test_merge_bounties_sums_remaining() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline, &setup.token.address);
+
+    setup.escrow.merge_bounties(&setup.depositor, &1, &2);
+
+    let merged = setup.escrow.get_escrow_info(&1);
+    assert_eq!(merged.amount, 1500);
+    assert_eq!(merged.remaining_amount, 1500);
+    assert_eq!(merged.status, EscrowStatus::Locked);
+    assert!(setup.escrow.try_get_escrow_info(&2).is_err());
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 1500);
+}
+
+#[test]
+fn test_merge_bounties_clears_source_state() {
+    let setup = TestSetup::new();
+    let source_deadline = setup.env.ledger().timestamp() + 1000;
+    let target_deadline = source_deadline + 5000;
+    setup.escrow.set_submission_grace(&300);
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1000,
+        &target_deadline,
+        &setup.token.address,
+    );
+    setup.env.ledger().set_timestamp(120);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &2,
+        &1000,
+        &source_deadline,
+        &setup.token.address,
+    );
+    setup.escrow.set_release_limit(&2, &10_000, &86_400);
+    assert_eq!(
+        setup.escrow.release_percent(&2, &setup.contributor, &5000),
+        500
+    );
+    setup.env.ledger().set_timestamp(source_deadline - 100);
+    setup.escrow.register_submission(&setup.admin, &2);
+
+    // A partially released source can be merged
+    setup.escrow.merge_bounties(&setup.depositor, &1, &2);
+    let target = setup.escrow.get_escrow_info(&1);
+    assert_eq!(target.remaining_amount, 1500);
+    assert_eq!(target.status, EscrowStatus::PartiallyReleased);
+
+    assert_eq!(setup.escrow.get_submission(&2), None);
+    assert_eq!(setup.escrow.get_payout_history(&2).len(), 0);
+    assert_eq!(setup.escrow.get_release_limit(&2), None);
+    setup.assert_invariants();
+}
+
+#[test]
+fn test_merge_bounties_rejects_target_with_pending_release() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.env.ledger().set_timestamp(120);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+    setup.escrow.set_release_delay(&100);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+
+    assert_eq!(
+        setup.escrow.try_merge_bounties(&setup.depositor, &1, &2),
+        Err(Ok(Error::ReleasePending))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")] // Unauthorized
+fn test_merge_bounties_rejects_mismatched_depositors() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other_depositor = Address::generate(&setup.env);
+    setup.token_admin.mint(&other_depositor, &1000);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&other_depositor, &2, &500, &deadline, &setup.token.address);
+
+    setup.escrow.merge_bounties(&setup.admin, &1, &2);
+}
+
+// ============================================================================
+// EMERGENCY WITHDRAWAL TESTS
+// ============================================================================
+
+#[test]
+fn test_emergency_withdraw_single_admin_without_quorum() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let recipient = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.pause();
+
+    let withdrawn = setup
+        .escrow
+        .emergency_withdraw(&setup.admin, &setup.token.address, &recipient);
+    assert_eq!(withdrawn, 1000);
+    assert_eq!(setup.token.balance(&recipient), 1000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_emergency_withdraw_requires_pause() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let recipient = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    assert!(!setup.escrow.is_paused());
+    assert_eq!(
+        setup
+            .escrow
+            .try_emergency_withdraw(&setup.admin, &setup.token.address, &recipient),
+        Err(Ok(Error::ContractNotPaused))
+    );
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+
+    setup.escrow.pause();
+    assert!(setup.escrow.is_paused());
+    assert_eq!(
+        setup
+            .escrow
+            .emergency_withdraw(&setup.admin, &setup.token.address, &recipient),
+        1000
+    );
+
+    // Accounting is intentionally left stale
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 1000);
+}
+
+#[test]
+fn test_pause_blocks_lock_release_and_refund() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.pause();
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&1, &setup.contributor, &None),
+        Err(Ok(Error::ContractPaused))
+    );
+    setup.env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        setup
+            .escrow
+            .try_refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    setup.escrow.unpause();
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+}
+
+#[test]
+fn test_pause_inflows_only_blocks_locks() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let token = setup.token.address.clone();
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &token);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &token);
+    setup.escrow.pause_with_mode(&PauseMode::InflowsOnly);
+    assert_eq!(setup.escrow.get_pause_mode(), Some(PauseMode::InflowsOnly));
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &3, &1000, &deadline, &token),
+        Err(Ok(Error::ContractPaused))
+    );
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&2, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 1000);
+}
+
+#[test]
+fn test_pause_outflows_only_blocks_releases_but_allows_refunds() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let token = setup.token.address.clone();
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &token);
+    setup.escrow.pause_with_mode(&PauseMode::OutflowsOnly);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&1, &setup.contributor, &None),
+        Err(Ok(Error::ContractPaused))
+    );
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline, &token);
+
+    // A partial pause is not enough for an emergency drain
+    assert_eq!(
+        setup.escrow.try_emergency_withdraw(
+            &setup.admin,
+            &setup.token.address,
+            &Address::generate(&setup.env)
+        ),
+        Err(Ok(Error::ContractNotPaused))
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+
+    setup.escrow.unpause();
+    assert_eq!(setup.escrow.get_pause_mode(), None);
+    setup.escrow.release_funds(&2, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 500);
+}
+
+#[test]
+fn test_pause_blocks_secondary_fund_movements() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let token = setup.token.address.clone();
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &token);
+    setup.escrow.lock_funds_with_auto_refund(
+        &setup.depositor,
+        &2,
+        &1000,
+        &deadline,
+        &setup.token.address,
+        &true,
+    );
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &1000, &deadline, &token);
+    setup
+        .escrow
+        .approve_refund(&3, &400, &setup.depositor, &RefundMode::Partial);
+    setup.escrow.set_release_delay(&100);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+
+    setup.escrow.pause();
+    setup.env.ledger().set_timestamp(deadline + 1);
+
+    assert_eq!(
+        setup.escrow.try_top_up(&setup.depositor, &3, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup.escrow.try_execute_release(&1),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup.escrow.try_resolve_release(&1, &true),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup.escrow.try_partial_refund(&3, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup.escrow.try_execute_approved_refund(&3),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup.escrow.try_process_expirations(),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    // Refunds stay open under an outflow-only pause, top-ups under neither
+    setup.escrow.pause_with_mode(&PauseMode::OutflowsOnly);
+    assert_eq!(
+        setup.escrow.try_execute_release(&1),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(setup.escrow.process_expirations(), 1);
+    setup.escrow.partial_refund(&3, &100);
+    setup.escrow.pause_with_mode(&PauseMode::InflowsOnly);
+    assert_eq!(
+        setup.escrow.try_top_up(&setup.depositor, &3, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    setup.escrow.execute_release(&1);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_emergency_withdraw_requires_quorum_approvals() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let recipient = Address::generate(&setup.env);
+    let signer1 = Address::generate(&setup.env);
+    let signer2 = Address::generate(&setup.env);
+    let signer3 = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.set_emergency_quorum(
+        &vec![
+            &setup.env,
+            signer1.clone(),
+            signer2.clone(),
+            signer3.clone(),
+        ],
+        &2,
+    );
+    setup.escrow.pause();
+
+    // One approval is not enough
+    assert_eq!(
+        setup
+            .escrow
+            .approve_emergency_withdraw(&signer1, &recipient),
+        1
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_emergency_withdraw(&setup.admin, &setup.token.address, &recipient),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+
+    // Second approval reaches the threshold
+    assert_eq!(
+        setup
+            .escrow
+            .approve_emergency_withdraw(&signer2, &recipient),
+        2
+    );
+    let withdrawn = setup
+        .escrow
+        .emergency_withdraw(&setup.admin, &setup.token.address, &recipient);
+    assert_eq!(withdrawn, 1000);
+    assert_eq!(setup.token.balance(&recipient), 1000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")] // Unauthorized
+fn test_emergency_withdraw_approval_from_non_signer() {
+    let setup = TestSetup::new();
+    let recipient = Address::generate(&setup.env);
+    let signer1 = Address::generate(&setup.env);
+    let outsider = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .set_emergency_quorum(&vec![&setup.env, signer1.clone()], &1);
+    setup
+        .escrow
+        .approve_emergency_withdraw(&outsider, &recipient);
+}
+
+#[test]
+fn test_quorum_change_requires_current_signer_approvals() {
+    let setup = TestSetup::new();
+    let signer1 = Address::generate(&setup.env);
+    let signer2 = Address::generate(&setup.env);
+    let outsider = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .set_emergency_quorum(&vec![&setup.env, signer1.clone(), signer2.clone()], &2);
+
+    // The admin alone can no longer replace the quorum
+    let takeover = vec![&setup.env, setup.admin.clone()];
+    assert_eq!(
+        setup.escrow.try_set_emergency_quorum(&takeover, &1),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_approve_quorum_change(&outsider, &takeover, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Approvals count only for the exact configuration they were given to
+    let replacement = vec![&setup.env, signer1.clone(), outsider.clone()];
+    assert_eq!(
+        setup
+            .escrow
+            .approve_quorum_change(&signer1, &replacement, &2),
+        1
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .approve_quorum_change(&signer2, &replacement, &1),
+        1
+    );
+    assert_eq!(
+        setup.escrow.try_set_emergency_quorum(&replacement, &2),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    assert_eq!(
+        setup
+            .escrow
+            .approve_quorum_change(&signer2, &replacement, &2),
+        2
+    );
+    setup.escrow.set_emergency_quorum(&replacement, &2);
+    assert_eq!(
+        setup.escrow.get_emergency_quorum(),
+        Some(EmergencyQuorum {
+            signers: replacement,
+            threshold: 2,
+        })
+    );
+}
+
+#[test]
+fn test_emergency_approvals_reset_by_quorum_change_and_expiry() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let recipient = Address::generate(&setup.env);
+    let signer1 = Address::generate(&setup.env);
+    let signer2 = Address::generate(&setup.env);
+    let signer3 = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .set_emergency_quorum(&vec![&setup.env, signer1.clone(), signer2.clone()], &2);
+    setup.escrow.pause();
+    setup
+        .escrow
+        .approve_emergency_withdraw(&signer1, &recipient);
+    setup
+        .escrow
+        .approve_emergency_withdraw(&signer2, &recipient);
+
+    // Replacing the quorum voids the approvals given to the old one
+    let new_signers = vec![&setup.env, signer1.clone(), signer3.clone()];
+    setup
+        .escrow
+        .approve_quorum_change(&signer1, &new_signers, &2);
+    setup
+        .escrow
+        .approve_quorum_change(&signer2, &new_signers, &2);
+    setup.escrow.set_emergency_quorum(&new_signers, &2);
+    assert_eq!(
+        setup
+            .escrow
+            .try_emergency_withdraw(&signer1, &setup.token.address, &recipient),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    // Approvals lapse after a day
+    assert_eq!(
+        setup
+            .escrow
+            .approve_emergency_withdraw(&signer1, &recipient),
+        1
+    );
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 24 * 60 * 60 + 1);
+    assert_eq!(
+        setup
+            .escrow
+            .approve_emergency_withdraw(&signer3, &recipient),
+        1
+    );
+
+    // Only the admin or a signer may execute
+    setup
+        .escrow
+        .approve_emergency_withdraw(&signer1, &recipient);
+    assert_eq!(
+        setup.escrow.try_emergency_withdraw(
+            &Address::generate(&setup.env),
+            &setup.token.address,
+            &recipient
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .emergency_withdraw(&signer3, &setup.token.address, &recipient),
+        1000
+    );
+}
+
+// ============================================================================
+// REFUND APPROVAL EXPIRY TESTS
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")] // RefundApprovalExpired
+fn test_refund_with_expired_approval_rejected() {
+    let setup = TestSetup::new();
+    let custom_recipient = Address::generate(&setup.env);
+    let current_time = setup.env.ledger().timestamp();
+    let deadline = current_time + REFUND_APPROVAL_EXPIRY * 4;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .approve_refund(&1, &500, &custom_recipient.clone(), &RefundMode::Custom);
+
+    // Move past the approval window, still before the deadline
+    setup
+        .env
+        .ledger()
+        .set_timestamp(current_time + REFUND_APPROVAL_EXPIRY + 1);
+
+    let (can_refund, deadline_passed, _, _) = setup.escrow.get_refund_eligibility(&1);
+    assert!(!can_refund);
+    assert!(!deadline_passed);
+
+    setup.escrow.refund(
+        &1,
+        &Some(500),
+        &Some(custom_recipient.clone()),
+        &RefundMode::Custom,
+    );
+}
+
+#[test]
+fn test_refund_approval_expiry_is_configurable() {
+    let setup = TestSetup::new();
+    let custom_recipient = Address::generate(&setup.env);
+    let current_time = setup.env.ledger().timestamp();
+    let deadline = current_time + REFUND_APPROVAL_EXPIRY;
+
+    assert_eq!(
+        setup.escrow.get_refund_approval_expiry(),
+        REFUND_APPROVAL_EXPIRY
+    );
+    assert_eq!(
+        setup.escrow.try_set_refund_approval_expiry(&0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    setup.escrow.set_refund_approval_expiry(&3600);
+    assert_eq!(setup.escrow.get_refund_approval_expiry(), 3600);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .approve_refund(&1, &500, &custom_recipient.clone(), &RefundMode::Custom);
+
+    setup.env.ledger().set_timestamp(current_time + 3601);
+    assert_eq!(
+        setup.escrow.try_refund(
+            &1,
+            &Some(500),
+            &Some(custom_recipient.clone()),
+            &RefundMode::Custom,
+        ),
+        Err(Ok(Error::RefundApprovalExpired))
+    );
+}
+
+// ============================================================================
+// TOKEN VALIDATION TESTS
+// ============================================================================
+
+#[test]
+fn test_init_rejects_non_token_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let not_a_token = Address::generate(&env);
+    let (escrow, _) = create_escrow_contract(&env);
+
+    assert_eq!(
+        escrow.try_init(&admin, &not_a_token),
+        Err(Ok(Error::InvalidToken))
+    );
+
+    // Contract stays uninitialized and can still be set up with a real token
+    let (token, _) = create_token_contract(&env, &admin);
+    escrow.init(&admin, &token.address);
+}
+
+// ============================================================================
+// PER-BOUNTY RELEASE LIMIT TESTS
+// ============================================================================
+
+#[test]
+fn test_release_up_to_bounty_limit() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.set_release_limit(&1, &1000, &86_400);
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_release_beyond_bounty_limit_blocked() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1500, &deadline, &setup.token.address);
+    setup.escrow.set_release_limit(&1, &1000, &86_400);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&1, &setup.contributor, &None),
+        Err(Ok(Error::ReleaseLimitExceeded))
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+
+    // Raising the limit unblocks the release
+    setup.escrow.set_release_limit(&1, &1500, &86_400);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 1500);
+}
+
+#[test]
+fn test_release_limit_resets_after_window() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 200_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &2000, &deadline, &setup.token.address);
+    setup.escrow.set_release_limit(&1, &1000, &86_400);
+
+    assert_eq!(
+        setup.escrow.release_percent(&1, &setup.contributor, &5000),
+        1000
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_percent(&1, &setup.contributor, &5000),
+        Err(Ok(Error::ReleaseLimitExceeded))
+    );
+
+    // Once the window has elapsed the allowance starts over
+    setup.env.ledger().set_timestamp(86_400);
+    assert_eq!(
+        setup
+            .escrow
+            .release_percent(&1, &setup.contributor, &10_000),
+        1000
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 2000);
+}
+
+#[test]
+fn test_split_bounty_keeps_release_limit() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.set_release_limit(&1, &300, &86_400);
+
+    setup.escrow.split_bounty(&setup.depositor, &1, &2, &600);
+    assert_eq!(
+        setup.escrow.get_release_limit(&2),
+        Some(ReleaseLimit {
+            max_amount: 300,
+            window: 86_400,
+        })
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&2, &setup.contributor, &None),
+        Err(Ok(Error::ReleaseLimitExceeded))
+    );
+}
+
+#[test]
+fn test_merge_bounties_keeps_stricter_release_limit() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 200_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &2000, &deadline, &setup.token.address);
+    setup.env.ledger().set_timestamp(120);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &setup.token.address);
+    setup.escrow.set_release_limit(&1, &1000, &86_400);
+    setup.escrow.set_release_limit(&2, &1500, &43_200);
+
+    setup.escrow.merge_bounties(&setup.depositor, &1, &2);
+    assert_eq!(
+        setup.escrow.get_release_limit(&1),
+        Some(ReleaseLimit {
+            max_amount: 1000,
+            window: 86_400,
+        })
+    );
+    assert_eq!(setup.escrow.get_release_limit(&2), None);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_percent(&1, &setup.contributor, &4000),
+        Err(Ok(Error::ReleaseLimitExceeded))
+    );
+    assert_eq!(
+        setup.escrow.release_percent(&1, &setup.contributor, &2500),
+        1000
+    );
+}
+
+// ============================================================================
+// AUTHORIZATION QUERY TESTS
+// ============================================================================
+
+#[test]
+fn test_can_release_only_for_admin() {
+    let setup = TestSetup::new();
+
+    assert!(setup.escrow.can_release(&setup.admin));
+    assert!(!setup.escrow.can_release(&setup.depositor));
+    assert!(!setup.escrow.can_release(&setup.contributor));
+}
+
+#[test]
+fn test_can_refund_depositor_after_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    // Before the deadline nobody can refund
+    assert!(!setup.escrow.can_refund(&setup.depositor, &1));
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    assert!(setup.escrow.can_refund(&setup.depositor, &1));
+    assert!(!setup.escrow.can_refund(&setup.contributor, &1));
+    assert!(!setup.escrow.can_refund(&setup.admin, &1));
+
+    // Unknown bounties are never refundable
+    assert!(!setup.escrow.can_refund(&setup.depositor, &99));
+}
+
+// ============================================================================
+// EXPIRATION PROCESSING TESTS
+// ============================================================================
+
+#[test]
+fn test_process_expirations_refunds_only_flagged_escrows() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+
+    // Expired and flagged
+    setup.escrow.lock_funds_with_auto_refund(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(now + 100),
+        &setup.token.address,
+        &true,
+    );
+    setup.escrow.lock_funds_with_auto_refund(
+        &setup.depositor,
+        &2,
+        &2000,
+        &(now + 100),
+        &setup.token.address,
+        &true,
+    );
+    // Expired but not flagged
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &3,
+        &3000,
+        &(now + 100),
+        &setup.token.address,
+    );
+    // Flagged but not yet expired
+    setup.escrow.lock_funds_with_auto_refund(
+        &setup.depositor,
+        &4,
+        &4000,
+        &(now + 10_000),
+        &setup.token.address,
+        &true,
+    );
+
+    setup.env.ledger().set_timestamp(now + 200);
+
+    assert_eq!(
+        setup.escrow.get_expired_bounties(),
+        vec![&setup.env, 1u64, 2u64, 3u64]
+    );
+
+    let refunded = setup.escrow.process_expirations();
+    assert_eq!(refunded, 2);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&3).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&4).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(setup.escrow.get_refund_history(&1).len(), 1);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        1_000_000 - 3000 - 4000
+    );
+
+    // Already refunded escrows are not processed twice
+    assert_eq!(setup.escrow.process_expirations(), 0);
+}
+
+#[test]
+fn test_sweep_expired_refunds_only_expired_escrows() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let token = setup.token.address.clone();
+
+    // Expired, untouched
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &(now + 100), &token);
+    // Not yet expired
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2000, &(now + 10_000), &token);
+    // Expired after a partial release
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &4000, &(now + 100), &token);
+    setup.escrow.release_percent(&3, &setup.contributor, &2500);
+
+    setup.env.ledger().set_timestamp(now + 200);
+
+    let page = Pagination {
+        start_index: 0,
+        limit: 10,
+    };
+    assert_eq!(setup.escrow.sweep_expired(&page), 2);
+
+    let first = setup.escrow.get_escrow_info(&1);
+    assert_eq!(first.status, EscrowStatus::Refunded);
+    assert_eq!(first.remaining_amount, 0);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Locked
+    );
+    let third = setup.escrow.get_escrow_info(&3);
+    assert_eq!(third.status, EscrowStatus::Refunded);
+    assert_eq!(third.refund_history.get(0).unwrap().amount, 3000);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        1_000_000 - 2000 - 1000
+    );
+
+    // A second sweep finds nothing left to refund
+    assert_eq!(setup.escrow.sweep_expired(&page), 0);
+
+    setup.assert_invariants();
+}
+
+#[test]
+fn test_sweep_expired_pages_and_respects_pause() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let token = setup.token.address.clone();
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &(now + 100), &token);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &(now + 100), &token);
+    setup.env.ledger().set_timestamp(now + 200);
+
+    setup.escrow.pause();
+    let page = Pagination {
+        start_index: 0,
+        limit: 10,
+    };
+    assert_eq!(
+        setup.escrow.try_sweep_expired(&page),
+        Err(Ok(Error::ContractPaused))
+    );
+    setup.escrow.unpause();
+
+    let second_only = Pagination {
+        start_index: 1,
+        limit: 1,
+    };
+    assert_eq!(setup.escrow.sweep_expired(&second_only), 1);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_registry_pages_span_many_bounties() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let token = setup.token.address.clone();
+
+    // 105 bounties fill the first registry page and spill onto a second
+    for (first, count) in [(1u64, 100u64), (101, 5)] {
+        let mut items = vec![&setup.env];
+        for bounty_id in first..first + count {
+            items.push_back(LockFundsItem {
+                bounty_id,
+                depositor: setup.depositor.clone(),
+                amount: 10,
+                deadline: now + 100,
+                token: token.clone(),
+            });
+        }
+        setup.escrow.batch_lock_funds(&items);
+    }
+
+    let straddling = Pagination {
+        start_index: 98,
+        limit: 4,
+    };
+    let all = EscrowFilter {
+        status: None,
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        start_time: None,
+        end_time: None,
+    };
+    assert_eq!(
+        setup.escrow.list_escrows(&all, &straddling),
+        vec![&setup.env, 99u64, 100, 101, 102]
+    );
+
+    setup.env.ledger().set_timestamp(now + 200);
+    assert_eq!(setup.escrow.sweep_expired(&straddling), 4);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&100).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&101).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&103).status,
+        EscrowStatus::Locked
+    );
+    setup.assert_invariants();
+}
+
+#[test]
+fn test_reused_bounty_id_counted_once_after_merge() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let token = setup.token.address.clone();
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &token);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline, &token);
+    setup.escrow.merge_bounties(&setup.depositor, &1, &2);
+
+    // The merged-away ID is locked again and must be listed only once
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &300, &deadline, &token);
+
+    let stats = setup.escrow.get_stats(&setup.token.address);
+    assert_eq!(stats.total_locked, 1800);
+
+    let all = EscrowFilter {
+        status: None,
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        start_time: None,
+        end_time: None,
+    };
+    let page = Pagination {
+        start_index: 0,
+        limit: 10,
+    };
+    assert_eq!(
+        setup.escrow.list_escrows(&all, &page),
+        vec![&setup.env, 1u64, 2]
+    );
+    setup.assert_invariants();
+}
+
+// ============================================================================
+// FEE WAIVER TESTS
+// ============================================================================
+
+#[test]
+fn test_first_operations_are_fee_free() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    // 10% lock fee, first two operations per address are free
+    setup
+        .escrow
+        .update_fee_config(&Some(1_000), &None, &None, &Some(true));
+    setup.escrow.set_free_operations(&2);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &1000, &deadline, &setup.token.address);
+
+    assert_eq!(setup.escrow.get_escrow_info(&1).amount, 1000);
+    assert_eq!(setup.escrow.get_escrow_info(&2).amount, 1000);
+    assert_eq!(setup.escrow.get_escrow_info(&3).amount, 900);
+    assert_eq!(setup.token.balance(&setup.admin), 100);
+}
+
+// ============================================================================
+// ERROR CATALOG TESTS
+// ============================================================================
+
+#[test]
+fn test_error_catalog_known_mappings() {
+    let setup = TestSetup::new();
+    let env = &setup.env;
+
+    let catalog = setup.escrow.get_error_catalog();
+    assert!(catalog.contains(&(1u32, Symbol::new(env, "AlreadyInitialized"))));
+    assert!(catalog.contains(&(4u32, Symbol::new(env, "BountyNotFound"))));
+    assert!(catalog.contains(&(17u32, Symbol::new(env, "RefundNotApproved"))));
+
+    assert_eq!(
+        setup.escrow.error_code(&Symbol::new(env, "FundsNotLocked")),
+        Some(Error::FundsNotLocked as u32)
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .error_code(&Symbol::new(env, "InsufficientFunds")),
+        Some(16)
+    );
+    assert_eq!(
+        setup.escrow.error_code(&Symbol::new(env, "NoSuchError")),
+        None
+    );
+}
+
+// ============================================================================
+// TOKEN RESCUE TESTS
+// ============================================================================
+
+#[test]
+fn test_rescue_foreign_token() {
+    let setup = TestSetup::new();
+    let rescuer = Address::generate(&setup.env);
+    let (foreign, foreign_admin) = create_token_contract(&setup.env, &setup.admin);
+
+    // Tokens sent directly to the contract, not via lock_funds
+    foreign_admin.mint(&setup.escrow_address, &750);
+
+    setup.escrow.rescue_tokens(&foreign.address, &rescuer, &750);
+    assert_eq!(foreign.balance(&rescuer), 750);
+    assert_eq!(foreign.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_rescue_escrow_token_limited_to_excess() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let rescuer = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    // Accidental direct transfer on top of the escrowed funds
+    setup.token_admin.mint(&setup.escrow_address, &200);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_rescue_tokens(&setup.token.address, &rescuer, &201),
+        Err(Ok(Error::InsufficientFunds))
+    );
+
+    setup
+        .escrow
+        .rescue_tokens(&setup.token.address, &rescuer, &200);
+    assert_eq!(setup.token.balance(&rescuer), 200);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+}
+
+#[test]
+fn test_rescue_tracks_obligations_across_payouts() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let rescuer = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline, &setup.token.address);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    setup.escrow.partial_refund(&2, &200);
+    setup.token_admin.mint(&setup.escrow_address, &100);
+
+    // Only bounty 2's remaining 300 is still owed
+    assert_eq!(
+        setup
+            .escrow
+            .try_rescue_tokens(&setup.token.address, &rescuer, &101),
+        Err(Ok(Error::InsufficientFunds))
+    );
+    setup
+        .escrow
+        .rescue_tokens(&setup.token.address, &rescuer, &100);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 300);
+}
+
+#[test]
+fn test_rescue_excludes_held_fees() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let rescuer = Address::generate(&setup.env);
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &Some(200), &None, &Some(true));
+    setup.escrow.hold_fees(&true);
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &10_000,
+        &deadline,
+        &setup.token.address,
+    );
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    setup.token_admin.mint(&setup.escrow_address, &50);
+
+    assert_eq!(setup.escrow.get_held_fees(&setup.token.address), 298);
+    assert_eq!(
+        setup
+            .escrow
+            .try_rescue_tokens(&setup.token.address, &rescuer, &51),
+        Err(Ok(Error::InsufficientFunds))
+    );
+    setup
+        .escrow
+        .rescue_tokens(&setup.token.address, &rescuer, &50);
+    assert_eq!(setup.escrow.sweep_held_fees(&setup.token.address), 298);
+}
+
+// ============================================================================
+// ESCROW LISTING TESTS
+// ============================================================================
+
+fn empty_filter() -> EscrowFilter {
+    EscrowFilter {
+        status: None,
+        depositor: None,
+        min_amount: None,
+        max_amount: None,
+        start_time: None,
+        end_time: None,
+    }
+}
+
+#[test]
+fn test_list_escrows_expired_filter() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let page = Pagination {
+        start_index: 0,
+        limit: 10,
+    };
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(now + 100),
+        &setup.token.address,
+    );
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &2,
+        &1000,
+        &(now + 100),
+        &setup.token.address,
+    );
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &3,
+        &1000,
+        &(now + 10_000),
+        &setup.token.address,
+    );
+    setup.escrow.release_funds(&2, &setup.contributor, &None);
+
+    let expired = EscrowFilter {
+        status: Some(EXPIRED_CODE),
+        ..empty_filter()
+    };
+    assert_eq!(setup.escrow.list_escrows(&expired, &page).len(), 0);
+
+    setup.env.ledger().set_timestamp(now + 200);
+
+    // Only the still-locked escrow past its deadline is expired
+    assert_eq!(
+        setup.escrow.list_escrows(&expired, &page),
+        vec![&setup.env, 1u64]
+    );
+
+    // The stored status filter still reports it as Locked
+    let locked = EscrowFilter {
+        status: Some(0),
+        ..empty_filter()
+    };
+    assert_eq!(
+        setup.escrow.list_escrows(&locked, &page),
+        vec![&setup.env, 1u64, 3u64]
+    );
+}
+
+#[test]
+fn test_query_escrows_filters_and_paginates() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &10_000);
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &100,
+        &(now + 1000),
+        &setup.token.address,
+    );
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &2,
+        &500,
+        &(now + 2000),
+        &setup.token.address,
+    );
+    setup
+        .escrow
+        .lock_funds(&other, &3, &700, &(now + 3000), &setup.token.address);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &4,
+        &900,
+        &(now + 4000),
+        &setup.token.address,
+    );
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &5,
+        &300,
+        &(now + 5000),
+        &setup.token.address,
+    );
+    setup.escrow.release_funds(&4, &setup.contributor, &None);
+
+    let all = Pagination {
+        start_index: 0,
+        limit: 10,
+    };
+
+    // Locked escrows from the depositor with 200..=800 remaining
+    let filter = EscrowFilter {
+        status: Some(0),
+        depositor: Some(setup.depositor.clone()),
+        min_amount: Some(200),
+        max_amount: Some(800),
+        ..empty_filter()
+    };
+    let result = setup.escrow.query_escrows(&filter, &all);
+    assert_eq!(result.len(), 2);
+    let (id, escrow) = result.get(0).unwrap();
+    assert_eq!(id, 2);
+    assert_eq!(escrow.remaining_amount, 500);
+    assert_eq!(result.get(1).unwrap().0, 5);
+
+    // Released escrow is only matched by its own status
+    let released = EscrowFilter {
+        status: Some(1),
+        ..empty_filter()
+    };
+    let result = setup.escrow.query_escrows(&released, &all);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result.get(0).unwrap().1.status, EscrowStatus::Released);
+
+    // Deadline window
+    let window = EscrowFilter {
+        start_time: Some(now + 2000),
+        end_time: Some(now + 3000),
+        ..empty_filter()
+    };
+    let result = setup.escrow.query_escrows(&window, &all);
+    assert_eq!(result.len(), 2);
+    assert_eq!(result.get(0).unwrap().0, 2);
+    assert_eq!(result.get(1).unwrap().0, 3);
+
+    // Pagination over the unfiltered set
+    let page = Pagination {
+        start_index: 1,
+        limit: 2,
+    };
+    let result = setup.escrow.query_escrows(&empty_filter(), &page);
+    assert_eq!(result.len(), 2);
+    assert_eq!(result.get(0).unwrap().0, 2);
+    assert_eq!(result.get(1).unwrap().0, 3);
+
+    let past_end = Pagination {
+        start_index: 5,
+        limit: 10,
+    };
+    assert_eq!(
+        setup.escrow.query_escrows(&empty_filter(), &past_end).len(),
+        0
+    );
+}
+
+#[test]
+fn test_escrow_queries_cap_limit() {
+    let setup = TestSetup::new();
+    setup.env.budget().reset_unlimited();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let depositor = Address::generate(&setup.env);
+    setup.token_admin.mint(&depositor, &1_000_000);
+
+    let mut items = Vec::new(&setup.env);
+    for bounty_id in 0..(MAX_QUERY_LIMIT as u64 + 5) {
+        items.push_back(LockFundsItem {
+            bounty_id,
+            depositor: depositor.clone(),
+            amount: 10,
+            deadline,
+            token: setup.token.address.clone(),
+        });
+        if items.len() == MAX_BATCH_SIZE {
+            setup.escrow.batch_lock_funds(&items);
+            items = Vec::new(&setup.env);
+        }
+    }
+    setup.escrow.batch_lock_funds(&items);
+
+    let page = Pagination {
+        start_index: 0,
+        limit: 1000,
+    };
+    let result = setup.escrow.query_escrows(&empty_filter(), &page);
+    assert_eq!(result.len(), MAX_QUERY_LIMIT);
+    let ids = setup.escrow.list_escrows(&empty_filter(), &page);
+    assert_eq!(ids.len(), MAX_QUERY_LIMIT);
+}
+
+#[test]
+fn test_get_statuses_returns_codes() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    for bounty_id in 1..=4u64 {
+        setup.escrow.lock_funds(
+            &setup.depositor,
+            &bounty_id,
+            &1000,
+            &deadline,
+            &setup.token.address,
+        );
+    }
+
+    setup.escrow.release_funds(&2, &setup.contributor, &None);
+    setup.env.ledger().set_timestamp(120);
+    setup.escrow.release_percent(&3, &setup.contributor, &5000);
+    setup.env.ledger().set_timestamp(deadline);
+    setup
+        .escrow
+        .refund(&4, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    let ids = vec![&setup.env, 1u64, 2, 3, 4, 99];
+    let statuses = setup.escrow.get_statuses(&ids);
+    assert_eq!(statuses.len(), 4);
+    assert_eq!(statuses.get(0).unwrap(), (1, 0));
+    assert_eq!(statuses.get(1).unwrap(), (2, 1));
+    assert_eq!(statuses.get(2).unwrap(), (3, 4));
+    assert_eq!(statuses.get(3).unwrap(), (4, 2));
+}
+
+#[test]
+fn test_get_actionable_tags_each_state() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let recipient = Address::generate(&setup.env);
+
+    // Expired lock
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(now + 100),
+        &setup.token.address,
+    );
+    // Approved early refund awaiting execution
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &2,
+        &1000,
+        &(now + 10_000),
+        &setup.token.address,
+    );
+    setup
+        .escrow
+        .approve_refund(&2, &500, &recipient, &RefundMode::Custom);
+    // Nothing to do
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &3,
+        &1000,
+        &(now + 10_000),
+        &setup.token.address,
+    );
+    // Already released
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &4,
+        &1000,
+        &(now + 100),
+        &setup.token.address,
+    );
+    setup.escrow.release_funds(&4, &setup.contributor, &None);
+
+    let actionable = setup.escrow.get_actionable(&(now + 200), &10);
+    assert_eq!(
+        actionable,
+        vec![
+            &setup.env,
+            (1u64, symbol_short!("refund")),
+            (2u64, symbol_short!("exec_ref")),
+        ]
+    );
+
+    assert_eq!(setup.escrow.get_actionable(&(now + 200), &1).len(), 1);
+}
+
+// ============================================================================
+// REENTRANCY GUARD TESTS
+// ============================================================================
+
+fn simulate_operation_in_progress(setup: &TestSetup, operation: Symbol) {
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &operation);
+    });
+}
+
+#[test]
+fn test_read_only_reentry_allowed_during_operation() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    assert_eq!(setup.escrow.get_active_operation(), None);
+
+    simulate_operation_in_progress(&setup, symbol_short!("release"));
+
+    // Views remain callable while a mutating operation is in flight
+    assert_eq!(
+        setup.escrow.get_active_operation(),
+        Some(symbol_short!("release"))
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&1).amount, 1000);
+    assert!(setup.escrow.can_release(&setup.admin));
+}
+
+#[test]
+#[should_panic(expected = "Reentrancy detected")]
+fn test_mutating_reentry_blocked_during_operation() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    simulate_operation_in_progress(&setup, symbol_short!("release"));
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+}
+
+#[test]
+#[should_panic(expected = "Reentrancy detected")]
+fn test_refund_blocked_during_operation() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    simulate_operation_in_progress(&setup, symbol_short!("release"));
+
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+}
+
+#[test]
+fn test_batch_and_sweep_operations_blocked_during_operation() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.token_admin.mint(&setup.escrow_address, &100);
+    simulate_operation_in_progress(&setup, symbol_short!("release"));
+
+    let releases = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: setup.contributor.clone(),
+        },
+    ];
+    assert!(setup
+        .escrow
+        .try_batch_release_funds(&releases, &true)
+        .is_err());
+    assert!(setup
+        .escrow
+        .try_split_bounty(&setup.depositor, &1, &2, &400)
+        .is_err());
+    assert!(setup
+        .escrow
+        .try_rescue_tokens(&setup.token.address, &setup.admin, &50)
+        .is_err());
+    setup.env.ledger().set_timestamp(deadline + 1);
+    assert!(setup
+        .escrow
+        .try_sweep_expired(&Pagination {
+            start_index: 0,
+            limit: 10
+        })
+        .is_err());
+
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1100);
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 1000);
+}
+
+// ============================================================================
+// VOLUME STATISTICS TESTS
+// ============================================================================
+
+#[test]
+fn test_get_stats_tracks_locked_and_released() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2500, &deadline, &setup.token.address);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+
+    let (other_token, other_token_admin) = create_token_contract(&setup.env, &setup.admin);
+    other_token_admin.mint(&setup.depositor, &700);
+    setup.env.ledger().set_timestamp(120);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &700, &deadline, &other_token.address);
+
+    let stats = setup.escrow.get_stats(&setup.token.address);
+    assert_eq!(stats.total_locked, 3500);
+    assert_eq!(stats.total_released, 1000);
+
+    let other_stats = setup.escrow.get_stats(&other_token.address);
+    assert_eq!(other_stats.total_locked, 700);
+    assert_eq!(other_stats.total_released, 0);
+}
+
+// ============================================================================
+// PERCENTAGE RELEASE TESTS
+// ============================================================================
+
+#[test]
+fn test_release_percent_quarter() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let released = setup.escrow.release_percent(&1, &setup.contributor, &2500);
+    assert_eq!(released, 250);
+    assert_eq!(setup.token.balance(&setup.contributor), 250);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 750);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyReleased);
+}
+
+#[test]
+fn test_release_percent_full() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    assert_eq!(
+        setup
+            .escrow
+            .release_percent(&1, &setup.contributor, &10_000),
+        1000
+    );
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_release_percent_cumulative_milestones_zero_remainder() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1001, &deadline, &setup.token.address);
+
+    // 1/3 of the remainder each time rounds down; the final 100% sweeps the dust
+    let first = setup.escrow.release_percent(&1, &setup.contributor, &3333);
+    let second = setup.escrow.release_percent(&1, &setup.contributor, &5000);
+    let last = setup
+        .escrow
+        .release_percent(&1, &setup.contributor, &10_000);
+
+    assert_eq!(first, 333);
+    assert_eq!(second, 334);
+    assert_eq!(first + second + last, 1001);
+    assert_eq!(setup.token.balance(&setup.contributor), 1001);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+
+    let res = setup
+        .escrow
+        .try_release_percent(&1, &setup.contributor, &10_000);
+    assert_eq!(res, Err(Ok(Error::FundsNotLocked)));
+}
+
+#[test]
+fn test_release_percent_with_lock_fee_pays_only_net_amount() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .update_fee_config(&Some(1_000), &None, &None, &Some(true));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 900);
+    assert_eq!(escrow.remaining_amount, 900);
+
+    assert_eq!(
+        setup
+            .escrow
+            .release_percent(&1, &setup.contributor, &10_000),
+        900
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 900);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+    setup.assert_invariants();
+}
+
+#[test]
+fn test_release_percent_rejects_over_100_percent() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let res = setup
+        .escrow
+        .try_release_percent(&1, &setup.contributor, &10_001);
+    assert_eq!(res, Err(Ok(Error::InvalidPercentage)));
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+// ============================================================================
+// ADMIN PARTIAL REFUND TESTS
+// ============================================================================
+
+#[test]
+fn test_partial_refund_before_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    let depositor_before = setup.token.balance(&setup.depositor);
+
+    setup.escrow.partial_refund(&1, &300);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 700);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        depositor_before + 300
+    );
+
+    let history = setup.escrow.get_refund_history(&1);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().mode, RefundMode::Partial);
+}
+
+#[test]
+fn test_partial_refund_invalid_amounts() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    assert_eq!(
+        setup.escrow.try_partial_refund(&1, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        setup.escrow.try_partial_refund(&1, &1001),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_release_and_partial_refund_interleave() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    // Locked -> PartiallyReleased
+    setup.escrow.release_percent(&1, &setup.contributor, &5000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::PartiallyReleased
+    );
+
+    // PartiallyReleased -> PartiallyRefunded
+    setup.escrow.partial_refund(&1, &200);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(escrow.remaining_amount, 300);
+
+    // PartiallyRefunded -> PartiallyReleased
+    setup.escrow.release_percent(&1, &setup.contributor, &5000);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow.remaining_amount, 150);
+
+    // Final refund drains the escrow
+    setup.escrow.partial_refund(&1, &150);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 650);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+
+    let stats = setup.escrow.get_stats(&setup.token.address);
+    assert_eq!(stats.total_locked, 1000);
+    assert_eq!(stats.total_released, 650);
+}
+
+// ============================================================================
+// APPROVED REFUND EXECUTION TESTS
+// ============================================================================
+
+#[test]
+fn test_execute_approved_refund_before_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    let depositor_before = setup.token.balance(&setup.depositor);
+
+    setup
+        .escrow
+        .approve_refund(&1, &1000, &setup.depositor, &RefundMode::Full);
+    setup.escrow.execute_approved_refund(&1);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        depositor_before + 1000
+    );
+
+    // The approval is single-use
+    let (_, _, _, approval) = setup.escrow.get_refund_eligibility(&1);
+    assert!(approval.is_none());
+}
+
+#[test]
+fn test_execute_approved_refund_without_approval() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let res = setup.escrow.try_execute_approved_refund(&1);
+    assert_eq!(res, Err(Ok(Error::RefundNotApproved)));
+}
+
+#[test]
+fn test_execute_approved_refund_mode_mismatch() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    // Partial refunds may only go back to the depositor
+    let other = Address::generate(&setup.env);
+    setup
+        .escrow
+        .approve_refund(&1, &400, &other, &RefundMode::Partial);
+    let res = setup.escrow.try_execute_approved_refund(&1);
+    assert_eq!(res, Err(Ok(Error::RefundNotApproved)));
+
+    // A Full approval must cover the whole remaining amount
+    setup
+        .escrow
+        .approve_refund(&1, &400, &setup.depositor, &RefundMode::Full);
+    let res = setup.escrow.try_execute_approved_refund(&1);
+    assert_eq!(res, Err(Ok(Error::RefundNotApproved)));
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+// ============================================================================
+// STATUS REPAIR TESTS
+// ============================================================================
+
+#[test]
+fn test_repair_status_fixes_stale_record() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.partial_refund(&1, &1000);
+
+    // Simulate a record written by an older version that never updated the status
+    setup.env.as_contract(&setup.escrow_address, || {
+        let mut escrow: Escrow = setup
+            .env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(1))
+            .unwrap();
+        escrow.status = EscrowStatus::Locked;
+        setup
+            .env
+            .storage()
+            .persistent()
+            .set(&DataKey::Escrow(1), &escrow);
+    });
+    let balance_before = setup.token.balance(&setup.escrow_address);
+
+    assert_eq!(setup.escrow.repair_status(&1), EscrowStatus::Refunded);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(setup.token.balance(&setup.escrow_address), balance_before);
+}
+
+#[test]
+fn test_repair_status_keeps_consistent_record() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.release_percent(&1, &setup.contributor, &2500);
+
+    assert_eq!(
+        setup.escrow.repair_status(&1),
+        EscrowStatus::PartiallyReleased
+    );
+    assert_eq!(
+        setup.escrow.try_repair_status(&99),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+// ============================================================================
+// DEPOSITOR REASSIGNMENT TESTS
+// ============================================================================
+
+#[test]
+fn test_reassign_depositor_redirects_refund() {
+    let setup = TestSetup::new();
+    let new_depositor = Address::generate(&setup.env);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    setup.escrow.reassign_depositor(&1, &new_depositor);
+    assert_eq!(setup.escrow.get_escrow_info(&1).depositor, new_depositor);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    assert_eq!(setup.token.balance(&new_depositor), 1000);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 1000);
+}
+
+#[test]
+fn test_reassign_depositor_rejects_settled_or_missing_bounty() {
+    let setup = TestSetup::new();
+    let new_depositor = Address::generate(&setup.env);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+
+    assert_eq!(
+        setup.escrow.try_reassign_depositor(&1, &new_depositor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        setup.escrow.try_reassign_depositor(&99, &new_depositor),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+// ============================================================================
+// DEADLINE EXTENSION TESTS
+// ============================================================================
+
+#[test]
+fn test_extend_deadline_moves_deadline_forward() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    setup.escrow.extend_deadline(&1, &(deadline + 500));
+    assert_eq!(setup.escrow.get_escrow_info(&1).deadline, deadline + 500);
+
+    // The original deadline no longer unlocks a refund
+    setup.env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        setup
+            .escrow
+            .try_refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+}
+
+#[test]
+fn test_extend_deadline_rejects_earlier_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    assert_eq!(
+        setup.escrow.try_extend_deadline(&1, &deadline),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        setup.escrow.try_extend_deadline(&1, &(deadline - 1)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(
+        setup.escrow.try_extend_deadline(&1, &(deadline + 500)),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_extend_deadline_rejects_non_depositor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let new_deadline = deadline + 500;
+    let stranger = Address::generate(&setup.env);
+    setup.env.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &setup.escrow_address,
+            fn_name: "extend_deadline",
+            args: (1u64, new_deadline).into_val(&setup.env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    setup.escrow.extend_deadline(&1, &new_deadline);
+}
+
+// ============================================================================
+// RELEASE CLIFF TESTS
+// ============================================================================
+
+#[test]
+fn test_release_blocked_before_cliff() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup.escrow.lock_funds_with_cliff(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(now + 10_000),
+        &setup.token.address,
+        &Some(now + 5_000),
+    );
+    assert_eq!(setup.escrow.get_release_cliff(&1), Some(now + 5_000));
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&1, &setup.contributor, &None),
+        Err(Ok(Error::ReleaseLocked))
+    );
+    setup.env.ledger().set_timestamp(now + 4_999);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_percent(&1, &setup.contributor, &5000),
+        Err(Ok(Error::ReleaseLocked))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_release_allowed_after_cliff() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup.escrow.lock_funds_with_cliff(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(now + 10_000),
+        &setup.token.address,
+        &Some(now + 5_000),
+    );
+
+    setup.env.ledger().set_timestamp(now + 5_000);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_lock_funds_with_cliff_rejects_past_cliff() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1_000);
+
+    assert_eq!(
+        setup.escrow.try_lock_funds_with_cliff(
+            &setup.depositor,
+            &1,
+            &1000,
+            &10_000,
+            &setup.token.address,
+            &Some(1_000)
+        ),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    setup.escrow.lock_funds_with_cliff(
+        &setup.depositor,
+        &1,
+        &1000,
+        &10_000,
+        &setup.token.address,
+        &None,
+    );
+    assert_eq!(setup.escrow.get_release_cliff(&1), None);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+}
+
+#[test]
+fn test_split_bounty_keeps_release_cliff() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup.escrow.lock_funds_with_cliff(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(now + 10_000),
+        &setup.token.address,
+        &Some(now + 5_000),
+    );
+
+    setup.escrow.split_bounty(&setup.admin, &1, &2, &1000);
+    assert_eq!(setup.escrow.get_release_cliff(&2), Some(now + 5_000));
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&2, &setup.contributor, &None),
+        Err(Ok(Error::ReleaseLocked))
+    );
+}
+
+#[test]
+fn test_merge_bounties_keeps_later_cliff() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(now + 10_000),
+        &setup.token.address,
+    );
+    setup.escrow.lock_funds_with_cliff(
+        &setup.depositor,
+        &2,
+        &500,
+        &(now + 10_000),
+        &setup.token.address,
+        &Some(now + 5_000),
+    );
+
+    setup.escrow.merge_bounties(&setup.depositor, &1, &2);
+    assert_eq!(setup.escrow.get_release_cliff(&1), Some(now + 5_000));
+    assert_eq!(setup.escrow.get_release_cliff(&2), None);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&1, &setup.contributor, &None),
+        Err(Ok(Error::ReleaseLocked))
+    );
+
+    setup.env.ledger().set_timestamp(now + 5_000);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 1500);
+}
+
+// ============================================================================
+// INTENDED CONTRIBUTOR TESTS
+// ============================================================================
+
+#[test]
+fn test_release_to_intended_contributor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.lock_funds_for_contributor(
+        &setup.depositor,
+        &1,
+        &1000,
+        &deadline,
+        &setup.token.address,
+        &Some(setup.contributor.clone()),
+    );
+    assert_eq!(
+        setup.escrow.get_intended_contributor(&1),
+        Some(setup.contributor.clone())
+    );
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_release_to_other_contributor_rejected() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup.escrow.lock_funds_for_contributor(
+        &setup.depositor,
+        &1,
+        &1000,
+        &deadline,
+        &setup.token.address,
+        &Some(setup.contributor.clone()),
+    );
+
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &other, &None),
+        Err(Ok(Error::ContributorMismatch))
+    );
+    assert_eq!(
+        setup.escrow.try_release_percent(&1, &other, &5000),
+        Err(Ok(Error::ContributorMismatch))
+    );
+    assert_eq!(setup.token.balance(&other), 0);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_split_bounty_keeps_intended_contributor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup.escrow.lock_funds_for_contributor(
+        &setup.depositor,
+        &1,
+        &1000,
+        &deadline,
+        &setup.token.address,
+        &Some(setup.contributor.clone()),
+    );
+
+    setup.escrow.split_bounty(&setup.admin, &1, &2, &400);
+    assert_eq!(
+        setup.escrow.get_intended_contributor(&2),
+        Some(setup.contributor.clone())
+    );
+    assert_eq!(
+        setup.escrow.try_release_funds(&2, &other, &None),
+        Err(Ok(Error::ContributorMismatch))
+    );
+    setup.escrow.release_funds(&2, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 400);
+}
+
+#[test]
+fn test_merge_bounties_requires_matching_intended_contributor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.lock_funds_for_contributor(
+        &setup.depositor,
+        &2,
+        &500,
+        &deadline,
+        &setup.token.address,
+        &Some(setup.contributor.clone()),
+    );
+
+    // Folding a pinned escrow into an unpinned one would drop the pin
+    assert_eq!(
+        setup.escrow.try_merge_bounties(&setup.depositor, &1, &2),
+        Err(Ok(Error::ContributorMismatch))
+    );
+
+    setup.escrow.lock_funds_for_contributor(
+        &setup.depositor,
+        &3,
+        &300,
+        &deadline,
+        &setup.token.address,
+        &Some(setup.contributor.clone()),
+    );
+    setup.escrow.merge_bounties(&setup.depositor, &3, &2);
+    assert_eq!(setup.escrow.get_intended_contributor(&2), None);
+    assert_eq!(
+        setup.escrow.get_intended_contributor(&3),
+        Some(setup.contributor.clone())
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&3).remaining_amount, 800);
+}
+
+// ============================================================================
+// OPTIMISTIC RELEASE TESTS
+// ============================================================================
+
+fn setup_delayed_release(setup: &TestSetup) {
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.set_release_delay(&3600);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+}
+
+#[test]
+fn test_optimistic_release_executes_after_delay() {
+    let setup = TestSetup::new();
+    setup_delayed_release(&setup);
+    let now = setup.env.ledger().timestamp();
+
+    // Only the intent is recorded
+    let pending = setup.escrow.get_pending_release(&1).unwrap();
+    assert_eq!(pending.contributor, setup.contributor);
+    assert_eq!(pending.executable_at, now + 3600);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+
+    assert_eq!(
+        setup.escrow.try_execute_release(&1),
+        Err(Ok(Error::ReleaseNotReady))
+    );
+
+    setup.env.ledger().set_timestamp(now + 3600);
+    setup.escrow.execute_release(&1);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert!(setup.escrow.get_pending_release(&1).is_none());
+}
+
+#[test]
+fn test_optimistic_release_objection_blocks_execution() {
+    let setup = TestSetup::new();
+    setup_delayed_release(&setup);
+    let now = setup.env.ledger().timestamp();
+
+    setup.escrow.object_release(&1);
+    assert!(setup.escrow.get_pending_release(&1).unwrap().objected);
+
+    setup.env.ledger().set_timestamp(now + 3600);
+    assert_eq!(
+        setup.escrow.try_execute_release(&1),
+        Err(Ok(Error::ReleaseObjected))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_optimistic_release_admin_resolution() {
+    let setup = TestSetup::new();
+    setup_delayed_release(&setup);
+    setup.escrow.object_release(&1);
+
+    // Rejecting keeps the funds locked and clears the request
+    setup.escrow.resolve_release(&1, &false);
+    assert!(setup.escrow.get_pending_release(&1).is_none());
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+
+    // A new request can be approved despite an objection
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 120);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    setup.escrow.object_release(&1);
+    setup.escrow.resolve_release(&1, &true);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_immediate_release_paths_rejected_during_release_delay() {
+    let setup = TestSetup::new();
+    setup_delayed_release(&setup);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_percent(&2, &setup.contributor, &5000),
+        Err(Ok(Error::ReleaseDelayActive))
+    );
+    assert_eq!(
+        setup.escrow.try_release_split(
+            &2,
+            &vec![&setup.env, setup.contributor.clone()],
+            &vec![&setup.env, 500i128]
+        ),
+        Err(Ok(Error::ReleaseDelayActive))
+    );
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: setup.contributor.clone(),
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&items, &true),
+        Err(Ok(Error::ReleaseDelayActive))
+    );
+    assert_eq!(
+        setup.escrow.try_process_release_queue(&10),
+        Err(Ok(Error::ReleaseDelayActive))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_refunds_clear_pending_release() {
+    let setup = TestSetup::new();
+    setup_delayed_release(&setup);
+    let deadline = setup.escrow.get_escrow_info(&1).deadline;
+
+    // Admin refund of the whole remainder
+    setup.escrow.partial_refund(&1, &1000);
+    assert!(setup.escrow.get_pending_release(&1).is_none());
+
+    // Depositor refund after the deadline
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 120);
+    setup.escrow.release_funds(&2, &setup.contributor, &None);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&2, &None::<i128>, &None::<Address>, &RefundMode::Full);
+    assert!(setup.escrow.get_pending_release(&2).is_none());
+    assert_eq!(
+        setup.escrow.try_execute_release(&2),
+        Err(Ok(Error::PendingReleaseNotFound))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_emergency_withdraw_voids_pending_release() {
+    let setup = TestSetup::new();
+    setup_delayed_release(&setup);
+    let recipient = Address::generate(&setup.env);
+
+    setup.escrow.pause();
+    setup
+        .escrow
+        .emergency_withdraw(&setup.admin, &setup.token.address, &recipient);
+    setup.escrow.unpause();
+    assert!(setup.escrow.get_pending_release(&1).is_none());
+
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 3600);
+    assert_eq!(
+        setup.escrow.try_execute_release(&1),
+        Err(Ok(Error::PendingReleaseNotFound))
+    );
+
+    // A release requested after the drain is tracked normally
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert!(setup.escrow.get_pending_release(&1).is_some());
+}
+
+// ============================================================================
+// FEE EVENT TESTS
+// ============================================================================
+
+fn fee_events(setup: &TestSetup) -> Vec<events::FeeCollected> {
+    let topics: Vec<Val> = (symbol_short!("fee"),).into_val(&setup.env);
+    let mut found = Vec::new(&setup.env);
+    for (contract, event_topics, data) in setup.env.events().all().iter() {
+        if contract == setup.escrow_address && event_topics == topics {
+            found.push_back(events::FeeCollected::try_from_val(&setup.env, &data).unwrap());
+        }
+    }
+    found
+}
+
+#[test]
+fn test_fee_collected_emitted_for_lock_and_release() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &Some(200), &None, &Some(true));
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &10_000,
+        &deadline,
+        &setup.token.address,
+    );
+    let events = fee_events(&setup);
+    assert_eq!(events.len(), 1);
+    let event = events.get(0).unwrap();
+    assert_eq!(event.operation_type, events::FeeOperationType::Lock);
+    assert_eq!(event.amount, 100);
+    assert_eq!(event.fee_rate, 100);
+    assert_eq!(event.recipient, setup.admin);
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    // The event log accumulates, so the lock fee event is still first
+    let events = fee_events(&setup);
+    assert_eq!(events.len(), 2);
+    let event = events.get(1).unwrap();
+    assert_eq!(event.operation_type, events::FeeOperationType::Release);
+    // 2% of the 9_900 net escrowed amount
+    assert_eq!(event.amount, 198);
+    assert_eq!(event.fee_rate, 200);
+    assert_eq!(setup.token.balance(&setup.contributor), 9_900 - 198);
+}
+
+#[test]
+fn test_fee_collected_absent_when_fees_disabled() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &Some(200), &None, &Some(false));
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &10_000,
+        &deadline,
+        &setup.token.address,
+    );
+    assert_eq!(fee_events(&setup).len(), 0);
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(fee_events(&setup).len(), 0);
+    assert_eq!(setup.token.balance(&setup.contributor), 10_000);
+}
+
+#[test]
+fn test_held_fees_accumulate_and_sweep_to_new_recipient() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &Some(200), &None, &Some(true));
+    setup.escrow.hold_fees(&true);
+    assert!(setup.escrow.is_holding_fees());
+
+    let admin_balance = setup.token.balance(&setup.admin);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &10_000,
+        &deadline,
+        &setup.token.address,
+    );
+    let events = fee_events(&setup);
+    assert_eq!(events.get(0).unwrap().recipient, setup.escrow_address);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+
+    // 100 lock fee + 2% of the 9_900 net amount, none of it paid out yet
+    assert_eq!(setup.escrow.get_held_fees(&setup.token.address), 298);
+    assert_eq!(setup.token.balance(&setup.admin), admin_balance);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 298);
+
+    let new_recipient = Address::generate(&setup.env);
+    setup
+        .escrow
+        .update_fee_config(&None, &None, &Some(new_recipient.clone()), &None);
+    setup.escrow.hold_fees(&false);
+
+    assert_eq!(setup.escrow.sweep_held_fees(&setup.token.address), 298);
+    assert_eq!(setup.token.balance(&new_recipient), 298);
+    assert_eq!(setup.escrow.get_held_fees(&setup.token.address), 0);
+    assert_eq!(setup.escrow.sweep_held_fees(&setup.token.address), 0);
+
+    // Once released, new fees go straight to the recipient again
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &2,
+        &10_000,
+        &deadline,
+        &setup.token.address,
+    );
+    assert_eq!(setup.token.balance(&new_recipient), 398);
+    assert_eq!(setup.escrow.get_held_fees(&setup.token.address), 0);
+}
+
+#[test]
+fn test_fee_config_updated_carries_merged_config() {
+    let setup = TestSetup::new();
+    let recipient = Address::generate(&setup.env);
+    setup.escrow.update_fee_config(
+        &Some(150),
+        &Some(250),
+        &Some(recipient.clone()),
+        &Some(false),
+    );
+
+    setup
+        .escrow
+        .update_fee_config(&None, &None, &None, &Some(true));
+
+    let topics: Vec<Val> = (symbol_short!("fee_cfg"),).into_val(&setup.env);
+    let (_, _, data) = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, event_topics, _)| {
+            *contract == setup.escrow_address && *event_topics == topics
+        })
+        .last()
+        .unwrap();
+    let event = events::FeeConfigUpdated::try_from_val(&setup.env, &data).unwrap();
+    assert_eq!(event.lock_fee_rate, 150);
+    assert_eq!(event.release_fee_rate, 250);
+    assert_eq!(event.fee_recipient, recipient);
+    assert!(event.fee_enabled);
+    assert_eq!(event.timestamp, setup.env.ledger().timestamp());
+}
+
+// ============================================================================
+// PERFORMANCE METRIC TESTS
+// ============================================================================
+
+#[test]
+fn test_performance_stats_count_calls() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let before = setup.escrow.get_performance_stats(&symbol_short!("lock"));
+    assert_eq!(before.call_count, 0);
+    assert_eq!(before.last_called, 0);
+
+    setup.env.ledger().set_timestamp(5_000);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(deadline + 5_000),
+        &setup.token.address,
+    );
+    let after_first = setup.escrow.get_performance_stats(&symbol_short!("lock"));
+    assert_eq!(after_first.call_count, 1);
+    assert_eq!(after_first.last_called, 5_000);
+
+    setup.env.ledger().set_timestamp(5_120);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &2,
+        &1000,
+        &(deadline + 5_120),
+        &setup.token.address,
+    );
+    let after_second = setup.escrow.get_performance_stats(&symbol_short!("lock"));
+    assert_eq!(after_second.call_count, 2);
+    assert_eq!(after_second.last_called, 5_120);
+}
+
+#[test]
+fn test_funds_released_topic_includes_recipient() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &7, &1000, &deadline, &setup.token.address);
+    setup.escrow.release_funds(&7, &setup.contributor, &None);
+
+    let expected: Vec<Val> =
+        (symbol_short!("f_rel"), 7u64, setup.contributor.clone()).into_val(&setup.env);
+    let found = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .any(|(contract, topics, _)| contract == setup.escrow_address && topics == expected);
+    assert!(found);
+}
+
+// ============================================================================
+// MULTI-TOKEN TESTS
+// ============================================================================
+
+#[test]
+fn test_bounties_in_different_tokens_release_their_own_asset() {
+    let setup = TestSetup::new();
+    let (usdc, usdc_admin) = create_token_contract(&setup.env, &setup.admin);
+    usdc_admin.mint(&setup.depositor, &5_000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+    );
+    setup.env.ledger().set_timestamp(120);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &3_000, &deadline, &usdc.address);
+
+    assert_eq!(setup.escrow.get_escrow_token(&1), setup.token.address);
+    assert_eq!(setup.escrow.get_escrow_token(&2), usdc.address);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1_000);
+    assert_eq!(usdc.balance(&setup.escrow_address), 3_000);
+
+    setup.escrow.release_funds(&2, &setup.contributor, &None);
+    assert_eq!(usdc.balance(&setup.contributor), 3_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+
+    setup.env.ledger().set_timestamp(240);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(usdc.balance(&setup.contributor), 3_000);
+}
+
+#[test]
+fn test_refund_returns_escrow_token() {
+    let setup = TestSetup::new();
+    let (usdc, usdc_admin) = create_token_contract(&setup.env, &setup.admin);
+    usdc_admin.mint(&setup.depositor, &5_000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &2_000, &deadline, &usdc.address);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    assert_eq!(usdc.balance(&setup.depositor), 5_000);
+    assert_eq!(usdc.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_lock_variants_and_balances_use_given_token() {
+    let setup = TestSetup::new();
+    let (usdc, usdc_admin) = create_token_contract(&setup.env, &setup.admin);
+    usdc_admin.mint(&setup.depositor, &5_000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.lock_funds_with_auto_refund(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &usdc.address,
+        &true,
+    );
+    setup.escrow.batch_lock_funds(&vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 2_000,
+            deadline,
+            token: usdc.address.clone(),
+        },
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline,
+            token: setup.token.address.clone(),
+        },
+    ]);
+
+    assert_eq!(setup.escrow.get_escrow_token(&1), usdc.address);
+    assert_eq!(setup.escrow.get_escrow_token(&2), usdc.address);
+    assert_eq!(setup.escrow.get_escrow_token(&3), setup.token.address);
+    assert_eq!(setup.escrow.get_balance(&usdc.address), 3_000);
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), 500);
+
+    setup.escrow.pause();
+    let recipient = Address::generate(&setup.env);
+    assert_eq!(
+        setup
+            .escrow
+            .emergency_withdraw(&setup.admin, &usdc.address, &recipient),
+        3_000
+    );
+    assert_eq!(usdc.balance(&recipient), 3_000);
+    assert_eq!(setup.escrow.get_balance(&setup.token.address), 500);
+}
+
+#[test]
+fn test_merge_rejects_different_tokens() {
+    let setup = TestSetup::new();
+    let (usdc, usdc_admin) = create_token_contract(&setup.env, &setup.admin);
+    usdc_admin.mint(&setup.depositor, &5_000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+    );
+    setup.env.ledger().set_timestamp(120);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline, &usdc.address);
+
+    let res = setup.escrow.try_merge_bounties(&setup.depositor, &1, &2);
+    assert_eq!(res, Err(Ok(Error::TokenMismatch)));
+}
+
+#[test]
+fn test_escrow_stored_without_token_uses_default_token() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+    );
+
+    // Escrows written before the upgrade carry no token
+    setup.env.as_contract(&setup.escrow_address, || {
+        let mut escrow: Escrow = setup
+            .env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(1))
+            .unwrap();
+        escrow.token = None;
+        setup
+            .env
+            .storage()
+            .persistent()
+            .set(&DataKey::Escrow(1), &escrow);
+    });
+
+    assert_eq!(setup.escrow.get_escrow_token(&1), setup.token.address);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+}
+
+#[test]
+fn test_lock_rejects_non_token_address() {
+    let setup = TestSetup::new();
+    let not_a_token = Address::generate(&setup.env);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &1, &1_000, &deadline, &not_a_token),
+        Err(Ok(Error::InvalidToken))
+    );
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&vec![
+            &setup.env,
+            LockFundsItem {
+                bounty_id: 2,
+                depositor: setup.depositor.clone(),
+                amount: 1_000,
+                deadline,
+                token: not_a_token,
+            },
+        ]),
+        Err(Ok(Error::InvalidToken))
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+}
+
+// ============================================================================
+// NEXT ACTION TIME TESTS
+// ============================================================================
+
+#[test]
+fn test_next_action_time_is_deadline_for_locked_escrow() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    assert_eq!(setup.escrow.next_action_time(&1), Some(deadline));
+
+    // Past the deadline nothing is left to wait for
+    setup.env.ledger().set_timestamp(deadline);
+    assert_eq!(setup.escrow.next_action_time(&1), None);
+}
+
+#[test]
+fn test_next_action_time_prefers_earlier_cliff_and_pending_release() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup.escrow.lock_funds_with_cliff(
+        &setup.depositor,
+        &1,
+        &1000,
+        &(now + 10_000),
+        &setup.token.address,
+        &Some(now + 5_000),
+    );
+    assert_eq!(setup.escrow.next_action_time(&1), Some(now + 5_000));
+
+    setup.env.ledger().set_timestamp(now + 5_000);
+    setup.escrow.set_release_delay(&3600);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(setup.escrow.next_action_time(&1), Some(now + 8_600));
+}
+
+#[test]
+fn test_next_action_time_none_for_terminal_escrow() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+
+    assert_eq!(setup.escrow.next_action_time(&1), None);
+    assert_eq!(
+        setup.escrow.try_next_action_time(&99),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+// ============================================================================
+// DEPOSITOR INDEX TESTS
+// ============================================================================
+
+#[test]
+fn test_get_escrows_by_depositor_returns_only_own_bounties() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &10_000);
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &100,
+        &(now + 1000),
+        &setup.token.address,
+    );
+    setup
+        .escrow
+        .lock_funds(&other, &2, &200, &(now + 1000), &setup.token.address);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &3,
+        &300,
+        &(now + 1000),
+        &setup.token.address,
+    );
+
+    let all = Pagination {
+        start_index: 0,
+        limit: 10,
+    };
+    let mine = setup
+        .escrow
+        .get_escrows_by_depositor(&setup.depositor, &all);
+    assert_eq!(mine.len(), 2);
+    assert_eq!(mine.get(0).unwrap().0, 1);
+    assert_eq!(mine.get(1).unwrap().0, 3);
+    assert_eq!(mine.get(1).unwrap().1.amount, 300);
+
+    let theirs = setup.escrow.get_escrows_by_depositor(&other, &all);
+    assert_eq!(theirs.len(), 1);
+    assert_eq!(theirs.get(0).unwrap().0, 2);
+
+    let second_page = Pagination {
+        start_index: 1,
+        limit: 1,
+    };
+    let page = setup
+        .escrow
+        .get_escrows_by_depositor(&setup.depositor, &second_page);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().0, 3);
+}
+
+#[test]
+fn test_depositor_index_follows_batch_lock_and_reassignment() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline,
+            token: setup.token.address.clone(),
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 200,
+            deadline,
+            token: setup.token.address.clone(),
+        },
+    ];
+    setup.escrow.batch_lock_funds(&items);
+
+    setup.escrow.reassign_depositor(&2, &other);
+
+    let all = Pagination {
+        start_index: 0,
+        limit: 10,
+    };
+    let mine = setup
+        .escrow
+        .get_escrows_by_depositor(&setup.depositor, &all);
+    assert_eq!(mine.len(), 1);
+    assert_eq!(mine.get(0).unwrap().0, 1);
+
+    let theirs = setup.escrow.get_escrows_by_depositor(&other, &all);
+    assert_eq!(theirs.len(), 1);
+    assert_eq!(theirs.get(0).unwrap().0, 2);
+}
+
+#[test]
+fn test_unique_depositor_count_ignores_repeat_locks() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &10_000);
+    assert_eq!(setup.escrow.get_unique_depositor_count(), 0);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+    assert_eq!(setup.escrow.get_unique_depositor_count(), 1);
+
+    setup
+        .escrow
+        .lock_funds(&other, &3, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&other, &4, &1000, &deadline, &setup.token.address);
+    assert_eq!(setup.escrow.get_unique_depositor_count(), 2);
+}
+
+// ============================================================================
+// CONTRIBUTOR REPUTATION TESTS
+// ============================================================================
+
+#[test]
+fn test_contributor_completions_count_full_releases() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+    assert_eq!(
+        setup.escrow.get_contributor_completions(&setup.contributor),
+        0
+    );
+
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+    assert_eq!(
+        setup.escrow.get_contributor_completions(&setup.contributor),
+        1
+    );
+
+    setup.env.ledger().set_timestamp(120);
+    setup.escrow.release_funds(&2, &setup.contributor, &None);
+    assert_eq!(
+        setup.escrow.get_contributor_completions(&setup.contributor),
+        2
+    );
+}
+
+#[test]
+fn test_partial_release_does_not_count_as_completion() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    setup.escrow.release_percent(&1, &setup.contributor, &5000);
+    assert_eq!(
+        setup.escrow.get_contributor_completions(&setup.contributor),
+        0
+    );
+
+    setup.env.ledger().set_timestamp(120);
+    setup.escrow.release_percent(&1, &setup.contributor, &10000);
+    assert_eq!(
+        setup.escrow.get_contributor_completions(&setup.contributor),
+        1
+    );
+}
+
+// ============================================================================
+// ANALYTICS TESTS
+// ============================================================================
+
+#[test]
+fn test_analytics_counts_unique_users() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &10_000);
+    let before = setup.escrow.get_analytics();
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&other, &3, &1000, &deadline, &setup.token.address);
+
+    let after = setup.escrow.get_analytics();
+    assert_eq!(after.operation_count - before.operation_count, 3);
+    assert_eq!(after.unique_users - before.unique_users, 2);
+}
+
+// ============================================================================
+// ACTIVE ESCROW CAP TESTS
+// ============================================================================
+
+#[test]
+fn test_active_escrow_cap_blocks_extra_locks() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    assert_eq!(setup.escrow.get_max_active_escrows(), 0);
+    setup.escrow.set_max_active_escrows(&2);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline, &setup.token.address);
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &3, &100, &deadline, &setup.token.address),
+        Err(Ok(Error::TooManyActiveEscrows))
+    );
+
+    // Other depositors have their own allowance
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &1_000);
+    setup
+        .escrow
+        .lock_funds(&other, &4, &100, &deadline, &setup.token.address);
+}
+
+#[test]
+fn test_active_escrow_cap_frees_up_after_release() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.set_max_active_escrows(&1);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline, &setup.token.address);
+    setup.escrow.release_funds(&1, &setup.contributor, &None);
+
+    setup.env.ledger().set_timestamp(120);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline, &setup.token.address);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_active_escrow_cap_applies_within_batch() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.set_max_active_escrows(&1);
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline,
+            token: setup.token.address.clone(),
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline,
+            token: setup.token.address.clone(),
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::TooManyActiveEscrows))
+    );
+}
+
+#[test]
+fn test_active_escrow_cap_applies_to_split_and_reassign() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &1_000);
+    setup.escrow.set_max_active_escrows(&1);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&other, &2, &100, &deadline, &setup.token.address);
+
+    assert_eq!(
+        setup.escrow.try_split_bounty(&setup.depositor, &1, &3, &40),
+        Err(Ok(Error::TooManyActiveEscrows))
+    );
+    assert_eq!(
+        setup.escrow.try_reassign_depositor(&1, &other),
+        Err(Ok(Error::TooManyActiveEscrows))
+    );
+
+    // Reassigning to the current depositor doesn't count the bounty twice
+    setup.escrow.reassign_depositor(&1, &setup.depositor);
+    assert_eq!(setup.escrow.get_escrow_info(&1).depositor, setup.depositor);
+}
+
+// ============================================================================
+// MAX BATCH SIZE TESTS
+// ============================================================================
+
+fn lock_items(setup: &TestSetup, count: u64) -> Vec<LockFundsItem> {
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let mut items = Vec::new(&setup.env);
+    for bounty_id in 1..=count {
+        items.push_back(LockFundsItem {
+            bounty_id,
+            depositor: setup.depositor.clone(),
+            amount: 10,
+            deadline,
+            token: setup.token.address.clone(),
+        });
+    }
+    items
+}
+
+#[test]
+fn test_lowered_max_batch_size_rejects_batch() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.get_max_batch_size(), MAX_BATCH_SIZE);
+
+    setup.escrow.set_max_batch_size(&3);
+    assert_eq!(setup.escrow.get_max_batch_size(), 3);
+
+    // Four items pass the default limit but not the lowered one
+    let res = setup.escrow.try_batch_lock_funds(&lock_items(&setup, 4));
+    assert_eq!(res, Err(Ok(Error::InvalidBatchSize)));
+
+    assert_eq!(setup.escrow.batch_lock_funds(&lock_items(&setup, 3)), 3);
+}
+
+#[test]
+fn test_max_batch_size_must_be_in_range() {
+    let setup = TestSetup::new();
+    assert_eq!(
+        setup.escrow.try_set_max_batch_size(&0),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        setup.escrow.try_set_max_batch_size(&1001),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    setup.escrow.set_max_batch_size(&1000);
+    assert_eq!(setup.escrow.get_max_batch_size(), 1000);
+}
+
+// ============================================================================
+// RELEASE QUEUE TESTS
+// ============================================================================
+
+#[test]
+fn test_release_queue_processes_in_request_order() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let first = Address::generate(&setup.env);
+    let second = Address::generate(&setup.env);
+    let third = Address::generate(&setup.env);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &setup.token.address);
+
+    setup.escrow.request_release(&1, &first, &300);
+    setup.escrow.request_release(&2, &second, &1000);
+    setup.escrow.request_release(&1, &third, &700);
+    assert_eq!(setup.escrow.get_release_queue_length(), 3);
+
+    // Nothing moves until the queue is processed
+    assert_eq!(setup.token.balance(&first), 0);
+
+    assert_eq!(setup.escrow.process_release_queue(&2), 2);
+    assert_eq!(setup.token.balance(&first), 300);
+    assert_eq!(setup.token.balance(&second), 1000);
+    assert_eq!(setup.token.balance(&third), 0);
+    assert_eq!(setup.escrow.get_release_queue_length(), 1);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::PartiallyReleased
+    );
+
+    assert_eq!(setup.escrow.process_release_queue(&10), 1);
+    assert_eq!(setup.token.balance(&third), 700);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.escrow.get_release_queue_length(), 0);
+}
+
+#[test]
+fn test_release_queue_rejects_overcommitted_bounty() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    setup.escrow.request_release(&1, &setup.contributor, &600);
+    assert_eq!(
+        setup
+            .escrow
+            .try_request_release(&1, &setup.contributor, &500),
+        Err(Ok(Error::InsufficientFunds))
+    );
+}
+
+#[test]
+fn test_release_queue_drops_entries_for_refunded_escrows() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.escrow.request_release(&1, &setup.contributor, &400);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    assert_eq!(setup.escrow.process_release_queue(&10), 0);
+    assert_eq!(setup.escrow.get_release_queue_length(), 0);
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+// ============================================================================
+// SPLIT RELEASE TESTS
+// ============================================================================
+
+#[test]
+fn test_release_split_pays_three_coauthors_evenly() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &900, &deadline, &setup.token.address);
+
+    let a = Address::generate(&setup.env);
+    let b = Address::generate(&setup.env);
+    let c = Address::generate(&setup.env);
+    let recipients = vec![&setup.env, a.clone(), b.clone(), c.clone()];
+    let amounts = vec![&setup.env, 300i128, 300, 300];
+    setup.escrow.release_split(&1, &recipients, &amounts);
+
+    assert_eq!(setup.token.balance(&a), 300);
+    assert_eq!(setup.token.balance(&b), 300);
+    assert_eq!(setup.token.balance(&c), 300);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+
+    let history = setup.escrow.get_payout_history(&1);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(1).unwrap().recipient, b);
+    assert_eq!(history.get(1).unwrap().amount, 300);
+
+    for recipient in recipients.iter() {
+        let expected: Vec<Val> = (symbol_short!("f_rel"), 1u64, recipient).into_val(&setup.env);
+        let found = setup
+            .env
+            .events()
+            .all()
+            .iter()
+            .any(|(contract, topics, _)| contract == setup.escrow_address && topics == expected);
+        assert!(found);
+    }
+}
+
+#[test]
+fn test_release_split_partial_leaves_remainder() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+
+    let recipients = vec![&setup.env, setup.contributor.clone()];
+    let amounts = vec![&setup.env, 400i128];
+    setup.escrow.release_split(&1, &recipients, &amounts);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow.remaining_amount, 600);
+}
+
+#[test]
+fn test_release_split_rejects_sum_over_remaining() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &900, &deadline, &setup.token.address);
+
+    let recipients = vec![
+        &setup.env,
+        Address::generate(&setup.env),
+        Address::generate(&setup.env),
+    ];
+    let amounts = vec![&setup.env, 500i128, 500];
+    assert_eq!(
+        setup.escrow.try_release_split(&1, &recipients, &amounts),
+        Err(Ok(Error::InsufficientFunds))
+    );
+
+    let mismatched = vec![&setup.env, 100i128];
+    assert_eq!(
+        setup.escrow.try_release_split(&1, &recipients, &mismatched),
+        Err(Ok(Error::BatchSizeMismatch))
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 900);
+}
+
+// ============================================================================
+// CANCELLATION TESTS
+// ============================================================================
+
+#[test]
+fn test_cancel_with_penalty_splits_between_fee_recipient_and_depositor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let treasury = Address::generate(&setup.env);
+    setup
+        .escrow
+        .update_fee_config(&None, &None, &Some(treasury.clone()), &None);
+    setup.escrow.set_cancel_penalty_rate(&1_000); // 10%
+    assert_eq!(setup.escrow.get_cancel_penalty_rate(), 1_000);
+
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &2_000,
+        &deadline,
+        &setup.token.address,
+    );
+    let depositor_before = setup.token.balance(&setup.depositor);
+
+    let penalty = setup.escrow.cancel_with_penalty(&1);
+    assert_eq!(penalty, 200);
+    assert_eq!(setup.token.balance(&treasury), 200);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        depositor_before + 1_800
+    );
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.get(0).unwrap().amount, 1_800);
+}
+
+#[test]
+fn test_cancel_with_penalty_rejected_after_release_or_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.set_cancel_penalty_rate(&500);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+    );
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &2,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+    );
+
+    setup.escrow.release_percent(&1, &setup.contributor, &5000);
+    assert_eq!(
+        setup.escrow.try_cancel_with_penalty(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+
+    setup.env.ledger().set_timestamp(deadline);
+    assert_eq!(
+        setup.escrow.try_cancel_with_penalty(&2),
+        Err(Ok(Error::InvalidDeadline))
+    );
+}
+
+#[test]
+fn test_cancel_penalty_rate_must_be_valid() {
+    let setup = TestSetup::new();
+    assert_eq!(
+        setup.escrow.try_set_cancel_penalty_rate(&10_001),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+    assert_eq!(
+        setup.escrow.try_set_cancel_penalty_rate(&-1),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+}
+
+#[test]
+fn test_fees_active_matches_fee_config() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.fees_active(), (false, 0, 0));
+
+    setup
+        .escrow
+        .update_fee_config(&Some(150), &Some(250), &None, &Some(true));
+
+    let config = setup.escrow.get_fee_config();
+    assert_eq!(
+        setup.escrow.fees_active(),
+        (
+            config.fee_enabled,
+            config.lock_fee_rate,
+            config.release_fee_rate
+        )
+    );
+    assert_eq!(setup.escrow.fees_active(), (true, 150, 250));
+}
+
+// ============================================================================
+// SUBMISSION GRACE TESTS
+// ============================================================================
+
+#[test]
+fn test_late_submission_extends_deadline_and_delays_refund() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.set_submission_grace(&300);
+    setup.escrow.lock_funds_for_contributor(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+        &Some(setup.contributor.clone()),
+    );
+
+    // Submission 100 seconds before the deadline falls inside the grace window
+    setup.env.ledger().set_timestamp(deadline - 100);
+    let new_deadline = setup.escrow.register_submission(&setup.contributor, &1);
+    assert_eq!(new_deadline, deadline + 300);
+    assert_eq!(setup.escrow.get_escrow_info(&1).deadline, deadline + 300);
+    assert_eq!(setup.escrow.get_submission(&1), Some(deadline - 100));
+
+    // The original deadline no longer unlocks the refund
+    setup.env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        setup
+            .escrow
+            .try_refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    // The extension is one-time
+    assert_eq!(
+        setup.escrow.register_submission(&setup.contributor, &1),
+        deadline + 300
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 300);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_early_submission_keeps_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.set_submission_grace(&300);
+    setup.escrow.lock_funds_for_contributor(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+        &Some(setup.contributor.clone()),
+    );
+
+    assert_eq!(
+        setup.escrow.register_submission(&setup.contributor, &1),
+        deadline
+    );
+    assert_eq!(setup.escrow.get_submission(&1), None);
+
+    // The early submission did not use up the one-time extension
+    setup.env.ledger().set_timestamp(deadline - 100);
+    assert_eq!(
+        setup.escrow.register_submission(&setup.contributor, &1),
+        deadline + 300
+    );
+    assert_eq!(setup.escrow.get_submission(&1), Some(deadline - 100));
+
+    setup.env.ledger().set_timestamp(deadline + 300);
+    assert_eq!(
+        setup.escrow.try_register_submission(&setup.contributor, &1),
+        Err(Ok(Error::InvalidDeadline))
+    );
+}
+
+#[test]
+fn test_submission_restricted_to_pinned_contributor_or_admin() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let stranger = Address::generate(&setup.env);
+    setup.escrow.set_submission_grace(&300);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+    );
+
+    // Nobody but the admin can submit for an unpinned bounty
+    setup.env.ledger().set_timestamp(deadline - 100);
+    assert_eq!(
+        setup.escrow.try_register_submission(&stranger, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        setup.escrow.try_register_submission(&setup.contributor, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&1).deadline, deadline);
+
+    assert_eq!(
+        setup.escrow.register_submission(&setup.admin, &1),
+        deadline + 300
+    );
+}
+
+// ============================================================================
+// RATE LIMIT TESTS
+// ============================================================================
+
+#[test]
+fn test_lock_funds_in_cooldown_returns_typed_error() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1_000);
+    let deadline = 100_000;
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+    );
+
+    setup.env.ledger().set_timestamp(1_030);
+    assert_eq!(
+        setup.escrow.try_lock_funds(
+            &setup.depositor,
+            &2,
+            &1_000,
+            &deadline,
+            &setup.token.address
+        ),
+        Err(Ok(Error::InCooldown))
+    );
+
+    // Once the cooldown has elapsed the depositor can lock again
+    setup.env.ledger().set_timestamp(1_060);
+    setup.escrow.lock_funds(
+        &setup.depositor,
+        &2,
+        &1_000,
+        &deadline,
+        &setup.token.address,
+    );
+}
+
+#[test]
+fn test_lock_funds_over_window_limit_returns_typed_error() {
+    let setup = TestSetup::new();
+    let deadline = 100_000;
+
+    // Default config allows 10 operations per hour, 60 seconds apart
+    for i in 0..10u64 {
+        setup.env.ledger().set_timestamp(1_000 + i * 60);
+        setup.escrow.lock_funds(
+            &setup.depositor,
+            &(i + 1),
+            &100,
+            &deadline,
+            &setup.token.address,
+        );
+    }
+
+    setup.env.ledger().set_timestamp(1_600);
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &11, &100, &deadline, &setup.token.address),
+        Err(Ok(Error::RateLimited))
+    );
+}
+
+// ============================================================================
+// REFUND POLICY TESTS
+// ============================================================================
+
+#[test]
+fn test_depositor_only_policy_blocks_custom_recipient() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    assert_eq!(
+        setup.escrow.get_refund_policy(),
+        RefundPolicy::DepositorOnly
+    );
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup.env.ledger().set_timestamp(deadline + 1);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_refund(&1, &Some(500), &Some(other.clone()), &RefundMode::Custom),
+        Err(Ok(Error::RefundDestinationNotAllowed))
+    );
+
+    // Custom refunds to the depositor themselves are still fine
+    setup.escrow.refund(
+        &1,
+        &Some(500),
+        &Some(setup.depositor.clone()),
+        &RefundMode::Custom,
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 500);
+}
+
+#[test]
+fn test_depositor_or_approved_policy_allows_custom_recipient() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup
+        .escrow
+        .set_refund_policy(&RefundPolicy::DepositorOrApproved);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &setup.token.address);
+    setup
+        .escrow
+        .approve_refund(&1, &400, &other, &RefundMode::Custom);
+    setup.escrow.execute_approved_refund(&1);
+
+    assert_eq!(setup.token.balance(&other), 400);
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 600);
+}
@@ -48,7 +48,7 @@ fn setup_bounty_with_schedule(
 
     // Lock funds for bounty
     token_client.approve(admin, contract_id, &amount, &1000);
-    client.lock_funds(&contributor.clone(), &bounty_id, &amount, &1000000000);
+    client.lock_funds(&contributor.clone(), &bounty_id, &amount, &1000000000, token);
 
     // Create release schedule
     client.create_release_schedule(
@@ -91,7 +91,7 @@ fn test_single_release_schedule() {
     let deadline = env.ledger().timestamp() + 1000000000;
 
     // Lock funds
-    escrow.lock_funds(&admin, &bounty_id, &amount, &deadline);
+    escrow.lock_funds(&admin, &bounty_id, &amount, &deadline, &token_address);
 
     // Create release schedule
     let release_timestamp = 1000;
@@ -150,7 +150,7 @@ fn test_multiple_release_schedules() {
     let deadline = env.ledger().timestamp() + 1000000000;
 
     // Lock funds
-    escrow.lock_funds(&admin, &bounty_id, &total_amount, &deadline);
+    escrow.lock_funds(&admin, &bounty_id, &total_amount, &deadline, &token_address);
 
     // Create first release schedule
     escrow.create_release_schedule(&bounty_id, &amount1, &1000, &contributor1.clone());
@@ -192,11 +192,12 @@ fn test_multiple_release_schedules() {
 
 #[test]
 fn test_init_event() {
-    let (env, client, _contract_id) = create_test_env();
+    let (env, client, contract_id) = create_test_env();
     let _employee = Address::generate(&env);
 
     let admin = Address::generate(&env);
-    let token = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, _token_admin_client) = create_token_contract(&env, &token_admin);
     let _depositor = Address::generate(&env);
     let _bounty_id = 1;
 
@@ -205,11 +206,15 @@ fn test_init_event() {
     // Initialize
     client.init(&admin.clone(), &token.clone());
 
-    // Get all events emitted
+    // Get the escrow's events (the token contract's set_admin is also logged)
     let events = env.events().all();
+    let escrow_events = events
+        .iter()
+        .filter(|(contract, _, _)| *contract == contract_id)
+        .count();
 
     // Verify the event was emitted (1 init event + 2 monitoring events)
-    assert_eq!(events.len(), 3);
+    assert_eq!(escrow_events, 3);
 }
 
 #[test]
@@ -234,7 +239,7 @@ fn test_lock_fund() {
 
     token_admin_client.mint(&depositor, &amount);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &token);
 
     // Get all events emitted
     let events = env.events().all();
@@ -266,9 +271,9 @@ fn test_release_fund() {
 
     token_admin_client.mint(&depositor, &amount);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &token);
 
-    client.release_funds(&bounty_id, &contributor);
+    client.release_funds(&bounty_id, &contributor, &None);
 
     // Get all events emitted
     let events = env.events().all();
@@ -294,7 +299,7 @@ fn test_lock_fund_invalid_amount() {
 
     client.init(&admin.clone(), &token.clone());
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &token);
 }
 
 #[test]
@@ -315,7 +320,7 @@ fn test_lock_fund_invalid_deadline() {
     client.init(&admin.clone(), &token.clone());
     token_admin_client.mint(&depositor, &amount);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &token);
 }
 
 // ============================================================================
@@ -345,18 +350,21 @@ fn test_batch_lock_funds() {
         depositor: depositor.clone(),
         amount: 1000,
         deadline: 100,
+        token: token.clone(),
     });
     items.push_back(crate::LockFundsItem {
         bounty_id: 2,
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 200,
+        token: token.clone(),
     });
     items.push_back(crate::LockFundsItem {
         bounty_id: 3,
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 300,
+        token: token.clone(),
     });
 
     // Execute batch lock
@@ -392,8 +400,8 @@ fn test_batch_release_funds() {
     let amount2 = 2000i128;
     token_admin_client.mint(&depositor, &(amount1 + amount2));
 
-    client.lock_funds(&depositor, &1, &amount1, &100);
-    client.lock_funds(&depositor, &2, &amount2, &200);
+    client.lock_funds(&depositor, &1, &amount1, &100, &token);
+    client.lock_funds(&depositor, &2, &amount2, &200, &token);
 
     // Create batch release items
     let mut items = vec![&env];
@@ -407,7 +415,7 @@ fn test_batch_release_funds() {
     });
 
     // Execute batch release
-    let released_count = client.batch_release_funds(&items);
+    let released_count = client.batch_release_funds(&items, &true);
     assert_eq!(released_count, 2);
 
     // Verify funds were released
@@ -443,12 +451,14 @@ fn test_batch_lock_duplicate_bounty_id() {
         depositor: depositor.clone(),
         amount: 1000,
         deadline: 100,
+        token: token.clone(),
     });
     items.push_back(crate::LockFundsItem {
         bounty_id: 1, // Duplicate!
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 200,
+        token: token.clone(),
     });
 
     client.batch_lock_funds(&items);
@@ -469,7 +479,7 @@ fn test_batch_lock_existing_bounty() {
     token_admin_client.mint(&depositor, &5000);
 
     // Lock a bounty first
-    client.lock_funds(&depositor, &1, &1000, &100);
+    client.lock_funds(&depositor, &1, &1000, &100, &token);
 
     // Try to batch lock the same bounty
     let mut items = vec![&env];
@@ -478,6 +488,7 @@ fn test_batch_lock_existing_bounty() {
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 200,
+        token: token.clone(),
     });
 
     client.batch_lock_funds(&items);
@@ -509,12 +520,14 @@ fn test_batch_lock_event_emission() {
         depositor: depositor.clone(),
         amount: 1000,
         deadline: 100,
+        token: token.clone(),
     });
     items.push_back(crate::LockFundsItem {
         bounty_id: 2,
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 200,
+        token: token.clone(),
     });
 
     client.batch_lock_funds(&items);
@@ -540,8 +553,8 @@ fn test_batch_release_event_emission() {
     token_admin_client.mint(&depositor, &5000);
 
     // Lock funds
-    client.lock_funds(&depositor, &1, &1000, &100);
-    client.lock_funds(&depositor, &2, &2000, &200);
+    client.lock_funds(&depositor, &1, &1000, &100, &token);
+    client.lock_funds(&depositor, &2, &2000, &200, &token);
 
     let initial_event_count = env.events().all().len();
 
@@ -556,7 +569,7 @@ fn test_batch_release_event_emission() {
         contributor: contributor2.clone(),
     });
 
-    client.batch_release_funds(&items);
+    client.batch_release_funds(&items, &true);
 
     // Verify events were emitted
     let events = env.events().all();
@@ -588,7 +601,7 @@ fn test_complete_bounty_workflow_lock_release() {
     // 3. Lock funds
     let bounty_id = 1u64;
     let deadline = 1000u64;
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &token);
 
     // 4. Verify funds locked
     let escrow = client.get_escrow_info(&bounty_id);
@@ -596,11 +609,11 @@ fn test_complete_bounty_workflow_lock_release() {
     assert_eq!(escrow.status, crate::EscrowStatus::Locked);
 
     // 5. Verify contract balance
-    let contract_balance = client.get_balance();
+    let contract_balance = client.get_balance(&token);
     assert_eq!(contract_balance, amount);
 
     // 6. Release funds to contributor
-    client.release_funds(&bounty_id, &contributor);
+    client.release_funds(&bounty_id, &contributor, &None);
 
     // 7. Verify funds released
     let escrow_after = client.get_escrow_info(&bounty_id);
@@ -630,7 +643,7 @@ fn test_complete_bounty_workflow_lock_refund() {
     // Use a future deadline, then advance the ledger timestamp past it
     let current_time = env.ledger().timestamp();
     let deadline = current_time + 1_000;
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &token);
 
     // Advance time past deadline so refund is eligible
     env.ledger().set_timestamp(deadline + 1);
@@ -426,7 +426,7 @@ impl GovernanceContract {
         }
         
         // Execute the upgrade
-        env.deployer().update_current_contract_wasm(proposal.new_wasm_hash);
+        env.deployer().update_current_contract_wasm(proposal.new_wasm_hash.clone());
         
         // Mark as executed
         proposal.status = ProposalStatus::Executed;
@@ -83,7 +83,7 @@
 //! ```rust
 //! // 1. Initialize contract (one-time)
 //! let admin = Address::from_string("GADMIN...");
-//! contract.init(&admin);
+//! contract.initialize(&admin, &1);
 //!
 //! // 2. Develop and test new version locally
 //! // ... make changes to contract code ...
@@ -163,7 +163,8 @@ pub use governance::{
     Error as GovError, Proposal, ProposalStatus, VoteType, VotingScheme, GovernanceConfig, Vote
 };
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec, String,
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env,
+    Symbol, Vec, String,
 };
 
 // ==================== MONITORING MODULE ====================
@@ -370,15 +371,15 @@ pub struct GrainlifyContract;
 /// Storage keys for contract data.
 ///
 /// # Keys
-/// * `Admin` - Stores the administrator address (set once at initialization)
-/// * `Version` - Stores the current contract version number
+/// * `Admin` - Stores the administrator address (changed only via a timelocked `update_admin`)
+/// * `Version` - Stores the current contract version number; its presence marks the contract as initialized
 ///
 /// # Storage Type
 /// Instance storage - Persists across contract upgrades
 ///
 /// # Security Note
 /// These keys use instance storage to ensure data survives WASM upgrades.
-/// The admin address is immutable after initialization.
+/// The admin address only changes through the timelocked `update_admin` flow.
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
@@ -396,6 +397,28 @@ enum DataKey {
     
     /// Previous version before migration (for rollback support)
     PreviousVersion,
+
+    /// Version for which `post_upgrade_init` last ran
+    PostUpgradeInitVersion,
+
+    /// Escrow contracts whose volume counts toward platform totals
+    EscrowContracts,
+
+    /// Admin handover waiting out `ADMIN_UPDATE_DELAY`
+    PendingAdmin,
+}
+
+/// Errors returned by the contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// An initializer already ran
+    AlreadyInitialized = 1,
+    /// No initializer has run yet
+    NotInitialized = 2,
+    /// Caller is neither the admin nor a multisig signer
+    Unauthorized = 3,
 }
 
 // ============================================================================
@@ -415,7 +438,22 @@ enum DataKey {
 ///
 /// # Usage
 /// Set during initialization and can be updated via `set_version()`.
-const VERSION: u32 = 2;
+/// Later versions are reached through `migrate()`, which requires the
+/// target to be greater than the stored version.
+const VERSION: u32 = 1;
+
+/// Seconds a proposed admin change must wait before it can be executed.
+const ADMIN_UPDATE_DELAY: u64 = 2 * 24 * 60 * 60;
+
+/// An admin change proposed through `update_admin`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdminUpdate {
+    /// Address that becomes admin once the update executes
+    pub new_admin: Address,
+    /// Earliest ledger timestamp at which the update can execute
+    pub executable_at: u64,
+}
 
 // ============================================================================
 // Migration System
@@ -435,6 +473,17 @@ pub struct MigrationState {
     pub migration_hash: BytesN<32>,
 }
 
+/// Volume in one token reported by an escrow contract's `get_stats`.
+///
+/// Field names must match the escrow contracts' `EscrowStats` so the
+/// cross-contract result decodes into this type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowStats {
+    pub total_locked: i128,
+    pub total_released: i128,
+}
+
 /// Migration event data
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -451,23 +500,32 @@ pub struct MigrationEvent {
 // Contract Implementation
 // ============================================================================
 
-
+#[contractimpl]
+impl GrainlifyContract {
     // ========================================================================
     // Initialization
     // ========================================================================
 
-    /// Initializes the contract with an admin address.
+    /// Initializes the contract with an admin address and starting version.
+    ///
+    /// Mutually exclusive with `init_multisig`; `init` is shorthand for
+    /// `initialize(admin, VERSION)`.
     ///
     /// # Arguments
     /// * `env` - The contract environment
     /// * `admin` - Address authorized to perform upgrades
+    /// * `initial_version` - Version to record, e.g. when redeploying a
+    ///   contract that already shipped earlier versions
+    ///
+    /// # Errors
+    /// * `AlreadyInitialized` - If the contract was already initialized by any initializer
     ///
     /// # Panics
-    /// * If contract is already initialized
+    /// * If `initial_version` is 0
     ///
     /// # State Changes
     /// - Sets Admin address in instance storage
-    /// - Sets initial Version number
+    /// - Sets Version to `initial_version`
     ///
     /// # Security Considerations
     /// - Can only be called once (prevents admin takeover)
@@ -482,11 +540,11 @@ pub struct MigrationEvent {
     /// let env = Env::default();
     /// let admin = Address::generate(&env);
     ///
-    /// // Initialize contract
-    /// contract.init(&env, &admin);
+    /// // Initialize contract at version 1
+    /// contract.initialize(&admin, &1);
     ///
-    /// // Subsequent init attempts will panic
-    /// // contract.init(&env, &another_admin); // ❌ Panics!
+    /// // Subsequent init attempts fail
+    /// // contract.try_initialize(&another_admin, &1); // ❌ Err(AlreadyInitialized)
     /// ```
     ///
     /// # Gas Cost
@@ -503,25 +561,74 @@ pub struct MigrationEvent {
     /// stellar contract invoke \
     ///   --id CONTRACT_ID \
     ///   --source ADMIN_SECRET_KEY \
-    ///   -- init \
-    ///   --admin GADMIN_ADDRESS
+    ///   -- initialize \
+    ///   --admin GADMIN_ADDRESS \
+    ///   --initial_version 1
     /// ```
- 
-#[contractimpl]
-impl GrainlifyContract {
+    pub fn initialize(env: Env, admin: Address, initial_version: u32) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+
+        // Prevent re-initialization by any initializer
+        if env.storage().instance().has(&DataKey::Version) {
+            monitoring::track_operation(&env, symbol_short!("init"), admin.clone(), false);
+            return Err(Error::AlreadyInitialized);
+        }
+        if initial_version == 0 {
+            monitoring::track_operation(&env, symbol_short!("init"), admin.clone(), false);
+            panic!("Initial version must be positive");
+        }
+
+        // Store admin address (only changeable through the update_admin timelock)
+        env.storage().instance().set(&DataKey::Admin, &admin);
+
+        // Set initial version
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &initial_version);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("init"), admin, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("init"), duration);
+
+        Ok(())
+    }
+
+    /// Initializes the contract with a single admin address at `VERSION`.
+    ///
+    /// Equivalent to `initialize(admin, VERSION)`.
+    ///
+    /// # Errors
+    /// * `AlreadyInitialized` - If the contract was already initialized by any initializer
+    pub fn init(env: Env, admin: Address) -> Result<(), Error> {
+        Self::initialize(env, admin, VERSION)
+    }
+
     /// Initializes the contract with multisig configuration.
     ///
+    /// `init_multisig`, `init` and `initialize` are mutually exclusive:
+    /// whichever runs first initializes the contract and the others return
+    /// `AlreadyInitialized` afterwards. A multisig-initialized contract has no
+    /// single admin.
+    ///
     /// # Arguments
     /// * `env` - The contract environment
     /// * `signers` - List of signer addresses for multisig
     /// * `threshold` - Number of signatures required to execute proposals
-    pub fn init(env: Env, signers: Vec<Address>, threshold: u32) {
+    ///
+    /// # Errors
+    /// * `AlreadyInitialized` - If the contract was already initialized by any initializer
+    pub fn init_multisig(env: Env, signers: Vec<Address>, threshold: u32) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Version) {
-            panic!("Already initialized");
+            return Err(Error::AlreadyInitialized);
         }
 
         MultiSig::init(&env, signers, threshold);
         env.storage().instance().set(&DataKey::Version, &VERSION);
+
+        Ok(())
     }
 
     /// Initialize governance system
@@ -533,36 +640,75 @@ impl GrainlifyContract {
         governance::GovernanceContract::init_governance(&env, admin, config)
     }
 
-    /// Initializes the contract with a single admin address.
+    /// Returns the single admin, or `None` for multisig-initialized contracts.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Proposes handing the admin role to `new_admin` (admin only).
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Address authorized to perform upgrades
-    pub fn init_admin(env: Env, admin: Address) {
-        let start = env.ledger().timestamp();
+    /// The change takes effect only when `execute_admin_update` is called at
+    /// least `ADMIN_UPDATE_DELAY` seconds later. A new proposal replaces any
+    /// pending one.
+    ///
+    /// # Panics
+    /// * If the contract has no single admin
+    /// * If caller is not the admin
+    pub fn update_admin(env: Env, new_admin: Address) -> PendingAdminUpdate {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Admin not set"));
+        admin.require_auth();
 
-        // Prevent re-initialization to protect admin immutability
-        if env.storage().instance().has(&DataKey::Admin) {
-            monitoring::track_operation(&env, symbol_short!("init"), admin.clone(), false);
-            panic!("Already initialized");
-        }
+        let pending = PendingAdminUpdate {
+            new_admin,
+            executable_at: env.ledger().timestamp() + ADMIN_UPDATE_DELAY,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &pending);
 
-        // Store admin address (immutable after this point)
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        monitoring::track_operation(&env, symbol_short!("upd_adm"), admin, true);
 
-        // Set initial version
-        env.storage().instance().set(&DataKey::Version, &VERSION);
+        pending
+    }
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("init"), admin, true);
+    /// Applies a pending admin update once its timelock has expired.
+    ///
+    /// # Panics
+    /// * If no admin update is pending
+    /// * If the timelock has not expired yet
+    pub fn execute_admin_update(env: Env) -> Address {
+        let pending: PendingAdminUpdate = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic!("No pending admin update"));
+        if env.ledger().timestamp() < pending.executable_at {
+            panic!("Admin update timelock not expired");
+        }
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("init"), duration);
-    }
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &pending.new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
 
+        monitoring::track_operation(
+            &env,
+            symbol_short!("exe_adm"),
+            pending.new_admin.clone(),
+            true,
+        );
 
+        pending.new_admin
+    }
 
+    /// Returns the pending admin update, if any.
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdminUpdate> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
 
     /// Proposes an upgrade with a new WASM hash (multisig version).
     ///
@@ -602,7 +748,39 @@ impl GrainlifyContract {
     }
 
 
-    /// Upgrades the contract to new WASM code.
+    /// Returns whether an upgrade proposal has enough approvals to execute.
+    pub fn is_upgrade_executable(env: Env, proposal_id: u64) -> bool {
+        MultiSig::can_execute(&env, proposal_id)
+    }
+
+    /// Executes an upgrade proposal that has met the multisig threshold.
+    ///
+    /// Callable by anyone once `threshold` distinct signers have approved.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `proposal_id` - The ID of the upgrade proposal to execute
+    ///
+    /// # Panics
+    /// * If the proposal has fewer approvals than the threshold
+    /// * If the proposal was already executed
+    pub fn execute_upgrade(env: Env, proposal_id: u64) {
+        if !MultiSig::can_execute(&env, proposal_id) {
+            panic!("Threshold not met");
+        }
+
+        let wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::UpgradeProposal(proposal_id))
+            .expect("Missing upgrade proposal");
+
+        env.deployer().update_current_contract_wasm(wasm_hash);
+
+        MultiSig::mark_executed(&env, proposal_id);
+    }
+
+    /// Upgrades the contract to new WASM code (single admin version).
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -611,6 +789,8 @@ impl GrainlifyContract {
     /// # Authorization
     /// - **CRITICAL**: Only admin can call this function
     /// - Admin must sign the transaction
+    /// - Multisig-initialized contracts have no admin and must upgrade through
+    ///   `propose_upgrade` / `approve_upgrade` / `execute_upgrade`
     ///
     /// # State Changes
     /// - Replaces current contract WASM with new version
@@ -693,33 +873,6 @@ impl GrainlifyContract {
     /// # Panics
     /// * If admin address is not set (contract not initialized)
     /// * If caller is not the admin
-
-    /// Executes an upgrade proposal that has met the multisig threshold.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `proposal_id` - The ID of the upgrade proposal to execute
-    pub fn execute_upgrade(env: Env, proposal_id: u64) {
-        if !MultiSig::can_execute(&env, proposal_id) {
-            panic!("Threshold not met");
-        }
-
-        let wasm_hash: BytesN<32> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UpgradeProposal(proposal_id))
-            .expect("Missing upgrade proposal");
-
-        env.deployer().update_current_contract_wasm(wasm_hash);
-
-        MultiSig::mark_executed(&env, proposal_id);
-    }
-
-    /// Upgrades the contract to new WASM code (single admin version).
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `new_wasm_hash` - Hash of the uploaded WASM code (32 bytes)
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
         let start = env.ledger().timestamp();
 
@@ -985,6 +1138,20 @@ impl GrainlifyContract {
         // Get current version
         let current_version = env.storage().instance().get(&DataKey::Version).unwrap_or(1);
 
+        // Check if migration already completed
+        if env.storage().instance().has(&DataKey::MigrationState) {
+            let migration_state: MigrationState = env
+                .storage()
+                .instance()
+                .get(&DataKey::MigrationState)
+                .unwrap();
+            
+            if migration_state.to_version >= target_version {
+                // Migration already completed, skip
+                return;
+            }
+        }
+
         // Validate target version
         if target_version <= current_version {
             let error_msg = String::from_str(
@@ -1005,20 +1172,6 @@ impl GrainlifyContract {
             panic!("Target version must be greater than current version");
         }
 
-        // Check if migration already completed
-        if env.storage().instance().has(&DataKey::MigrationState) {
-            let migration_state: MigrationState = env
-                .storage()
-                .instance()
-                .get(&DataKey::MigrationState)
-                .unwrap();
-            
-            if migration_state.to_version >= target_version {
-                // Migration already completed, skip
-                return;
-            }
-        }
-
         // Execute version-specific migrations
         let mut from_version = current_version;
         while from_version < target_version {
@@ -1096,6 +1249,137 @@ impl GrainlifyContract {
         }
     }
 
+    /// Initializes storage introduced by the current version after an upgrade.
+    ///
+    /// Runs at most once per version: the version the hook last ran for is
+    /// recorded, and repeated calls for the same version are no-ops. New
+    /// fields are only written when absent, so existing data is never
+    /// overwritten.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin, or any signer of a multisig-initialized contract
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Hook ran for the current version
+    /// * `Ok(false)` - Hook already ran for the current version
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If no initializer has run
+    /// * `Unauthorized` - If `caller` is neither the admin nor a multisig signer
+    ///
+    /// # Authorization
+    /// - `caller` must authorize. Since the hook only fills in missing
+    ///   defaults, a single multisig signer may trigger it.
+    ///
+    /// # Example
+    /// ```rust
+    /// contract.upgrade(&env, &new_wasm_hash);
+    /// contract.set_version(&env, &3);
+    /// contract.post_upgrade_init(&env, &admin);
+    /// ```
+    pub fn post_upgrade_init(env: Env, caller: Address) -> Result<bool, Error> {
+        if !env.storage().instance().has(&DataKey::Version) {
+            return Err(Error::NotInitialized);
+        }
+        caller.require_auth();
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        let authorized = match admin {
+            Some(admin) => caller == admin,
+            None => MultiSig::is_signer(&env, &caller),
+        };
+        if !authorized {
+            monitoring::track_operation(&env, symbol_short!("post_upg"), caller, false);
+            return Err(Error::Unauthorized);
+        }
+
+        let current_version: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
+        let last_initialized: Option<u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::PostUpgradeInitVersion);
+
+        if last_initialized == Some(current_version) {
+            return Ok(false);
+        }
+
+        init_new_storage_fields(&env);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PostUpgradeInitVersion, &current_version);
+
+        monitoring::track_operation(&env, symbol_short!("post_upg"), caller, true);
+
+        Ok(true)
+    }
+
+    // ========================================================================
+    // Platform Volume
+    // ========================================================================
+
+    /// Registers an escrow contract whose volume counts toward platform totals.
+    ///
+    /// # Arguments
+    /// * `escrow` - Address of a bounty or program escrow contract exposing `get_stats`
+    ///
+    /// # Panics
+    /// * If caller is not the admin
+    /// * If the escrow is already registered
+    pub fn register_escrow_contract(env: Env, escrow: Address) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrows: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowContracts)
+            .unwrap_or(vec![&env]);
+        if escrows.contains(&escrow) {
+            panic!("Escrow already registered");
+        }
+        escrows.push_back(escrow);
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowContracts, &escrows);
+
+        monitoring::track_operation(&env, symbol_short!("reg_esc"), admin, true);
+    }
+
+    /// Lists the registered escrow contracts.
+    pub fn get_escrow_contracts(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::EscrowContracts)
+            .unwrap_or(vec![&env])
+    }
+
+    /// Sums locked and released volume in `token` across all registered escrows.
+    ///
+    /// Amounts in different tokens are not comparable, so volume is reported
+    /// one token at a time.
+    ///
+    /// # Arguments
+    /// * `token` - Token contract whose volume to report
+    ///
+    /// # Returns
+    /// * `EscrowStats` - Platform-wide totals from each escrow's `get_stats`
+    pub fn get_platform_volume(env: Env, token: Address) -> EscrowStats {
+        let mut volume = EscrowStats {
+            total_locked: 0,
+            total_released: 0,
+        };
+        for escrow in Self::get_escrow_contracts(env.clone()).iter() {
+            let stats: EscrowStats = env.invoke_contract(
+                &escrow,
+                &Symbol::new(&env, "get_stats"),
+                vec![&env, token.to_val()],
+            );
+            volume.total_locked += stats.total_locked;
+            volume.total_released += stats.total_released;
+        }
+        volume
+    }
+
     /// Gets the previous version (before last upgrade).
     ///
     /// # Returns
@@ -1135,6 +1419,15 @@ fn migrate_v1_to_v2(_env: &Env) {
     // Add actual migration logic based on your data structure changes
 }
 
+/// Sets defaults for storage introduced by newer versions.
+/// Only write keys that are absent so existing data is never clobbered.
+fn init_new_storage_fields(_env: &Env) {
+    // Example:
+    // if !env.storage().instance().has(&DataKey::NewField) {
+    //     env.storage().instance().set(&DataKey::NewField, &default_value);
+    // }
+}
+
 /// Migration from version 2 to version 3
 /// Placeholder for future migrations
 fn migrate_v2_to_v3(_env: &Env) {
@@ -1149,7 +1442,10 @@ fn migrate_v2_to_v3(_env: &Env) {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
+    use soroban_sdk::{
+        testutils::{Address as _, Events, Ledger},
+        Env,
+    };
 
     #[test]
     fn multisig_init_works() {
@@ -1162,7 +1458,190 @@ mod test {
         signers.push_back(Address::generate(&env));
         signers.push_back(Address::generate(&env));
 
-        client.init(&signers, &2u32);
+        client.init_multisig(&signers, &2u32);
+    }
+
+    #[test]
+    fn test_init_rejected_after_multisig_init() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        let mut signers = soroban_sdk::Vec::new(&env);
+        signers.push_back(Address::generate(&env));
+        signers.push_back(Address::generate(&env));
+        client.init_multisig(&signers, &2u32);
+        assert_eq!(client.get_admin(), None);
+
+        assert_eq!(
+            client.try_init(&Address::generate(&env)),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+        assert_eq!(client.get_admin(), None);
+    }
+
+    #[test]
+    fn test_multisig_init_rejected_after_init() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.init(&admin);
+        assert_eq!(client.get_admin(), Some(admin.clone()));
+
+        let mut signers = soroban_sdk::Vec::new(&env);
+        signers.push_back(admin);
+        assert_eq!(
+            client.try_init_multisig(&signers, &1u32),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+    }
+
+    #[test]
+    fn test_initialize_sets_admin_and_version() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &3);
+        assert_eq!(client.get_admin(), Some(admin));
+        assert_eq!(client.get_version(), 3);
+    }
+
+    #[test]
+    fn test_initialize_rejects_reinit() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        client.initialize(&Address::generate(&env), &1);
+        assert_eq!(
+            client.try_initialize(&Address::generate(&env), &2),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+        assert_eq!(client.get_version(), 1);
+    }
+
+    #[test]
+    fn test_init_rejected_after_initialize() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        client.initialize(&Address::generate(&env), &1);
+        assert_eq!(
+            client.try_init(&Address::generate(&env)),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Initial version must be positive")]
+    fn test_initialize_rejects_zero_version() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        client.initialize(&Address::generate(&env), &0);
+    }
+
+    #[test]
+    fn test_update_admin_applies_after_timelock() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        client.init(&admin);
+
+        let pending = client.update_admin(&new_admin);
+        assert_eq!(pending.new_admin, new_admin);
+        assert_eq!(client.get_pending_admin(), Some(pending.clone()));
+        assert_eq!(client.get_admin(), Some(admin));
+
+        env.ledger().set_timestamp(pending.executable_at);
+        assert_eq!(client.execute_admin_update(), new_admin);
+        assert_eq!(client.get_admin(), Some(new_admin));
+        assert_eq!(client.get_pending_admin(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Admin update timelock not expired")]
+    fn test_update_admin_rejected_before_timelock() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        client.init(&Address::generate(&env));
+        let pending = client.update_admin(&Address::generate(&env));
+
+        env.ledger().set_timestamp(pending.executable_at - 1);
+        client.execute_admin_update();
+    }
+
+    fn setup_multisig<'a>(
+        env: &Env,
+    ) -> (GrainlifyContractClient<'a>, soroban_sdk::Vec<Address>) {
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(env, &contract_id);
+
+        let mut signers = soroban_sdk::Vec::new(env);
+        signers.push_back(Address::generate(env));
+        signers.push_back(Address::generate(env));
+        signers.push_back(Address::generate(env));
+        client.init_multisig(&signers, &2u32);
+
+        (client, signers)
+    }
+
+    #[test]
+    fn test_upgrade_proposal_reaches_two_of_three_threshold() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, signers) = setup_multisig(&env);
+
+        let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm_hash);
+
+        client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+        assert!(!client.is_upgrade_executable(&proposal_id));
+
+        client.approve_upgrade(&proposal_id, &signers.get(2).unwrap());
+        assert!(client.is_upgrade_executable(&proposal_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "Threshold not met")]
+    fn test_execute_upgrade_below_threshold_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, signers) = setup_multisig(&env);
+
+        let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm_hash);
+        client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+
+        client.execute_upgrade(&proposal_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "AlreadyApproved")]
+    fn test_upgrade_double_approval_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, signers) = setup_multisig(&env);
+
+        let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm_hash);
+        client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+        client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
     }
 
     #[test]
@@ -1174,7 +1653,23 @@ mod test {
         let client = GrainlifyContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.init_admin(&admin);
+        client.init(&admin);
+
+        client.set_version(&2);
+        assert_eq!(client.get_version(), 2);
+    }
+
+    #[test]
+    fn test_init_starts_at_version_one() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.init(&admin);
+        assert_eq!(client.get_version(), 1);
 
         client.set_version(&2);
         assert_eq!(client.get_version(), 2);
@@ -1189,7 +1684,7 @@ mod test {
         let client = GrainlifyContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.init_admin(&admin);
+        client.init(&admin);
 
         // Initial version should be 1
         assert_eq!(client.get_version(), 1);
@@ -1221,7 +1716,7 @@ mod test {
         let client = GrainlifyContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.init_admin(&admin);
+        client.init(&admin);
 
         let migration_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1238,7 +1733,7 @@ mod test {
         let client = GrainlifyContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.init_admin(&admin);
+        client.init(&admin);
 
         let migration_hash = BytesN::from_array(&env, &[0u8; 32]);
 
@@ -1266,7 +1761,7 @@ mod test {
         let client = GrainlifyContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.init_admin(&admin);
+        client.init(&admin);
 
         // Initially no previous version
         assert!(client.get_previous_version().is_none());
@@ -1292,8 +1787,10 @@ mod test {
 
         let admin = Address::generate(&env);
         
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
         // 1. Initialize contract
-        client.init_admin(&admin);
+        client.init(&admin);
         assert_eq!(client.get_version(), 1);
 
         // 2. Simulate upgrade (in real scenario, this would call upgrade() with WASM hash)
@@ -1328,7 +1825,7 @@ mod test {
         let client = GrainlifyContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.init_admin(&admin);
+        client.init(&admin);
 
         // Migrate from v1 to v2
         let hash1 = BytesN::from_array(&env, &[1u8; 32]);
@@ -1350,7 +1847,7 @@ mod test {
         let client = GrainlifyContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.init_admin(&admin);
+        client.init(&admin);
 
         let initial_event_count = env.events().all().len();
 
@@ -1361,5 +1858,136 @@ mod test {
         let events = env.events().all();
         assert!(events.len() > initial_event_count);
     }
-}
 
+    #[test]
+    fn test_post_upgrade_init_runs_once_per_version() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.init(&admin);
+
+        // Simulate an upgrade bumping the version
+        client.set_version(&3);
+        assert!(client.post_upgrade_init(&admin));
+
+        // Running again for the same version is a no-op
+        assert!(!client.post_upgrade_init(&admin));
+        assert_eq!(client.get_version(), 3);
+
+        // The next version bump allows the hook to run again
+        client.set_version(&4);
+        assert!(client.post_upgrade_init(&admin));
+        assert!(!client.post_upgrade_init(&admin));
+    }
+
+    #[test]
+    fn test_post_upgrade_init_on_multisig_contract() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, signers) = setup_multisig(&env);
+
+        assert_eq!(
+            client.try_post_upgrade_init(&Address::generate(&env)),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert!(client.post_upgrade_init(&signers.get(0).unwrap()));
+        assert!(!client.post_upgrade_init(&signers.get(1).unwrap()));
+    }
+
+    #[test]
+    fn test_post_upgrade_init_requires_initialization() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        assert_eq!(
+            client.try_post_upgrade_init(&Address::generate(&env)),
+            Err(Ok(Error::NotInitialized))
+        );
+    }
+
+    mod mock_escrow {
+        use super::super::EscrowStats;
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct MockEscrow;
+
+        #[contractimpl]
+        impl MockEscrow {
+            pub fn set_stats(env: Env, token: Address, total_locked: i128, total_released: i128) {
+                env.storage().instance().set(
+                    &token,
+                    &EscrowStats {
+                        total_locked,
+                        total_released,
+                    },
+                );
+            }
+
+            pub fn get_stats(env: Env, token: Address) -> EscrowStats {
+                env.storage().instance().get(&token).unwrap_or(EscrowStats {
+                    total_locked: 0,
+                    total_released: 0,
+                })
+            }
+        }
+    }
+
+    #[test]
+    fn test_platform_volume_aggregates_registered_escrows() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.init(&admin);
+
+        let usdc = Address::generate(&env);
+        let xlm = Address::generate(&env);
+        let bounty_escrow = env.register_contract(None, mock_escrow::MockEscrow);
+        let bounty_client = mock_escrow::MockEscrowClient::new(&env, &bounty_escrow);
+        bounty_client.set_stats(&usdc, &5_000, &2_000);
+        bounty_client.set_stats(&xlm, &300, &100);
+        let program_escrow = env.register_contract(None, mock_escrow::MockEscrow);
+        mock_escrow::MockEscrowClient::new(&env, &program_escrow).set_stats(&usdc, &10_000, &7_500);
+
+        assert_eq!(client.get_platform_volume(&usdc).total_locked, 0);
+
+        client.register_escrow_contract(&bounty_escrow);
+        client.register_escrow_contract(&program_escrow);
+        assert_eq!(client.get_escrow_contracts().len(), 2);
+
+        let volume = client.get_platform_volume(&usdc);
+        assert_eq!(volume.total_locked, 15_000);
+        assert_eq!(volume.total_released, 9_500);
+
+        // Other tokens are reported separately, never summed into USDC volume
+        let volume = client.get_platform_volume(&xlm);
+        assert_eq!(volume.total_locked, 300);
+        assert_eq!(volume.total_released, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "Escrow already registered")]
+    fn test_register_escrow_contract_rejects_duplicates() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+        client.init(&Address::generate(&env));
+
+        let escrow = Address::generate(&env);
+        client.register_escrow_contract(&escrow);
+        client.register_escrow_contract(&escrow);
+    }
+}
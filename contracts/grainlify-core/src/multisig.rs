@@ -161,6 +161,14 @@ impl MultiSig {
         );
     }
 
+    /// Returns whether `address` is a configured signer (false before `init`)
+    pub fn is_signer(env: &Env, address: &Address) -> bool {
+        env.storage()
+            .instance()
+            .get::<DataKey, MultiSigConfig>(&DataKey::Config)
+            .is_some_and(|config| config.signers.contains(address))
+    }
+
     /// =======================
     /// Internal Helpers
     /// =======================
@@ -64,7 +64,7 @@ fn test_cross_contract_workflow_lock_release_payout() {
     // escrow_client.lock_funds(&depositor, &1, &amount, &deadline);
 
     // 2. Release funds to contributor
-    // escrow_client.release_funds(&1, &contributor);
+    // escrow_client.release_funds(&1, &contributor, &None);
 
     // 3. Verify contributor received funds
     // let balance = token_client.balance(&contributor);
@@ -92,6 +92,7 @@
 //! let program_id = String::from_str(&env, "Hackathon2024");
 //! let backend = Address::from_string("GBACKEND...");
 //! let usdc_token = Address::from_string("CUSDC...");
+//! let organizer = Address::from_string("GORGANIZER...");
 //!
 //! let program = escrow_client.init_program(
 //!     &program_id,
@@ -101,7 +102,7 @@
 //!
 //! // 2. Lock prize pool (10,000 USDC)
 //! let prize_pool = 10_000_0000000; // 10,000 USDC (7 decimals)
-//! escrow_client.lock_program_funds(&prize_pool);
+//! escrow_client.lock_program_funds(&program_id, &organizer, &prize_pool);
 //!
 //! // 3. After hackathon, distribute prizes
 //! let winners = vec![
@@ -140,8 +141,8 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Env, String, Symbol,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    vec, Address, Env, String, Symbol, Vec,
 };
 
 // Event types
@@ -149,6 +150,7 @@ const PROGRAM_INITIALIZED: Symbol = symbol_short!("ProgInit");
 const FUNDS_LOCKED: Symbol = symbol_short!("FundLock");
 const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
 const PAYOUT: Symbol = symbol_short!("Payout");
+const FUNDS_RECLAIMED: Symbol = symbol_short!("Reclaim");
 
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
@@ -159,6 +161,22 @@ const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
 
+// Upper bound on recipients in a single batch payout, keeps the
+// transaction within Soroban resource limits
+const MAX_RECIPIENTS: u32 = 100;
+
+// Upper bound on programs in a single `get_schedules_for` query
+const MAX_SCHEDULE_QUERY: u32 = 20;
+
+// Upper bound on programs registered by a single `init_programs` call
+const MAX_PROGRAM_BATCH: u32 = 20;
+
+// Upper bound on addresses updated by a single `set_whitelist_batch` call
+const MAX_WHITELIST_BATCH: u32 = 50;
+
+// Release history entries kept inline per program before older ones are archived
+const DEFAULT_HISTORY_RETENTION: u32 = 50;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
@@ -175,6 +193,17 @@ mod monitoring {
     const OPERATION_COUNT: &str = "op_count";
     const USER_COUNT: &str = "usr_count";
     const ERROR_COUNT: &str = "err_count";
+    const USER_SEEN: &str = "user_seen";
+
+    // Ledgers a seen-user flag is extended to (~180 days at 5s per ledger)
+    const USER_SEEN_TTL: u32 = 3_110_400;
+    // Remaining ledgers below which the flag is extended again
+    const USER_SEEN_TTL_THRESHOLD: u32 = USER_SEEN_TTL / 2;
+    const VERBOSITY: &str = "verbosity";
+
+    // Verbosity levels: summary-only events vs. one event per item
+    pub const VERBOSITY_SUMMARY: u32 = 0;
+    pub const VERBOSITY_DETAILED: u32 = 1;
 
     // Event: Operation metric
     #[contracttype]
@@ -248,6 +277,8 @@ mod monitoring {
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
 
+        track_user(env, &caller);
+
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
@@ -259,6 +290,40 @@ mod monitoring {
         );
     }
 
+    // Track unique user: one flag per address, counted the first time it is seen
+    pub fn track_user(env: &Env, caller: &Address) {
+        let seen_key = (Symbol::new(env, USER_SEEN), caller.clone());
+        if !env.storage().persistent().has(&seen_key) {
+            env.storage().persistent().set(&seen_key, &true);
+
+            let usr_key = Symbol::new(env, USER_COUNT);
+            let users: u64 = env.storage().persistent().get(&usr_key).unwrap_or(0);
+            env.storage().persistent().set(&usr_key, &(users + 1));
+        }
+
+        // Keep the flag live while the user is active; once archived it has to
+        // be restored before any of this user's operations can run
+        env.storage()
+            .persistent()
+            .extend_ttl(&seen_key, USER_SEEN_TTL_THRESHOLD, USER_SEEN_TTL);
+    }
+
+    // Event verbosity (defaults to detailed so existing indexers keep their events)
+    pub fn get_verbosity(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, VERBOSITY))
+            .unwrap_or(VERBOSITY_DETAILED)
+    }
+
+    pub fn set_verbosity(env: &Env, level: u32) {
+        env.storage().instance().set(&Symbol::new(env, VERBOSITY), &level);
+    }
+
+    pub fn is_detailed(env: &Env) -> bool {
+        get_verbosity(env) >= VERBOSITY_DETAILED
+    }
+
     // Track performance
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
         let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
@@ -358,7 +423,7 @@ mod monitoring {
 
 // ==================== ANTI-ABUSE MODULE ====================
 mod anti_abuse {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+    use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Symbol, Vec};
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -380,9 +445,12 @@ mod anti_abuse {
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub enum AntiAbuseKey {
         Config,
-        State(Address),
+        State(Symbol, Address), // operation, address -> AddressState
         Whitelist(Address),
+        WhitelistExpiry(Address),
         Admin,
+        OpConfig(Symbol), // operation -> AntiAbuseConfig overriding Config
+        WhitelistIndex,   // Vec<Address> of every address with a whitelist flag
     }
 
     pub fn get_config(env: &Env) -> AntiAbuseConfig {
@@ -400,21 +468,100 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Config, &config);
     }
 
-    pub fn is_whitelisted(env: &Env, address: Address) -> bool {
+    // Operation-specific config, falling back to the global one
+    pub fn get_operation_config(env: &Env, operation: Symbol) -> AntiAbuseConfig {
         env.storage()
             .instance()
-            .has(&AntiAbuseKey::Whitelist(address))
+            .get(&AntiAbuseKey::OpConfig(operation))
+            .unwrap_or_else(|| get_config(env))
+    }
+
+    pub fn set_operation_config(env: &Env, operation: Symbol, config: AntiAbuseConfig) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::OpConfig(operation), &config);
+    }
+
+    pub fn is_whitelisted(env: &Env, address: Address) -> bool {
+        if !env
+            .storage()
+            .instance()
+            .has(&AntiAbuseKey::Whitelist(address.clone()))
+        {
+            return false;
+        }
+        let expiry: Option<u64> = env
+            .storage()
+            .instance()
+            .get(&AntiAbuseKey::WhitelistExpiry(address));
+        match expiry {
+            Some(expiry) => env.ledger().timestamp() < expiry,
+            None => true,
+        }
     }
 
     pub fn set_whitelist(env: &Env, address: Address, whitelisted: bool) {
         if whitelisted {
             env.storage()
                 .instance()
-                .set(&AntiAbuseKey::Whitelist(address), &true);
+                .set(&AntiAbuseKey::Whitelist(address.clone()), &true);
+            index_whitelisted(env, &address);
         } else {
             env.storage()
                 .instance()
-                .remove(&AntiAbuseKey::Whitelist(address));
+                .remove(&AntiAbuseKey::Whitelist(address.clone()));
+            unindex_whitelisted(env, &address);
+        }
+        env.storage()
+            .instance()
+            .remove(&AntiAbuseKey::WhitelistExpiry(address));
+    }
+
+    pub fn set_whitelist_until(env: &Env, address: Address, expiry: u64) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::Whitelist(address.clone()), &true);
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::WhitelistExpiry(address.clone()), &expiry);
+        index_whitelisted(env, &address);
+    }
+
+    // Addresses currently whitelisted (expired entries are filtered out)
+    pub fn get_whitelist(env: &Env) -> Vec<Address> {
+        let mut result = vec![env];
+        for address in get_whitelist_index(env).iter() {
+            if is_whitelisted(env, address.clone()) {
+                result.push_back(address);
+            }
+        }
+        result
+    }
+
+    fn get_whitelist_index(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::WhitelistIndex)
+            .unwrap_or(vec![env])
+    }
+
+    fn index_whitelisted(env: &Env, address: &Address) {
+        let mut index = get_whitelist_index(env);
+        if !index.contains(address) {
+            index.push_back(address.clone());
+            env.storage()
+                .instance()
+                .set(&AntiAbuseKey::WhitelistIndex, &index);
+        }
+    }
+
+    fn unindex_whitelisted(env: &Env, address: &Address) {
+        let mut index = get_whitelist_index(env);
+        if let Some(position) = index.first_index_of(address) {
+            index.remove(position);
+            env.storage()
+                .instance()
+                .set(&AntiAbuseKey::WhitelistIndex, &index);
         }
     }
 
@@ -426,14 +573,21 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
-    pub fn check_rate_limit(env: &Env, address: Address) {
+    /// Enforces the cooldown and window limits for `address`.
+    ///
+    /// `operation` names the throttled entry point; it is published with the
+    /// abuse events and included in the panic message so operators can tell
+    /// which operation is being throttled. Limits come from the operation's
+    /// override when one is set, and each operation keeps its own window and
+    /// cooldown per address.
+    pub fn check_rate_limit(env: &Env, address: Address, operation: Symbol) {
         if is_whitelisted(env, address.clone()) {
             return;
         }
 
-        let config = get_config(env);
+        let config = get_operation_config(env, operation.clone());
         let now = env.ledger().timestamp();
-        let key = AntiAbuseKey::State(address.clone());
+        let key = AntiAbuseKey::State(operation.clone(), address.clone());
 
         let mut state: AddressState = env.storage().persistent().get(&key).unwrap_or(AddressState {
             last_operation_timestamp: 0,
@@ -447,9 +601,9 @@ mod anti_abuse {
         {
             env.events().publish(
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
-                (address.clone(), now),
+                (address.clone(), operation.clone(), now),
             );
-            panic!("Operation in cooldown period");
+            panic!("Operation in cooldown period: {:?}", operation);
         }
 
         // 2. Window check
@@ -462,9 +616,9 @@ mod anti_abuse {
             if state.operation_count >= config.max_operations {
                 env.events().publish(
                     (symbol_short!("abuse"), symbol_short!("limit")),
-                    (address.clone(), now),
+                    (address.clone(), operation.clone(), now),
                 );
-                panic!("Rate limit exceeded");
+                panic!("Rate limit exceeded: {:?}", operation);
             }
             state.operation_count += 1;
         }
@@ -537,6 +691,9 @@ pub struct PayoutRecord {
 /// * `released` - Whether this schedule has been executed
 /// * `released_at` - Timestamp when the schedule was executed (None if not released)
 /// * `released_by` - Address that triggered the release (None if not released)
+/// * `release_ledger` - Ledger sequence gating the release instead of
+///   `release_timestamp`, for programs that reason in blocks (None for
+///   time-based schedules). `release_timestamp` then records creation time.
 ///
 /// # Usage
 /// Used to implement milestone-based payouts and scheduled distributions for programs.
@@ -552,6 +709,7 @@ pub struct PayoutRecord {
 ///     released: false,
 ///     released_at: None,
 ///     released_by: None,
+///     release_ledger: None,
 /// };
 /// ```
 #[contracttype]
@@ -564,6 +722,7 @@ pub struct ProgramReleaseSchedule {
     pub released: bool,
     pub released_at: Option<u64>,
     pub released_by: Option<Address>,
+    pub release_ledger: Option<u32>,
 }
 
 /// History record for executed program release schedules.
@@ -579,6 +738,16 @@ pub struct ProgramReleaseHistory {
     pub release_type: ReleaseType,
 }
 
+/// Aggregate counts and amounts of a program's release schedules.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleStats {
+    pub pending_count: u32,
+    pub pending_amount: i128,
+    pub released_count: u32,
+    pub released_amount: i128,
+}
+
 /// Type of release execution for programs.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -599,6 +768,30 @@ pub struct ProgramScheduleCreated {
     pub created_by: Address,
 }
 
+/// Record (and event) of an in-place change to a pending program release schedule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramScheduleModified {
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub old_amount: i128,
+    pub new_amount: i128,
+    pub old_timestamp: u64,
+    pub new_timestamp: u64,
+    pub modified_by: Address,
+    pub modified_at: u64,
+}
+
+/// Summary event emitted once when a batch of schedules is created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchedulesBatchCreated {
+    pub program_id: String,
+    pub count: u32,
+    pub total_amount: i128,
+    pub created_by: Address,
+}
+
 /// Event emitted when a program release schedule is executed.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -610,6 +803,29 @@ pub struct ProgramScheduleReleased {
     pub released_at: u64,
     pub released_by: Address,
     pub release_type: ReleaseType,
+    /// Seconds between the scheduled release time and actual execution
+    pub latency: u64,
+}
+
+/// Event emitted when a payout drops a program's balance below its low-balance threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LowBalance {
+    pub program_id: String,
+    pub remaining_balance: i128,
+    pub threshold: i128,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the anti-abuse rate limit configuration changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitConfigUpdated {
+    pub window_size: u64,
+    pub max_operations: u32,
+    pub cooldown_period: u64,
+    pub updated_by: Address,
+    pub timestamp: u64,
 }
 
 /// Complete program state and configuration.
@@ -650,7 +866,8 @@ pub struct ProgramScheduleReleased {
 ///
 /// # Invariants
 /// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
+/// - `remaining_balance = total_funds - sum(payout_history.amounts)`, less
+///   any funds returned by `reclaim_unspent`
 /// - `payout_history` is append-only
 /// - `program_id` and `authorized_payout_key` are immutable after registration
 #[contracttype]
@@ -664,14 +881,66 @@ pub struct ProgramData {
     pub token_address: Address,
 }
 
+/// Lifetime volume for one token in this escrow contract, consumed by the core
+/// platform registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowStats {
+    pub total_locked: i128,
+    pub total_released: i128,
+}
+
+/// Typed errors surfaced by the program escrow
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Locking would push a program's `total_funds` above its deposit cap
+    DepositCapExceeded = 1,
+    /// Locking would overflow a program's `total_funds` or `remaining_balance`
+    BalanceOverflow = 2,
+    /// A deduplicated batch payout lists the same recipient twice
+    DuplicateRecipient = 3,
+    /// Payouts and schedule releases are halted while the contract is paused
+    ContractPaused = 4,
+    /// A schedule's timestamp is closer than the minimum interval to another pending schedule
+    ScheduleTooClose = 5,
+    /// A released schedule can no longer be modified
+    ScheduleAlreadyReleased = 6,
+    /// Pending schedules would exceed the program's remaining balance
+    TotalScheduleExceedsBalance = 7,
+    /// A payout would take the program's remaining balance below its reserve
+    BelowReserve = 8,
+    /// No program is registered under the given ID
+    ProgramNotFound = 9,
+    /// The program has no remaining balance to reclaim
+    NothingToReclaim = 10,
+    /// Full coverage was required but the schedules leave part of the balance unscheduled
+    IncompleteScheduleCoverage = 11,
+    /// A weighted vesting total overflows when scheduled or split across its weights
+    VestingOverflow = 12,
+}
+
 /// Storage key type for individual programs
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    Program(String), // program_id -> ProgramData
-    ReleaseSchedule(String, u64), // program_id, schedule_id -> ProgramReleaseSchedule
-    ReleaseHistory(String), // program_id -> Vec<ProgramReleaseHistory>
-    NextScheduleId(String), // program_id -> next schedule_id
+    Program(String),               // program_id -> ProgramData
+    DepositCap(String),            // program_id -> maximum total_funds
+    ReentrancyGuard,               // Symbol of the schedule release in progress
+    LowBalanceThreshold(String),   // program_id -> balance below which LowBalance is emitted
+    Reserve(String),               // program_id -> balance payouts may not touch until released
+    Paused,                        // bool: payouts and schedule releases are halted
+    MinScheduleInterval,           // u64 seconds required between a program's pending schedules
+    ReleaseSchedule(String, u64),  // program_id, schedule_id -> ProgramReleaseSchedule
+    ReleaseHistory(String),        // program_id -> Vec<ProgramReleaseHistory>
+    HistoryRetention,              // u32 inline release history entries kept per program
+    ArchivedHistory(String, u32),  // program_id, archive index -> ProgramReleaseHistory
+    ArchivedHistoryCount(String),  // program_id -> number of archived history entries
+    ScheduleModifications(String), // program_id -> Vec<ProgramScheduleModified>
+    NextScheduleId(String),        // program_id -> next schedule_id
+    FeesCollected(Address),        // token -> total fees charged in that token
+    Reclaims(String),              // program_id -> Vec<PayoutRecord> of unspent funds returned
 }
 
 // ============================================================================
@@ -684,6 +953,14 @@ pub struct ProgramEscrowContract;
 // Event symbols for program release schedules
 const PROG_SCHEDULE_CREATED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_sch_c");
 const PROG_SCHEDULE_RELEASED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_sch_r");
+const PROG_SCHEDULES_BATCH: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_sch_b");
+const PROG_SCHEDULE_MODIFIED: soroban_sdk::Symbol = soroban_sdk::symbol_short!("prg_sch_m");
+
+// Event symbol for programs crossing their low-balance threshold
+const LOW_BALANCE: Symbol = symbol_short!("low_bal");
+
+// Event symbol for anti-abuse configuration changes
+const RATE_LIMIT_UPDATED: Symbol = symbol_short!("rl_cfg_up");
 
 #[contractimpl]
 impl ProgramEscrowContract {
@@ -765,21 +1042,78 @@ impl ProgramEscrowContract {
         token_address: Address,
     ) -> ProgramData {
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, authorized_payout_key.clone());
+        anti_abuse::check_rate_limit(
+            &env,
+            authorized_payout_key.clone(),
+            symbol_short!("init_prg"),
+        );
+
+        Self::init_program_internal(&env, program_id, authorized_payout_key, token_address)
+    }
+
+    /// Registers several programs in one transaction.
+    ///
+    /// Each entry is `(program_id, authorized_payout_key, token_address)` and is
+    /// registered exactly as by `initialize_program`. The batch is atomic: if
+    /// any ID is empty or already registered (including earlier in the same
+    /// batch), the call panics and no program is registered.
+    ///
+    /// # Panics
+    /// * If the batch is empty or exceeds `MAX_PROGRAM_BATCH` entries
+    /// * If any program ID is empty or already exists
+    pub fn init_programs(env: Env, programs: Vec<(String, Address, Address)>) -> Vec<ProgramData> {
+        if programs.is_empty() {
+            panic!("Cannot process empty batch");
+        }
+        if programs.len() > MAX_PROGRAM_BATCH {
+            panic!("Batch exceeds maximum of {} programs", MAX_PROGRAM_BATCH);
+        }
+
+        // Rate limit each payout key once, however many programs it controls
+        let mut seen_keys: Vec<Address> = Vec::new(&env);
+        for (_, authorized_payout_key, _) in programs.iter() {
+            if !seen_keys.contains(&authorized_payout_key) {
+                anti_abuse::check_rate_limit(
+                    &env,
+                    authorized_payout_key.clone(),
+                    symbol_short!("init_prg"),
+                );
+                seen_keys.push_back(authorized_payout_key);
+            }
+        }
+
+        let mut registered: Vec<ProgramData> = Vec::new(&env);
+        for (program_id, authorized_payout_key, token_address) in programs.iter() {
+            registered.push_back(Self::init_program_internal(
+                &env,
+                program_id,
+                authorized_payout_key,
+                token_address,
+            ));
+        }
+        registered
+    }
 
+    /// Registers a single program; shared by `initialize_program` and `init_programs`
+    fn init_program_internal(
+        env: &Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+    ) -> ProgramData {
         let start = env.ledger().timestamp();
         let caller = authorized_payout_key.clone();
 
         // Validate program_id
         if program_id.len() == 0 {
-            monitoring::track_operation(&env, symbol_short!("init_prg"), caller, false);
+            monitoring::track_operation(env, symbol_short!("init_prg"), caller, false);
             panic!("Program ID cannot be empty");
         }
 
         // Check if program already exists
         let program_key = DataKey::Program(program_id.clone());
         if env.storage().instance().has(&program_key) {
-            monitoring::track_operation(&env, symbol_short!("init_prg"), caller, false);
+            monitoring::track_operation(env, symbol_short!("init_prg"), caller, false);
             panic!("Program already exists");
         }
 
@@ -789,7 +1123,7 @@ impl ProgramEscrowContract {
             total_funds: 0,
             remaining_balance: 0,
             authorized_payout_key: authorized_payout_key.clone(),
-            payout_history: vec![&env],
+            payout_history: vec![env],
             token_address: token_address.clone(),
         };
 
@@ -810,7 +1144,7 @@ impl ProgramEscrowContract {
             .storage()
             .instance()
             .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env]);
+            .unwrap_or(vec![env]);
         registry.push_back(program_id.clone());
         env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
 
@@ -821,15 +1155,53 @@ impl ProgramEscrowContract {
         );
 
         // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("init_prg"), caller, true);
+        monitoring::track_operation(env, symbol_short!("init_prg"), caller, true);
 
         // Track performance
         let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("init_prg"), duration);
+        monitoring::emit_performance(env, symbol_short!("init_prg"), duration);
 
         program_data
     }
 
+    /// Initializes a new program escrow with a cap on its total funds.
+    ///
+    /// Behaves like `initialize_program`, but any later `lock_program_funds`
+    /// call that would push `total_funds` above `max_total_funds` fails with
+    /// `Error::DepositCapExceeded`.
+    ///
+    /// # Panics
+    /// * If `max_total_funds` is zero or negative
+    /// * Any condition that makes `initialize_program` panic
+    pub fn initialize_program_with_cap(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        max_total_funds: i128,
+    ) -> ProgramData {
+        if max_total_funds <= 0 {
+            panic!("Deposit cap must be greater than zero");
+        }
+
+        let program_data = Self::initialize_program(
+            env.clone(),
+            program_id.clone(),
+            authorized_payout_key,
+            token_address,
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::DepositCap(program_id), &max_total_funds);
+
+        program_data
+    }
+
+    /// Returns the deposit cap for a program, if one was set at init.
+    pub fn get_deposit_cap(env: Env, program_id: String) -> Option<i128> {
+        env.storage().instance().get(&DataKey::DepositCap(program_id))
+    }
+
     /// Calculate fee amount based on rate (in basis points)
     fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
         if fee_rate == 0 {
@@ -842,6 +1214,13 @@ impl ProgramEscrowContract {
             .unwrap_or(0)
     }
 
+    /// Add a charged fee to the running total for its token
+    fn record_fee(env: &Env, token: &Address, amount: i128) {
+        let key = DataKey::FeesCollected(token.clone());
+        let total: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(total + amount));
+    }
+
     /// Get fee configuration (internal helper)
     fn get_fee_config_internal(env: &Env) -> FeeConfig {
         env.storage()
@@ -896,6 +1275,8 @@ impl ProgramEscrowContract {
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `program_id` - The program to fund
+    /// * `funder` - Address the tokens are transferred from (must authorize)
     /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
     ///
     /// # Returns
@@ -904,23 +1285,25 @@ impl ProgramEscrowContract {
     /// # Panics
     /// * If amount is zero or negative
     /// * If program is not initialized
+    /// * If the funder does not hold `amount` of the program token
+    /// * `Error::DepositCapExceeded` if the net amount would push `total_funds`
+    ///   above the program's deposit cap
+    /// * `Error::BalanceOverflow` if the net amount would overflow `total_funds`
+    ///   or `remaining_balance`
     ///
     /// # State Changes
-    /// - Increases `total_funds` by amount
-    /// - Increases `remaining_balance` by amount
+    /// - Transfers `amount` from `funder` to the contract
+    /// - Forwards any lock fee to the configured fee recipient
+    /// - Increases `total_funds` by the net amount
+    /// - Increases `remaining_balance` by the net amount
     /// - Emits FundsLocked event
     ///
-    /// # Prerequisites
-    /// Before calling this function:
-    /// 1. Caller must have sufficient token balance
-    /// 2. Caller must approve contract for token transfer
-    /// 3. Tokens must actually be transferred to contract
+    /// # Authorization
+    /// - `funder` must authorize the call
     ///
     /// # Security Considerations
     /// - Amount must be positive
-    /// - This function doesn't perform the actual token transfer
-    /// - Caller is responsible for transferring tokens to contract
-    /// - Consider verifying contract balance matches recorded amount
+    /// - Recorded balances always match tokens actually held by the contract
     /// - Multiple lock operations are additive (cumulative)
     ///
     /// # Events
@@ -928,55 +1311,43 @@ impl ProgramEscrowContract {
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::token;
-    ///
-    /// // 1. Transfer tokens to contract
     /// let amount = 10_000_0000000; // 10,000 USDC
-    /// token_client.transfer(
-    ///     &organizer,
-    ///     &contract_address,
-    ///     &amount
-    /// );
-    ///
-    /// // 2. Record the locked funds
-    /// let updated = escrow_client.lock_program_funds(&amount);
+    /// let updated = escrow_client.lock_program_funds(&program_id, &organizer, &amount);
     /// println!("Locked: {} USDC", amount / 10_000_000);
     /// println!("Remaining: {}", updated.remaining_balance);
     /// ```
     ///
     /// # Production Usage
     /// ```bash
-    /// # 1. Transfer USDC to contract
-    /// stellar contract invoke \
-    ///   --id USDC_TOKEN_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- transfer \
-    ///   --from ORGANIZER_ADDRESS \
-    ///   --to CONTRACT_ADDRESS \
-    ///   --amount 10000000000
-    ///
-    /// # 2. Record locked funds
     /// stellar contract invoke \
     ///   --id CONTRACT_ID \
     ///   --source ORGANIZER_KEY \
     ///   -- lock_program_funds \
+    ///   --program_id hackathon-2024-q1 \
+    ///   --funder ORGANIZER_ADDRESS \
     ///   --amount 10000000000
     /// ```
     ///
     /// # Gas Cost
-    /// Low - Storage update + event emission
+    /// Medium - Token transfer + storage update + event emission
     ///
     /// # Common Pitfalls
-    /// - Forgetting to transfer tokens before calling
-    /// -  Locking amount that exceeds actual contract balance
-    /// -  Not verifying contract received the tokens
-
-    pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
+    /// - Funder lacking sufficient token balance
+    /// - Locking into a program whose token differs from the funder's asset
+    pub fn lock_program_funds(
+        env: Env,
+        program_id: String,
+        funder: Address,
+        amount: i128,
+    ) -> ProgramData {
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, env.current_contract_address());
+        anti_abuse::check_rate_limit(&env, funder.clone(), symbol_short!("lock"));
+
+        // The funder must sign for the transfer into escrow
+        funder.require_auth();
 
         let start = env.ledger().timestamp();
-        let caller = env.current_contract_address();
+        let caller = funder.clone();
 
         // Validate amount
         if amount <= 0 {
@@ -1004,12 +1375,43 @@ impl ProgramEscrowContract {
         };
         let net_amount = amount - fee_amount;
 
+        // Compute new balances with net amount, rejecting overflow
+        let (total_funds, remaining_balance) = match (
+            program_data.total_funds.checked_add(net_amount),
+            program_data.remaining_balance.checked_add(net_amount),
+        ) {
+            (Some(total), Some(remaining)) => (total, remaining),
+            _ => {
+                monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+                panic_with_error!(&env, Error::BalanceOverflow);
+            }
+        };
+
+        // Enforce the deposit cap, if one was set at init
+        if let Some(cap) = env
+            .storage()
+            .instance()
+            .get::<_, i128>(&DataKey::DepositCap(program_id.clone()))
+        {
+            if total_funds > cap {
+                monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+                panic_with_error!(&env, Error::DepositCapExceeded);
+            }
+        }
+
+        // Pull the full amount from the funder into escrow
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&funder, &contract_address, &amount);
+
         // Update balances with net amount
-        program_data.total_funds += net_amount;
-        program_data.remaining_balance += net_amount;
+        program_data.total_funds = total_funds;
+        program_data.remaining_balance = remaining_balance;
 
-        // Emit fee collected event if applicable
+        // Transfer fee to fee recipient if applicable
         if fee_amount > 0 {
+            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+            Self::record_fee(&env, &program_data.token_address, fee_amount);
             env.events().publish(
                 (symbol_short!("fee"),),
                 (
@@ -1047,6 +1449,7 @@ impl ProgramEscrowContract {
     /// * `env` - The contract environment
     /// * `recipients` - Vector of recipient addresses
     /// * `amounts` - Vector of amounts (must match recipients length)
+    /// * `dedup` - When true, reject batches that list a recipient more than once
     /// 
     /// # Returns
     /// * `ProgramData` - Updated program data after payouts
@@ -1056,6 +1459,7 @@ impl ProgramEscrowContract {
     /// * If program is not initialized
     /// * If recipients and amounts vectors have different lengths
     /// * If vectors are empty
+    /// * `Error::DuplicateRecipient` if `dedup` is set and a recipient repeats
     /// * If any amount is zero or negative
     /// * If total payout exceeds remaining balance
     /// * If arithmetic overflow occurs
@@ -1105,7 +1509,7 @@ impl ProgramEscrowContract {
     /// ];
     ///
     /// // Execute batch payout (only authorized backend can call)
-    /// let result = escrow_client.batch_payout(&winners, &prizes);
+    /// let result = escrow_client.batch_payout(&program_id, &winners, &prizes, &true);
     /// println!("Paid {} winners", winners.len());
     /// println!("Remaining: {}", result.remaining_balance);
     /// ```
@@ -1118,7 +1522,8 @@ impl ProgramEscrowContract {
     ///   --source BACKEND_KEY \
     ///   -- batch_payout \
     ///   --recipients '["GWINNER1...", "GWINNER2...", "GWINNER3..."]' \
-    ///   --amounts '[5000000000, 3000000000, 2000000000]'
+    ///   --amounts '[5000000000, 3000000000, 2000000000]' \
+    ///   --dedup true
     /// ```
     ///
     /// # Gas Cost
@@ -1141,10 +1546,14 @@ impl ProgramEscrowContract {
         program_id: String,
         recipients: Vec<Address>,
         amounts: Vec<i128>,
+        dedup: bool,
     ) -> ProgramData {
-        // Apply rate limiting to the contract itself or the program
-        // We can't easily get the caller here without getting program data first
-        
+        if Self::is_paused_internal(&env) {
+            panic_with_error!(&env, Error::ContractPaused);
+        }
+
+        let start = env.ledger().timestamp();
+
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
         let program_data: ProgramData = env
@@ -1152,9 +1561,10 @@ impl ProgramEscrowContract {
             .instance()
             .get(&program_key)
             .unwrap_or_else(|| panic!("Program not found"));
+        let caller = program_data.authorized_payout_key.clone();
 
         // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        anti_abuse::check_rate_limit(&env, caller.clone(), symbol_short!("batch_pay"));
 
         // Verify authorization - CRITICAL
         program_data.authorized_payout_key.require_auth();
@@ -1168,6 +1578,20 @@ impl ProgramEscrowContract {
             panic!("Cannot process empty batch");
         }
 
+        if recipients.len() > MAX_RECIPIENTS {
+            panic!("Batch exceeds maximum of {} recipients", MAX_RECIPIENTS);
+        }
+
+        if dedup {
+            let mut seen: Vec<Address> = Vec::new(&env);
+            for recipient in recipients.iter() {
+                if seen.contains(&recipient) {
+                    panic_with_error!(&env, Error::DuplicateRecipient);
+                }
+                seen.push_back(recipient);
+            }
+        }
+
         // Calculate total with overflow protection
         let mut total_payout: i128 = 0;
         for i in 0..amounts.len() {
@@ -1187,6 +1611,7 @@ impl ProgramEscrowContract {
                 total_payout, program_data.remaining_balance
             );
         }
+        Self::check_reserve(&env, &program_id, program_data.remaining_balance - total_payout);
 
         // Calculate fees if enabled
         let fee_config = Self::get_fee_config_internal(&env);
@@ -1230,6 +1655,7 @@ impl ProgramEscrowContract {
 
         // Emit fee collected event if applicable
         if total_fees > 0 {
+            Self::record_fee(&env, &program_data.token_address, total_fees);
             env.events().publish(
                 (symbol_short!("fee"),),
                 (
@@ -1248,6 +1674,12 @@ impl ProgramEscrowContract {
 
         // Store updated data
         env.storage().instance().set(&program_key, &updated_data);
+        Self::check_low_balance(
+            &env,
+            &program_id,
+            program_data.remaining_balance,
+            updated_data.remaining_balance,
+        );
 
         // Emit event
         env.events().publish(
@@ -1260,6 +1692,13 @@ impl ProgramEscrowContract {
             ),
         );
 
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("batch_pay"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("batch_pay"), duration);
+
         updated_data
     }
 
@@ -1322,6 +1761,12 @@ impl ProgramEscrowContract {
         recipient: Address,
         amount: i128,
     ) -> ProgramData {
+        if Self::is_paused_internal(&env) {
+            panic_with_error!(&env, Error::ContractPaused);
+        }
+
+        let start = env.ledger().timestamp();
+
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
         let program_data: ProgramData = env
@@ -1329,17 +1774,13 @@ impl ProgramEscrowContract {
             .instance()
             .get(&program_key)
             .unwrap_or_else(|| panic!("Program not found"));
+        let caller = program_data.authorized_payout_key.clone();
 
-        program_data.authorized_payout_key.require_auth();
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
-
-       
         // Verify authorization
-        // let caller = env.invoker();
-        // if caller != program_data.authorized_payout_key {
-        //     panic!("Unauthorized: only authorized payout key can trigger payouts");
-        // }
+        caller.require_auth();
+
+        // Apply rate limiting to the authorized payout key
+        anti_abuse::check_rate_limit(&env, caller.clone(), symbol_short!("payout"));
 
         // Validate amount
         if amount <= 0 {
@@ -1353,6 +1794,7 @@ impl ProgramEscrowContract {
                 amount, program_data.remaining_balance
             );
         }
+        Self::check_reserve(&env, &program_id, program_data.remaining_balance - amount);
 
         // Calculate and collect fee if enabled
         let fee_config = Self::get_fee_config_internal(&env);
@@ -1372,6 +1814,7 @@ impl ProgramEscrowContract {
         // Transfer fee to fee recipient if applicable
         if fee_amount > 0 {
             token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+            Self::record_fee(&env, &program_data.token_address, fee_amount);
             env.events().publish(
                 (symbol_short!("fee"),),
                 (
@@ -1401,9 +1844,14 @@ impl ProgramEscrowContract {
 
         // Store updated data
         env.storage().instance().set(&program_key, &updated_data);
+        Self::check_low_balance(
+            &env,
+            &program_id,
+            program_data.remaining_balance,
+            updated_data.remaining_balance,
+        );
 
         // Emit Payout event (with net amount after fee)
-        // Emit event
         env.events().publish(
             (PAYOUT,),
             (
@@ -1414,59 +1862,164 @@ impl ProgramEscrowContract {
             ),
         );
 
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("payout"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("payout"), duration);
+
         updated_data
     }
 
-    // ========================================================================
-    // Release Schedule Functions
-    // ========================================================================
-
-    /// Creates a time-based release schedule for a program.
+    /// Returns a program's unspent balance to the organizer.
+    ///
+    /// Transfers the part of `remaining_balance` not committed to pending
+    /// release schedules to `recipient`, records the movement under
+    /// `get_reclaims` (not the payout history) and clears any reserve. Pending schedules stay funded and can
+    /// still be released afterwards.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to create schedule for
-    /// * `amount` - Amount to release (in token's smallest denomination)
-    /// * `release_timestamp` - Unix timestamp when funds become available
-    /// * `recipient` - Address that will receive the funds
+    /// * `program_id` - The program to reclaim from
+    /// * `recipient` - Address that receives the unspent funds
     ///
     /// # Returns
-    /// * `ProgramData` - Updated program data
-    ///
-    /// # Panics
-    /// * If program is not initialized
-    /// * If caller is not authorized payout key
-    /// * If amount is invalid
-    /// * If timestamp is in the past
-    /// * If amount exceeds remaining balance
+    /// * `ProgramData` - Updated program data whose remaining balance covers
+    ///   only the pending schedules
     ///
-    /// # State Changes
-    /// - Creates ProgramReleaseSchedule record
-    /// - Updates next schedule ID
-    /// - Emits ScheduleCreated event
+    /// # Errors
+    /// * `Error::ContractPaused` if the contract is paused
+    /// * `Error::ProgramNotFound` if the program does not exist
+    /// * `Error::NothingToReclaim` if pending schedules already commit the whole
+    ///   remaining balance
     ///
     /// # Authorization
-    /// - Only authorized payout key can call this function
+    /// - Program's authorized payout key must authorize
     ///
-    /// # Example
-    /// ```rust
-    /// let now = env.ledger().timestamp();
-    /// let release_time = now + (30 * 24 * 60 * 60); // 30 days from now
-    /// escrow_client.create_program_release_schedule(
-    ///     &"Hackathon2024",
-    ///     &500_0000000, // 500 tokens
-    ///     &release_time,
-    ///     &winner_address
-    /// );
-    /// ```
-    pub fn create_program_release_schedule(
+    /// # Events
+    /// Emits: `FundsReclaimed(program_id, recipient, amount)`
+    pub fn reclaim_unspent(
         env: Env,
         program_id: String,
-        amount: i128,
-        release_timestamp: u64,
         recipient: Address,
-    ) -> ProgramData {
-        let start = env.ledger().timestamp();
+    ) -> Result<ProgramData, Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .ok_or(Error::ProgramNotFound)?;
+        let caller = program_data.authorized_payout_key.clone();
+        caller.require_auth();
+
+        let scheduled = get_program_total_scheduled_amount(&env, &program_id);
+        let amount = program_data.remaining_balance - scheduled;
+        if amount <= 0 {
+            return Err(Error::NothingToReclaim);
+        }
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+        let reclaims_key = DataKey::Reclaims(program_id.clone());
+        let mut reclaims: Vec<PayoutRecord> = env
+            .storage()
+            .instance()
+            .get(&reclaims_key)
+            .unwrap_or(vec![&env]);
+        reclaims.push_back(PayoutRecord {
+            recipient: recipient.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().instance().set(&reclaims_key, &reclaims);
+        program_data.remaining_balance = scheduled;
+        env.storage().instance().set(&program_key, &program_data);
+        env.storage()
+            .instance()
+            .remove(&DataKey::Reserve(program_id.clone()));
+
+        env.events()
+            .publish((FUNDS_RECLAIMED,), (program_id, recipient, amount));
+
+        monitoring::track_operation(&env, symbol_short!("reclaim"), caller, true);
+
+        Ok(program_data)
+    }
+
+    /// Returns the unspent funds returned by `reclaim_unspent` for a program.
+    pub fn get_reclaims(env: Env, program_id: String) -> Vec<PayoutRecord> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Reclaims(program_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Total returned to the organizer by `reclaim_unspent`.
+    fn total_reclaimed(env: &Env, program_id: &String) -> i128 {
+        let reclaims: Vec<PayoutRecord> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Reclaims(program_id.clone()))
+            .unwrap_or(vec![env]);
+        reclaims.iter().map(|record| record.amount).sum()
+    }
+
+    // ========================================================================
+    // Release Schedule Functions
+    // ========================================================================
+
+    /// Creates a time-based release schedule for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to create schedule for
+    /// * `amount` - Amount to release (in token's smallest denomination)
+    /// * `release_timestamp` - Unix timestamp when funds become available
+    /// * `recipient` - Address that will receive the funds
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data
+    ///
+    /// # Panics
+    /// * If program is not initialized
+    /// * If caller is not authorized payout key
+    /// * If amount is invalid
+    /// * If timestamp is in the past
+    /// * If amount exceeds remaining balance
+    /// * `Error::ScheduleTooClose` if another pending schedule is within the minimum interval
+    ///
+    /// # State Changes
+    /// - Creates ProgramReleaseSchedule record
+    /// - Updates next schedule ID
+    /// - Emits ScheduleCreated event
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    ///
+    /// # Example
+    /// ```rust
+    /// let now = env.ledger().timestamp();
+    /// let release_time = now + (30 * 24 * 60 * 60); // 30 days from now
+    /// escrow_client.create_program_release_schedule(
+    ///     &"Hackathon2024",
+    ///     &500_0000000, // 500 tokens
+    ///     &release_time,
+    ///     &winner_address
+    /// );
+    /// ```
+    pub fn create_program_release_schedule(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        release_timestamp: u64,
+        recipient: Address,
+    ) -> ProgramData {
+        let start = env.ledger().timestamp();
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
@@ -1477,7 +2030,11 @@ impl ProgramEscrowContract {
             .unwrap_or_else(|| panic!("Program not found"));
 
         // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        anti_abuse::check_rate_limit(
+            &env,
+            program_data.authorized_payout_key.clone(),
+            symbol_short!("create_p"),
+        );
 
         // Verify authorization
         program_data.authorized_payout_key.require_auth();
@@ -1498,45 +2055,14 @@ impl ProgramEscrowContract {
             panic!("Insufficient balance for scheduled amount");
         }
 
-        // Get next schedule ID
-        let schedule_id: u64 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextScheduleId(program_id.clone()))
-            .unwrap_or(1);
-
-        // Create release schedule
-        let schedule = ProgramReleaseSchedule {
-            schedule_id,
+        store_program_schedule(
+            &env,
+            &program_id,
             amount,
             release_timestamp,
-            recipient: recipient.clone(),
-            released: false,
-            released_at: None,
-            released_by: None,
-        };
-
-        // Store schedule
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id), &schedule);
-
-        // Update next schedule ID
-        env.storage()
-            .persistent()
-            .set(&DataKey::NextScheduleId(program_id.clone()), &(schedule_id + 1));
-
-        // Emit program schedule created event
-        env.events().publish(
-            (PROG_SCHEDULE_CREATED,),
-            ProgramScheduleCreated {
-                program_id: program_id.clone(),
-                schedule_id,
-                amount,
-                release_timestamp,
-                recipient: recipient.clone(),
-                created_by: program_data.authorized_payout_key.clone(),
-            },
+            &recipient,
+            &program_data.authorized_payout_key,
+            true,
         );
 
         // Track successful operation
@@ -1555,6 +2081,218 @@ impl ProgramEscrowContract {
         updated_data
     }
 
+    /// Creates a release schedule gated on a ledger sequence instead of a timestamp.
+    ///
+    /// The schedule becomes releasable once `env.ledger().sequence()` reaches
+    /// `release_ledger`; otherwise it behaves like a time-based schedule.
+    /// Block-based schedules are not subject to the minimum schedule interval.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to create schedule for
+    /// * `amount` - Amount to release (in token's smallest denomination)
+    /// * `release_ledger` - Ledger sequence at which funds become available
+    /// * `recipient` - Address that will receive the funds
+    ///
+    /// # Panics
+    /// * If program is not initialized
+    /// * If amount is invalid
+    /// * If `release_ledger` is not after the current ledger sequence
+    /// * If amount exceeds remaining balance
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    pub fn create_prog_ledger_schedule(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        release_ledger: u32,
+        recipient: Address,
+    ) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        anti_abuse::check_rate_limit(
+            &env,
+            program_data.authorized_payout_key.clone(),
+            symbol_short!("create_l"),
+        );
+        program_data.authorized_payout_key.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        if release_ledger <= env.ledger().sequence() {
+            panic!("Release ledger must be in the future");
+        }
+
+        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id);
+        if scheduled_total + amount > program_data.remaining_balance {
+            panic!("Insufficient balance for scheduled amount");
+        }
+
+        store_ledger_schedule(
+            &env,
+            &program_id,
+            amount,
+            release_ledger,
+            &recipient,
+            &program_data.authorized_payout_key,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("create_l"), program_data.authorized_payout_key, true);
+
+        env.storage().instance().get(&program_key).unwrap()
+    }
+
+    /// Creates a non-linear vesting plan as a series of release schedules.
+    ///
+    /// `total` is split across the periods proportionally to `weights`
+    /// (e.g. `[1, 2, 3, 4]` for a back-loaded curve). Rounding remainders go
+    /// to the last period so the schedule amounts always sum to `total`.
+    /// Periods that round down to zero are skipped.
+    ///
+    /// Emits a single `SchedulesBatchCreated` summary; the per-period
+    /// `ProgramScheduleCreated` events are only emitted at detailed
+    /// monitoring verbosity (see `set_event_verbosity`).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to vest from
+    /// * `recipient` - Address that will receive the funds
+    /// * `total` - Total amount to vest
+    /// * `timestamps` - Strictly increasing release timestamps, one per period
+    /// * `weights` - Relative weight of each period
+    /// * `require_full_coverage` - When true, the pending schedules must cover
+    ///   the program's entire remaining balance once this plan is added
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data
+    ///
+    /// # Panics
+    /// * If program is not found
+    /// * If `timestamps` and `weights` are empty or differ in length
+    /// * If weights do not sum to a positive value
+    /// * If timestamps are not in the future and strictly increasing
+    /// * If `total` exceeds the unscheduled remaining balance
+    /// * `Error::IncompleteScheduleCoverage` if `require_full_coverage` is set
+    ///   and `total` is less than the unscheduled remaining balance
+    /// * `Error::ScheduleTooClose` if any period is within the minimum interval
+    ///   of another pending schedule
+    /// * `Error::VestingOverflow` if `total` overflows when scheduled or
+    ///   multiplied by a period's weight
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    pub fn create_weighted_vesting(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        total: i128,
+        timestamps: Vec<u64>,
+        weights: Vec<u32>,
+        require_full_coverage: bool,
+    ) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        anti_abuse::check_rate_limit(
+            &env,
+            program_data.authorized_payout_key.clone(),
+            symbol_short!("vest_w"),
+        );
+        program_data.authorized_payout_key.require_auth();
+
+        if timestamps.is_empty() || timestamps.len() != weights.len() {
+            panic!("Timestamps and weights must be non-empty and the same length");
+        }
+        if total <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        let mut weight_sum: i128 = 0;
+        for weight in weights.iter() {
+            weight_sum += weight as i128;
+        }
+        if weight_sum <= 0 {
+            panic!("Weights must sum to a positive value");
+        }
+
+        let mut previous = env.ledger().timestamp();
+        for timestamp in timestamps.iter() {
+            if timestamp <= previous {
+                panic!("Release timestamps must be in the future and strictly increasing");
+            }
+            previous = timestamp;
+        }
+
+        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id)
+            .checked_add(total)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::VestingOverflow));
+        if scheduled_total > program_data.remaining_balance {
+            panic!("Insufficient balance for scheduled amount");
+        }
+        if require_full_coverage && scheduled_total < program_data.remaining_balance {
+            panic_with_error!(&env, Error::IncompleteScheduleCoverage);
+        }
+
+        let detailed = monitoring::is_detailed(&env);
+        let last = timestamps.len() - 1;
+        let mut allocated: i128 = 0;
+        let mut count: u32 = 0;
+        for i in 0..timestamps.len() {
+            let amount = if i == last {
+                total - allocated
+            } else {
+                match total.checked_mul(weights.get(i).unwrap() as i128) {
+                    Some(weighted) => weighted / weight_sum,
+                    None => panic_with_error!(&env, Error::VestingOverflow),
+                }
+            };
+            allocated += amount;
+
+            if amount > 0 {
+                store_program_schedule(
+                    &env,
+                    &program_id,
+                    amount,
+                    timestamps.get(i).unwrap(),
+                    &recipient,
+                    &program_data.authorized_payout_key,
+                    detailed,
+                );
+                count += 1;
+            }
+        }
+
+        env.events().publish(
+            (PROG_SCHEDULES_BATCH,),
+            SchedulesBatchCreated {
+                program_id: program_id.clone(),
+                count,
+                total_amount: total,
+                created_by: program_data.authorized_payout_key.clone(),
+            },
+        );
+
+        monitoring::track_operation(
+            &env,
+            symbol_short!("vest_w"),
+            program_data.authorized_payout_key,
+            true,
+        );
+
+        env.storage().instance().get(&program_key).unwrap()
+    }
+
     /// Automatically releases funds for program schedules that are due.
     /// Can be called by anyone after the release timestamp has passed.
     ///
@@ -1562,12 +2300,17 @@ impl ProgramEscrowContract {
     /// * `env` - The contract environment
     /// * `program_id` - The program to check for due schedules
     /// * `schedule_id` - The specific schedule to release
+    /// * `caller` - Whoever triggers the release (must authorize); recorded as
+    ///   `released_by` so the audit trail names the executor, not the recipient
     ///
     /// # Panics
     /// * If program doesn't exist
     /// * If schedule doesn't exist
     /// * If schedule is already released
     /// * If schedule is not yet due
+    /// * If the remaining balance no longer covers the scheduled amount
+    /// * If another schedule release is already in progress (reentrancy)
+    /// * `Error::ContractPaused` if the contract is paused
     ///
     /// # State Changes
     /// - Transfers tokens to recipient
@@ -1579,15 +2322,23 @@ impl ProgramEscrowContract {
     /// # Example
     /// ```rust
     /// // Anyone can call this after the timestamp
-    /// escrow_client.release_program_schedule_automatic(&"Hackathon2024", &1);
+    /// escrow_client.release_prog_schedule_automatic(&"Hackathon2024", &1, &keeper);
     /// ```
     pub fn release_prog_schedule_automatic(
         env: Env,
         program_id: String,
         schedule_id: u64,
+        caller: Address,
     ) {
+        if Self::is_paused_internal(&env) {
+            panic_with_error!(&env, Error::ContractPaused);
+        }
+
+        caller.require_auth();
+
+        Self::enter_guard(&env, symbol_short!("rel_auto"));
+
         let start = env.ledger().timestamp();
-        let caller = env.current_contract_address();
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
@@ -1619,10 +2370,14 @@ impl ProgramEscrowContract {
 
         // Check if due for release
         let now = env.ledger().timestamp();
-        if now < schedule.release_timestamp {
+        if !is_schedule_due(&env, &schedule) {
             panic!("Schedule not yet due for release");
         }
 
+        if program_data.remaining_balance < schedule.amount {
+            panic!("Insufficient balance for scheduled amount");
+        }
+
         // Get token client
         let contract_address = env.current_contract_address();
         let token_client = token::Client::new(&env, &program_data.token_address);
@@ -1633,7 +2388,7 @@ impl ProgramEscrowContract {
         // Update schedule
         schedule.released = true;
         schedule.released_at = Some(now);
-        schedule.released_by = Some(env.current_contract_address());
+        schedule.released_by = Some(caller.clone());
 
         // Update program data
         let mut updated_data = program_data.clone();
@@ -1646,25 +2401,22 @@ impl ProgramEscrowContract {
             amount: schedule.amount,
             recipient: schedule.recipient.clone(),
             released_at: now,
-            released_by: env.current_contract_address(),
+            released_by: caller.clone(),
             release_type: ReleaseType::Automatic,
         };
 
-        let mut history: Vec<ProgramReleaseHistory> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id.clone()))
-            .unwrap_or(vec![&env]);
-        history.push_back(history_entry);
-
         // Store updates
         env.storage()
             .persistent()
             .set(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id), &schedule);
         env.storage().instance().set(&program_key, &updated_data);
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+        append_release_history(&env, &program_id, history_entry);
+        Self::check_low_balance(
+            &env,
+            &program_id,
+            program_data.remaining_balance,
+            updated_data.remaining_balance,
+        );
 
         // Emit program schedule released event
         env.events().publish(
@@ -1675,8 +2427,9 @@ impl ProgramEscrowContract {
                 amount: schedule.amount,
                 recipient: schedule.recipient.clone(),
                 released_at: now,
-                released_by: env.current_contract_address(),
+                released_by: caller.clone(),
                 release_type: ReleaseType::Automatic,
+                latency: now.saturating_sub(schedule.release_timestamp),
             },
         );
 
@@ -1686,6 +2439,8 @@ impl ProgramEscrowContract {
         // Track performance
         let duration = env.ledger().timestamp().saturating_sub(start);
         monitoring::emit_performance(&env, symbol_short!("rel_auto"), duration);
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
     }
 
     /// Manually releases funds for a program schedule (authorized payout key only).
@@ -1701,6 +2456,9 @@ impl ProgramEscrowContract {
     /// * If caller is not authorized payout key
     /// * If schedule doesn't exist
     /// * If schedule is already released
+    /// * If the remaining balance no longer covers the scheduled amount
+    /// * If another schedule release is already in progress (reentrancy)
+    /// * `Error::ContractPaused` if the contract is paused
     ///
     /// # State Changes
     /// - Transfers tokens to recipient
@@ -1722,6 +2480,12 @@ impl ProgramEscrowContract {
         program_id: String,
         schedule_id: u64,
     ) {
+        if Self::is_paused_internal(&env) {
+            panic_with_error!(&env, Error::ContractPaused);
+        }
+
+        Self::enter_guard(&env, symbol_short!("rel_man"));
+
         let start = env.ledger().timestamp();
 
         // Get program data
@@ -1733,7 +2497,11 @@ impl ProgramEscrowContract {
             .unwrap_or_else(|| panic!("Program not found"));
 
         // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        anti_abuse::check_rate_limit(
+            &env,
+            program_data.authorized_payout_key.clone(),
+            symbol_short!("rel_man"),
+        );
 
         // Verify authorization
         program_data.authorized_payout_key.require_auth();
@@ -1758,6 +2526,10 @@ impl ProgramEscrowContract {
             panic!("Schedule already released");
         }
 
+        if program_data.remaining_balance < schedule.amount {
+            panic!("Insufficient balance for scheduled amount");
+        }
+
         // Get token client
         let contract_address = env.current_contract_address();
         let token_client = token::Client::new(&env, &program_data.token_address);
@@ -1786,23 +2558,20 @@ impl ProgramEscrowContract {
             release_type: ReleaseType::Manual,
         };
 
-        let mut history: Vec<ProgramReleaseHistory> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id.clone()))
-            .unwrap_or(vec![&env]);
-        history.push_back(history_entry);
-
         // Store updates
         env.storage()
             .persistent()
             .set(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id), &schedule);
         env.storage().instance().set(&program_key, &updated_data);
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
-
-        // Emit program schedule released event
+        append_release_history(&env, &program_id, history_entry);
+        Self::check_low_balance(
+            &env,
+            &program_id,
+            program_data.remaining_balance,
+            updated_data.remaining_balance,
+        );
+
+        // Emit program schedule released event
         env.events().publish(
             (PROG_SCHEDULE_RELEASED,),
             ProgramScheduleReleased {
@@ -1813,6 +2582,7 @@ impl ProgramEscrowContract {
                 released_at: now,
                 released_by: program_data.authorized_payout_key.clone(),
                 release_type: ReleaseType::Manual,
+                latency: now.saturating_sub(schedule.release_timestamp),
             },
         );
 
@@ -1822,6 +2592,192 @@ impl ProgramEscrowContract {
         // Track performance
         let duration = env.ledger().timestamp().saturating_sub(start);
         monitoring::emit_performance(&env, symbol_short!("rel_man"), duration);
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+    }
+
+    /// Adjusts the amount and/or timestamp of a pending schedule in place.
+    ///
+    /// Keeps the schedule's ID stable instead of cancelling and recreating it;
+    /// each change is appended to the program's modification log.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program owning the schedule
+    /// * `schedule_id` - The schedule to modify
+    /// * `new_amount` - Replacement amount, or `None` to keep the current one
+    /// * `new_timestamp` - Replacement release timestamp, or `None` to keep it;
+    ///   a block-based schedule given a timestamp becomes time-based
+    ///
+    /// # Panics
+    /// * If program or schedule doesn't exist
+    /// * If the new amount is not positive or the new timestamp is not in the future
+    /// * `Error::ScheduleAlreadyReleased` if the schedule was already released
+    /// * `Error::TotalScheduleExceedsBalance` if pending schedules would exceed
+    ///   the remaining balance
+    /// * `Error::ScheduleTooClose` if the new timestamp is within the minimum
+    ///   interval of another pending schedule
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    pub fn modify_program_schedule(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+        new_amount: Option<i128>,
+        new_timestamp: Option<u64>,
+    ) -> ProgramReleaseSchedule {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        anti_abuse::check_rate_limit(
+            &env,
+            program_data.authorized_payout_key.clone(),
+            symbol_short!("mod_sch"),
+        );
+        program_data.authorized_payout_key.require_auth();
+
+        let schedule_key = DataKey::ReleaseSchedule(program_id.clone(), schedule_id);
+        let mut schedule: ProgramReleaseSchedule = env
+            .storage()
+            .persistent()
+            .get(&schedule_key)
+            .unwrap_or_else(|| panic!("Schedule not found"));
+
+        if schedule.released {
+            panic_with_error!(&env, Error::ScheduleAlreadyReleased);
+        }
+
+        let old_amount = schedule.amount;
+        let old_timestamp = schedule.release_timestamp;
+
+        if let Some(amount) = new_amount {
+            if amount <= 0 {
+                panic!("Amount must be greater than zero");
+            }
+            let others = get_program_total_scheduled_amount(&env, &program_id) - old_amount;
+            if others + amount > program_data.remaining_balance {
+                panic_with_error!(&env, Error::TotalScheduleExceedsBalance);
+            }
+            schedule.amount = amount;
+        }
+
+        if let Some(timestamp) = new_timestamp {
+            if timestamp <= env.ledger().timestamp() {
+                panic!("Release timestamp must be in the future");
+            }
+            check_schedule_spacing(&env, &program_id, timestamp, Some(schedule_id));
+            schedule.release_timestamp = timestamp;
+            schedule.release_ledger = None;
+        }
+
+        env.storage().persistent().set(&schedule_key, &schedule);
+
+        let record = ProgramScheduleModified {
+            program_id: program_id.clone(),
+            schedule_id,
+            old_amount,
+            new_amount: schedule.amount,
+            old_timestamp,
+            new_timestamp: schedule.release_timestamp,
+            modified_by: program_data.authorized_payout_key.clone(),
+            modified_at: env.ledger().timestamp(),
+        };
+
+        let log_key = DataKey::ScheduleModifications(program_id);
+        let mut log: Vec<ProgramScheduleModified> = env
+            .storage()
+            .persistent()
+            .get(&log_key)
+            .unwrap_or(vec![&env]);
+        log.push_back(record.clone());
+        env.storage().persistent().set(&log_key, &log);
+
+        env.events().publish((PROG_SCHEDULE_MODIFIED,), record);
+
+        monitoring::track_operation(&env, symbol_short!("mod_sch"), program_data.authorized_payout_key, true);
+
+        schedule
+    }
+
+    /// Marks a schedule release as in progress, panicking on reentry.
+    ///
+    /// Schedule releases call out to the program's token contract, so a
+    /// malicious token could otherwise re-enter and release again before
+    /// the schedule is marked released. Every failure path panics, which
+    /// rolls back the guard along with the rest of the state.
+    fn enter_guard(env: &Env, operation: Symbol) {
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &operation);
+    }
+
+    /// Sets the minimum number of seconds required between a program's pending
+    /// schedule timestamps (contract admin only). Zero disables the check.
+    ///
+    /// Every schedule is a separate keeper call, so tightly packed schedules
+    /// multiply keeper work without changing what the recipient receives.
+    pub fn set_min_schedule_interval(env: Env, interval: u64) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinScheduleInterval, &interval);
+    }
+
+    /// Sets how many release history entries are kept inline per program
+    /// (contract admin only). Older entries are moved to the archive the next
+    /// time a schedule is released.
+    pub fn set_history_retention(env: Env, limit: u32) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if limit == 0 {
+            panic!("Retention must be greater than zero");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::HistoryRetention, &limit);
+    }
+
+    /// Returns the inline release history retention (defaults to 50).
+    pub fn get_history_retention(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HistoryRetention)
+            .unwrap_or(DEFAULT_HISTORY_RETENTION)
+    }
+
+    /// Returns the minimum schedule interval in seconds (0 when unset).
+    pub fn get_min_schedule_interval(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinScheduleInterval)
+            .unwrap_or(0)
+    }
+
+    /// Sets the monitoring event verbosity (contract admin only).
+    ///
+    /// At `0` (summary) batch operations such as `create_weighted_vesting`
+    /// emit only their summary event; at `1` (detailed, the default) they
+    /// also emit one event per created item.
+    pub fn set_event_verbosity(env: Env, level: u32) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        monitoring::set_verbosity(&env, level);
+    }
+
+    /// Returns the monitoring event verbosity level.
+    pub fn get_event_verbosity(env: Env) -> u32 {
+        monitoring::get_verbosity(&env)
     }
 
     // ========================================================================
@@ -1891,7 +2847,114 @@ impl ProgramEscrowContract {
         program_data.remaining_balance
     }
 
-    /// Update fee configuration (admin only - uses authorized_payout_key)
+    /// Sets the balance below which payouts emit a `LowBalance` event (contract admin only).
+    ///
+    /// # Panics
+    /// * If threshold is negative
+    /// * If program doesn't exist
+    pub fn set_low_balance_threshold(env: Env, program_id: String, threshold: i128) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if threshold < 0 {
+            panic!("Threshold cannot be negative");
+        }
+        if !env
+            .storage()
+            .instance()
+            .has(&DataKey::Program(program_id.clone()))
+        {
+            panic!("Program not found");
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::LowBalanceThreshold(program_id), &threshold);
+    }
+
+    /// Returns a program's low-balance threshold, if one is set.
+    pub fn get_low_balance_threshold(env: Env, program_id: String) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::LowBalanceThreshold(program_id))
+    }
+
+    /// Holds back part of a program's balance for final settlement (contract admin only).
+    ///
+    /// `batch_payout` and `single_payout` reject payouts that would take
+    /// `remaining_balance` below the reserve until `release_reserve` is called.
+    ///
+    /// # Panics
+    /// * If reserve is negative or exceeds the remaining balance
+    /// * If program doesn't exist
+    pub fn set_reserve(env: Env, program_id: String, reserve: i128) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if reserve < 0 {
+            panic!("Reserve cannot be negative");
+        }
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+        if reserve > program_data.remaining_balance {
+            panic!("Reserve exceeds remaining balance");
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserve(program_id), &reserve);
+    }
+
+    /// Lifts a program's reserve so the full balance can be paid out (contract admin only).
+    pub fn release_reserve(env: Env, program_id: String) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::Reserve(program_id));
+    }
+
+    /// Returns a program's reserve (0 when none is held).
+    pub fn get_reserve(env: Env, program_id: String) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Reserve(program_id))
+            .unwrap_or(0)
+    }
+
+    fn check_reserve(env: &Env, program_id: &String, balance_after: i128) {
+        if balance_after < Self::get_reserve(env.clone(), program_id.clone()) {
+            panic_with_error!(env, Error::BelowReserve);
+        }
+    }
+
+    /// Emits `LowBalance` when a payout takes the balance from at or above the
+    /// program's threshold to below it
+    fn check_low_balance(env: &Env, program_id: &String, before: i128, after: i128) {
+        let threshold: Option<i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::LowBalanceThreshold(program_id.clone()));
+        if let Some(threshold) = threshold {
+            if before >= threshold && after < threshold {
+                env.events().publish(
+                    (LOW_BALANCE,),
+                    LowBalance {
+                        program_id: program_id.clone(),
+                        remaining_balance: after,
+                        threshold,
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Update fee configuration (contract admin only)
     /// 
     /// # Arguments
     /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
@@ -1905,16 +2968,9 @@ impl ProgramEscrowContract {
         fee_recipient: Option<Address>,
         fee_enabled: Option<bool>,
     ) {
-        // Verify authorization
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
-
-        // Note: In Soroban, we check authorization by requiring auth from the authorized key
-        // For this function, we'll require auth from the authorized_payout_key
-        program_data.authorized_payout_key.require_auth();
+        // Fee config is shared by all programs, so only the contract admin may change it
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
         let mut fee_config = Self::get_fee_config_internal(&env);
 
@@ -1959,6 +3015,58 @@ impl ProgramEscrowContract {
         Self::get_fee_config_internal(&env)
     }
 
+    /// Get total fees charged in a given token across all programs (view function)
+    pub fn get_fees_collected(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeesCollected(token))
+            .unwrap_or(0)
+    }
+
+    /// Sweep the contract's `token` balance that no program is owed to the fee recipient
+    /// (contract admin only)
+    ///
+    /// Only the excess above the summed `remaining_balance` of programs funded in
+    /// `token` is moved, so sweeping one token never touches another token's
+    /// programs or the funds still owed to this one.
+    ///
+    /// # Returns
+    /// * `i128` - Amount swept (0 if nothing was in excess)
+    pub fn sweep_fees(env: Env, token: Address) -> i128 {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        let registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+        let mut owed: i128 = 0;
+        for program_id in registry.iter() {
+            let program: Option<ProgramData> =
+                env.storage().instance().get(&DataKey::Program(program_id));
+            if let Some(program) = program {
+                if program.token_address == token {
+                    owed += program.remaining_balance;
+                }
+            }
+        }
+
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &token);
+        let excess = token_client.balance(&contract_address) - owed;
+        if excess <= 0 {
+            return 0;
+        }
+
+        let fee_recipient = Self::get_fee_config_internal(&env).fee_recipient;
+        token_client.transfer(&contract_address, &fee_recipient, &excess);
+        env.events()
+            .publish((symbol_short!("fee_swp"),), (token, excess, fee_recipient));
+
+        excess
+    }
+
     /// Gets the total number of programs registered.
     ///
     /// # Returns
@@ -1973,6 +3081,38 @@ impl ProgramEscrowContract {
         registry.len()
     }
 
+    /// Returns the total amount locked and paid out across all programs
+    /// funded in `token`.
+    ///
+    /// # Returns
+    /// * `EscrowStats` - Sum of `total_funds` and of funds already disbursed;
+    ///   reclaimed funds are not counted as released
+    pub fn get_stats(env: Env, token: Address) -> EscrowStats {
+        let registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+
+        let mut stats = EscrowStats {
+            total_locked: 0,
+            total_released: 0,
+        };
+        for program_id in registry.iter() {
+            let program: Option<ProgramData> = env
+                .storage()
+                .instance()
+                .get(&DataKey::Program(program_id.clone()));
+            if let Some(program) = program.filter(|program| program.token_address == token) {
+                stats.total_locked += program.total_funds;
+                stats.total_released += program.total_funds
+                    - program.remaining_balance
+                    - Self::total_reclaimed(&env, &program_id);
+            }
+        }
+        stats
+    }
+
     // ========================================================================
     // Monitoring & Analytics Functions
     // ========================================================================
@@ -1997,6 +3137,42 @@ impl ProgramEscrowContract {
         monitoring::get_performance_stats(&env, function_name)
     }
 
+    // ========================================================================
+    // Pause Control
+    // ========================================================================
+
+    /// Halts payouts and schedule releases until `unpause` is called (admin only).
+    ///
+    /// Schedule releases can be triggered by anyone, so pausing is how an
+    /// operator stops funds leaving the contract during an incident.
+    pub fn pause(env: Env) {
+        Self::set_paused(&env, true);
+    }
+
+    /// Resumes payouts and schedule releases (admin only).
+    pub fn unpause(env: Env) {
+        Self::set_paused(&env, false);
+    }
+
+    /// Returns whether the contract is paused.
+    pub fn is_paused(env: Env) -> bool {
+        Self::is_paused_internal(&env)
+    }
+
+    fn is_paused_internal(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    fn set_paused(env: &Env, paused: bool) {
+        let admin = anti_abuse::get_admin(env).expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &paused);
+    }
+
     // ========================================================================
     // Anti-Abuse Administrative Functions
     // ========================================================================
@@ -2029,6 +3205,49 @@ impl ProgramEscrowContract {
                 cooldown_period,
             },
         );
+
+        env.events().publish(
+            (RATE_LIMIT_UPDATED,),
+            RateLimitConfigUpdated {
+                window_size,
+                max_operations,
+                cooldown_period,
+                updated_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Overrides the rate limit configuration for a single operation.
+    /// Only the admin can call this.
+    ///
+    /// `operation` is the symbol the entry point rate-limits under (e.g.
+    /// `create_p`, `payout`); operations without an override use the global
+    /// configuration.
+    pub fn set_operation_rate_limit(
+        env: Env,
+        operation: Symbol,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        anti_abuse::set_operation_config(
+            &env,
+            operation,
+            anti_abuse::AntiAbuseConfig {
+                window_size,
+                max_operations,
+                cooldown_period,
+            },
+        );
+    }
+
+    /// Returns the rate limit configuration in effect for an operation.
+    pub fn get_operation_rate_limit(env: Env, operation: Symbol) -> anti_abuse::AntiAbuseConfig {
+        anti_abuse::get_operation_config(&env, operation)
     }
 
     /// Adds or removes an address from the whitelist.
@@ -2040,8 +3259,41 @@ impl ProgramEscrowContract {
         anti_abuse::set_whitelist(&env, address, whitelisted);
     }
 
-    /// Checks if an address is whitelisted.
-    pub fn is_whitelisted(env: Env, address: Address) -> bool {
+    /// Adds or removes several addresses from the whitelist in one call.
+    /// Only the admin can call this.
+    ///
+    /// # Panics
+    /// * If more than `MAX_WHITELIST_BATCH` addresses are given
+    pub fn set_whitelist_batch(env: Env, addresses: Vec<Address>, whitelisted: bool) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if addresses.len() > MAX_WHITELIST_BATCH {
+            panic!("Batch exceeds maximum of {} addresses", MAX_WHITELIST_BATCH);
+        }
+
+        for address in addresses.iter() {
+            anti_abuse::set_whitelist(&env, address, whitelisted);
+        }
+    }
+
+    /// Returns every currently whitelisted address, in the order they were added.
+    pub fn get_whitelist(env: Env) -> Vec<Address> {
+        anti_abuse::get_whitelist(&env)
+    }
+
+    /// Whitelists an address until `expiry` (exclusive), after which it is
+    /// rate limited again without needing an explicit removal.
+    /// Only the admin can call this.
+    pub fn set_whitelist_until(env: Env, address: Address, expiry: u64) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        anti_abuse::set_whitelist_until(&env, address, expiry);
+    }
+
+    /// Checks if an address is whitelisted.
+    pub fn is_whitelisted(env: Env, address: Address) -> bool {
         anti_abuse::is_whitelisted(&env, address)
     }
 
@@ -2111,6 +3363,33 @@ impl ProgramEscrowContract {
         schedules
     }
 
+    /// Retrieves release schedules for several programs in one call.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_ids` - Programs to query, at most `MAX_SCHEDULE_QUERY`
+    ///
+    /// # Returns
+    /// * `Vec<(String, Vec<ProgramReleaseSchedule>)>` - Schedules per program, in input order
+    ///
+    /// # Panics
+    /// * If more than `MAX_SCHEDULE_QUERY` programs are requested
+    pub fn get_schedules_for(
+        env: Env,
+        program_ids: Vec<String>,
+    ) -> Vec<(String, Vec<ProgramReleaseSchedule>)> {
+        if program_ids.len() > MAX_SCHEDULE_QUERY {
+            panic!("Query exceeds maximum of {} programs", MAX_SCHEDULE_QUERY);
+        }
+
+        let mut result = Vec::new(&env);
+        for program_id in program_ids.iter() {
+            let schedules = Self::get_all_prog_release_schedules(env.clone(), program_id.clone());
+            result.push_back((program_id, schedules));
+        }
+        result
+    }
+
     /// Retrieves pending (unreleased) schedules for a program.
     ///
     /// # Arguments
@@ -2143,10 +3422,9 @@ impl ProgramEscrowContract {
     pub fn get_due_program_schedules(env: Env, program_id: String) -> Vec<ProgramReleaseSchedule> {
         let pending = Self::get_pending_program_schedules(env.clone(), program_id.clone());
         let mut due = Vec::new(&env);
-        let now = env.ledger().timestamp();
-        
+
         for schedule in pending.iter() {
-            if schedule.release_timestamp <= now {
+            if is_schedule_due(&env, &schedule) {
                 due.push_back(schedule.clone());
             }
         }
@@ -2154,6 +3432,33 @@ impl ProgramEscrowContract {
         due
     }
 
+    /// Lists every due, unreleased schedule across all registered programs.
+    ///
+    /// Meant for keeper bots: each entry can be passed straight to
+    /// `release_prog_schedule_automatic`. Pagination is over programs in
+    /// registration order, so a bot can walk the registry in bounded pages.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `offset` - Number of registered programs to skip
+    /// * `limit` - Programs to scan, capped at `MAX_SCHEDULE_QUERY`
+    ///
+    /// # Returns
+    /// * `Vec<(String, u64)>` - `(program_id, schedule_id)` pairs ready for release
+    pub fn get_all_ready_schedules(env: Env, offset: u32, limit: u32) -> Vec<(String, u64)> {
+        let programs = Self::list_programs(env.clone());
+        let end = offset.saturating_add(limit.min(MAX_SCHEDULE_QUERY)).min(programs.len());
+
+        let mut ready = Vec::new(&env);
+        for i in offset..end {
+            let program_id = programs.get(i).unwrap();
+            for schedule in Self::get_due_program_schedules(env.clone(), program_id.clone()).iter() {
+                ready.push_back((program_id.clone(), schedule.schedule_id));
+            }
+        }
+        ready
+    }
+
     /// Retrieves release history for a program.
     ///
     /// # Arguments
@@ -2161,13 +3466,309 @@ impl ProgramEscrowContract {
     /// * `program_id` - The program to query
     ///
     /// # Returns
-    /// * `Vec<ProgramReleaseHistory>` - Complete release history
+    /// * `Vec<ProgramReleaseHistory>` - The most recent release history entries,
+    ///   up to the retention limit (see `get_archived_schedule_history`)
     pub fn get_program_release_history(env: Env, program_id: String) -> Vec<ProgramReleaseHistory> {
         env.storage()
             .persistent()
             .get(&DataKey::ReleaseHistory(program_id))
             .unwrap_or(vec![&env])
     }
+
+    /// Retrieves release history entries that were archived out of the inline
+    /// history, oldest first.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    /// * `offset` - Number of archived entries to skip
+    /// * `limit` - Maximum entries to return (capped at `MAX_SCHEDULE_QUERY`)
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseHistory>` - Archived history entries
+    pub fn get_archived_schedule_history(
+        env: Env,
+        program_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<ProgramReleaseHistory> {
+        let count = Self::get_archived_history_count(env.clone(), program_id.clone());
+        let end = offset
+            .saturating_add(limit.min(MAX_SCHEDULE_QUERY))
+            .min(count);
+
+        let mut archived = Vec::new(&env);
+        for index in offset..end {
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ArchivedHistory(program_id.clone(), index))
+            {
+                archived.push_back(entry);
+            }
+        }
+        archived
+    }
+
+    /// Returns how many release history entries have been archived for a program.
+    pub fn get_archived_history_count(env: Env, program_id: String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ArchivedHistoryCount(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Summarizes a program's release schedules for vesting progress views.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `ScheduleStats` - Pending and released schedule counts and amounts
+    pub fn get_schedule_stats(env: Env, program_id: String) -> ScheduleStats {
+        let mut stats = ScheduleStats {
+            pending_count: 0,
+            pending_amount: 0,
+            released_count: 0,
+            released_amount: 0,
+        };
+        for schedule in Self::get_all_prog_release_schedules(env, program_id).iter() {
+            if schedule.released {
+                stats.released_count += 1;
+                stats.released_amount += schedule.amount;
+            } else {
+                stats.pending_count += 1;
+                stats.pending_amount += schedule.amount;
+            }
+        }
+        stats
+    }
+
+    /// Reports milestone progress as a single tuple for dashboards.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `(released_amount, total_amount, released_count, total_count)` across
+    ///   all of the program's release schedules
+    ///
+    /// # Errors
+    /// * `Error::ProgramNotFound` if the program does not exist
+    pub fn get_schedule_progress(
+        env: Env,
+        program_id: String,
+    ) -> Result<(i128, i128, u32, u32), Error> {
+        if !env
+            .storage()
+            .instance()
+            .has(&DataKey::Program(program_id.clone()))
+        {
+            return Err(Error::ProgramNotFound);
+        }
+
+        let stats = Self::get_schedule_stats(env, program_id);
+        Ok((
+            stats.released_amount,
+            stats.released_amount + stats.pending_amount,
+            stats.released_count,
+            stats.released_count + stats.pending_count,
+        ))
+    }
+
+    /// Retrieves the log of in-place schedule modifications for a program.
+    pub fn get_schedule_modifications(env: Env, program_id: String) -> Vec<ProgramScheduleModified> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ScheduleModifications(program_id))
+            .unwrap_or(vec![&env])
+    }
+}
+
+/// Helper function to store a new release schedule and, when `emit_event` is
+/// set, emit its creation event.
+fn store_program_schedule(
+    env: &Env,
+    program_id: &String,
+    amount: i128,
+    release_timestamp: u64,
+    recipient: &Address,
+    created_by: &Address,
+    emit_event: bool,
+) -> u64 {
+    check_schedule_spacing(env, program_id, release_timestamp, None);
+
+    let schedule =
+        write_program_schedule(env, program_id, amount, release_timestamp, recipient, None);
+    if emit_event {
+        emit_program_schedule_created(env, program_id, &schedule, created_by);
+    }
+
+    schedule.schedule_id
+}
+
+/// Helper function to store a schedule gated on `release_ledger` and emit its
+/// creation event. Ledger-gated schedules are exempt from timestamp spacing.
+fn store_ledger_schedule(
+    env: &Env,
+    program_id: &String,
+    amount: i128,
+    release_ledger: u32,
+    recipient: &Address,
+    created_by: &Address,
+) -> u64 {
+    let schedule = write_program_schedule(
+        env,
+        program_id,
+        amount,
+        env.ledger().timestamp(),
+        recipient,
+        Some(release_ledger),
+    );
+    emit_program_schedule_created(env, program_id, &schedule, created_by);
+
+    schedule.schedule_id
+}
+
+fn write_program_schedule(
+    env: &Env,
+    program_id: &String,
+    amount: i128,
+    release_timestamp: u64,
+    recipient: &Address,
+    release_ledger: Option<u32>,
+) -> ProgramReleaseSchedule {
+    // Get next schedule ID
+    let schedule_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::NextScheduleId(program_id.clone()))
+        .unwrap_or(1);
+
+    // Create release schedule
+    let schedule = ProgramReleaseSchedule {
+        schedule_id,
+        amount,
+        release_timestamp,
+        recipient: recipient.clone(),
+        released: false,
+        released_at: None,
+        released_by: None,
+        release_ledger,
+    };
+
+    // Store schedule
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id), &schedule);
+
+    // Update next schedule ID
+    env.storage()
+        .persistent()
+        .set(&DataKey::NextScheduleId(program_id.clone()), &(schedule_id + 1));
+
+    schedule
+}
+
+fn emit_program_schedule_created(
+    env: &Env,
+    program_id: &String,
+    schedule: &ProgramReleaseSchedule,
+    created_by: &Address,
+) {
+    env.events().publish(
+        (PROG_SCHEDULE_CREATED,),
+        ProgramScheduleCreated {
+            program_id: program_id.clone(),
+            schedule_id: schedule.schedule_id,
+            amount: schedule.amount,
+            release_timestamp: schedule.release_timestamp,
+            recipient: schedule.recipient.clone(),
+            created_by: created_by.clone(),
+        },
+    );
+}
+
+/// Rejects a release timestamp that falls within the minimum schedule interval
+/// of any of the program's pending schedules other than `skip`.
+fn check_schedule_spacing(
+    env: &Env,
+    program_id: &String,
+    release_timestamp: u64,
+    skip: Option<u64>,
+) {
+    let interval: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::MinScheduleInterval)
+        .unwrap_or(0);
+    if interval == 0 {
+        return;
+    }
+
+    let next_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::NextScheduleId(program_id.clone()))
+        .unwrap_or(1);
+
+    for schedule_id in 1..next_id {
+        if skip == Some(schedule_id) {
+            continue;
+        }
+        let schedule: Option<ProgramReleaseSchedule> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id));
+        if let Some(schedule) = schedule {
+            if schedule.release_ledger.is_some() {
+                continue;
+            }
+            let gap = schedule.release_timestamp.abs_diff(release_timestamp);
+            if !schedule.released && gap < interval {
+                panic_with_error!(env, Error::ScheduleTooClose);
+            }
+        }
+    }
+}
+
+/// Appends a release history entry, archiving the oldest inline entries so
+/// the inline vector never exceeds the configured retention.
+fn append_release_history(env: &Env, program_id: &String, entry: ProgramReleaseHistory) {
+    let history_key = DataKey::ReleaseHistory(program_id.clone());
+    let mut history: Vec<ProgramReleaseHistory> = env
+        .storage()
+        .persistent()
+        .get(&history_key)
+        .unwrap_or(vec![env]);
+    history.push_back(entry);
+
+    let retention = ProgramEscrowContract::get_history_retention(env.clone());
+    if history.len() > retention {
+        let count_key = DataKey::ArchivedHistoryCount(program_id.clone());
+        let mut archived: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        while history.len() > retention {
+            let oldest = history.pop_front().unwrap();
+            env.storage()
+                .persistent()
+                .set(&DataKey::ArchivedHistory(program_id.clone(), archived), &oldest);
+            archived += 1;
+        }
+        env.storage().persistent().set(&count_key, &archived);
+    }
+
+    env.storage().persistent().set(&history_key, &history);
+}
+
+/// Whether a schedule's release condition is met: its ledger sequence for
+/// block-based schedules, otherwise its timestamp.
+fn is_schedule_due(env: &Env, schedule: &ProgramReleaseSchedule) -> bool {
+    match schedule.release_ledger {
+        Some(ledger) => env.ledger().sequence() >= ledger,
+        None => env.ledger().timestamp() >= schedule.release_timestamp,
+    }
 }
 
 /// Helper function to calculate total scheduled amount for a program.
@@ -2207,8 +3808,8 @@ fn get_program_total_scheduled_amount(env: &Env, program_id: &String) -> i128 {
 mod test {
     use super::*;
     use soroban_sdk::{
-        testutils::{Address as _, Ledger},
-        token, Address, Env, String, Vec,
+        testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+        token, Address, Env, IntoVal, String, TryFromVal, Val, Vec,
     };
 
     // Test helper to create a mock token contract
@@ -2217,6 +3818,19 @@ mod test {
         token::Client::new(env, &token_address)
     }
 
+    // Test helper that mints `amount` to a fresh funder and locks it into the program
+    fn fund_program(
+        env: &Env,
+        client: &ProgramEscrowContractClient,
+        program_id: &String,
+        amount: i128,
+    ) -> ProgramData {
+        let token_address = client.get_program_info(program_id).token_address;
+        let funder = Address::generate(env);
+        token::StellarAssetClient::new(env, &token_address).mint(&funder, &amount);
+        client.lock_program_funds(program_id, &funder, &amount)
+    }
+
     // ========================================================================
     // Program Registration Tests
     // ========================================================================
@@ -2225,30 +3839,24 @@ mod test {
         env: &Env,
         client: &ProgramEscrowContractClient<'static>,
         authorized_key: &Address,
-        token: &Address,
         program_id: &String,
         total_amount: i128,
         winner: &Address,
         release_timestamp: u64,
     ) {
-        // Register program
-        client.register_program(program_id, token, authorized_key);
-        
-        // Create and fund token
+        // Create token and register program
         let token_client = create_token_contract(env, authorized_key);
-        let token_admin = token::StellarAssetClient::new(env, &token_client.address);
-        token_admin.mint(authorized_key, &total_amount);
+        client.initialize_program(program_id, authorized_key, &token_client.address);
         
         // Lock funds for program
-        token_client.approve(authorized_key, &env.current_contract_address(), &total_amount, &1000);
-        client.lock_funds(program_id, &total_amount);
+        fund_program(env, client, program_id, total_amount);
         
         // Create release schedule
         client.create_program_release_schedule(
             program_id,
             &total_amount,
             &release_timestamp,
-            winner.clone(),
+            winner,
         );
     }
 
@@ -2260,7 +3868,6 @@ mod test {
         
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2272,7 +3879,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2303,7 +3909,6 @@ mod test {
         let authorized_key = Address::generate(&env);
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 600_0000000;
         let amount2 = 400_0000000;
@@ -2311,17 +3916,12 @@ mod test {
         
         env.mock_all_auths();
         
-        // Register program
-        client.register_program(&program_id, &token, &authorized_key);
-        
-        // Create and fund token
+        // Create token and register program
         let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
         
         // Lock funds for program
-        token_client.approve(&authorized_key, &env.current_contract_address(), &total_amount, &1000);
-        client.lock_funds(&program_id, &total_amount);
+        fund_program(&env, &client, &program_id, total_amount);
         
         // Create first release schedule
         client.create_program_release_schedule(
@@ -2372,7 +3972,6 @@ mod test {
         
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2384,7 +3983,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2393,20 +3991,22 @@ mod test {
         
         // Try to release before timestamp (should fail)
         env.ledger().set_timestamp(999);
-        let result = client.try_release_prog_schedule_automatic(&program_id, &1);
+        let keeper = Address::generate(&env);
+        let result = client.try_release_prog_schedule_automatic(&program_id, &1, &keeper);
         assert!(result.is_err());
         
         // Advance time to after release timestamp
         env.ledger().set_timestamp(1001);
         
         // Release automatically
-        client.release_prog_schedule_automatic(&program_id, &1);
+        client.release_prog_schedule_automatic(&program_id, &1, &keeper);
         
         // Verify schedule was released
         let schedule = client.get_program_release_schedule(&program_id, &1);
         assert!(schedule.released);
         assert_eq!(schedule.released_at, Some(1001));
-        assert_eq!(schedule.released_by, Some(env.current_contract_address()));
+        assert_eq!(schedule.released_by, Some(keeper.clone()));
+        assert_ne!(schedule.released_by, Some(winner.clone()));
         
         // Check no pending schedules
         let pending = client.get_pending_program_schedules(&program_id);
@@ -2428,7 +4028,6 @@ mod test {
         
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2440,7 +4039,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2449,7 +4047,7 @@ mod test {
         
         // Manually release before timestamp (authorized key can do this)
         env.ledger().set_timestamp(999);
-        client.release_prog_schedule_manual(&program_id, &1);
+        client.release_program_schedule_manual(&program_id, &1);
         
         // Verify schedule was released
         let schedule = client.get_program_release_schedule(&program_id, &1);
@@ -2474,7 +4072,6 @@ mod test {
         let authorized_key = Address::generate(&env);
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 600_0000000;
         let amount2 = 400_0000000;
@@ -2482,17 +4079,12 @@ mod test {
         
         env.mock_all_auths();
         
-        // Register program
-        client.register_program(&program_id, &token, &authorized_key);
-        
-        // Create and fund token
+        // Create token and register program
         let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
         
         // Lock funds for program
-        token_client.approve(&authorized_key, &env.current_contract_address(), &total_amount, &1000);
-        client.lock_funds(&program_id, &total_amount);
+        fund_program(&env, &client, &program_id, total_amount);
         
         // Create first schedule
         client.create_program_release_schedule(
@@ -2511,11 +4103,11 @@ mod test {
         );
         
         // Release first schedule manually
-        client.release_prog_schedule_manual(&program_id, &1);
+        client.release_program_schedule_manual(&program_id, &1);
         
         // Advance time and release second schedule automatically
         env.ledger().set_timestamp(2001);
-        client.release_prog_schedule_automatic(&program_id, &2);
+        client.release_prog_schedule_automatic(&program_id, &2, &Address::generate(&env));
         
         // Verify complete history
         let history = client.get_program_release_history(&program_id);
@@ -2556,7 +4148,6 @@ mod test {
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
         let winner3 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 300_0000000;
         let amount2 = 300_0000000;
@@ -2566,17 +4157,12 @@ mod test {
         
         env.mock_all_auths();
         
-        // Register program
-        client.register_program(&program_id, &token, &authorized_key);
-        
-        // Create and fund token
+        // Create token and register program
         let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
         
         // Lock funds for program
-        token_client.approve(&authorized_key, &env.current_contract_address(), &total_amount, &1000);
-        client.lock_funds(&program_id, &total_amount);
+        fund_program(&env, &client, &program_id, total_amount);
         
         // Create overlapping schedules (all at same timestamp)
         client.create_program_release_schedule(
@@ -2608,9 +4194,9 @@ mod test {
         assert_eq!(due.len(), 3);
         
         // Release schedules one by one
-        client.release_prog_schedule_automatic(&program_id, &1);
-        client.release_prog_schedule_automatic(&program_id, &2);
-        client.release_prog_schedule_automatic(&program_id, &3);
+        client.release_prog_schedule_automatic(&program_id, &1, &Address::generate(&env));
+        client.release_prog_schedule_automatic(&program_id, &2, &Address::generate(&env));
+        client.release_prog_schedule_automatic(&program_id, &3, &Address::generate(&env));
         
         // Verify all schedules are released
         let pending = client.get_pending_program_schedules(&program_id);
@@ -2763,10 +4349,11 @@ mod test {
 
         // Lock funds
         let amount = 10_000_0000000i128; // 10,000 USDC
-        let updated = client.lock_program_funds(&prog_id, &amount);
+        let updated = fund_program(&env, &client, &prog_id, amount);
 
         assert_eq!(updated.total_funds, amount);
         assert_eq!(updated.remaining_balance, amount);
+        assert_eq!(token_client.balance(&contract_id), amount);
     }
 
     #[test]
@@ -2793,8 +4380,8 @@ mod test {
         let amount1 = 5_000_0000000i128;
         let amount2 = 10_000_0000000i128;
 
-        client.lock_program_funds(&prog1, &amount1);
-        client.lock_program_funds(&prog2, &amount2);
+        fund_program(&env, &client, &prog1, amount1);
+        fund_program(&env, &client, &prog2, amount2);
 
         // Verify isolation - funds don't mix
         let info1 = client.get_program_info(&prog1);
@@ -2822,19 +4409,98 @@ mod test {
         client.initialize_program(&prog_id, &backend, &token_client.address);
 
         // Lock funds multiple times
-        client.lock_program_funds(&prog_id, &1_000_0000000);
-        client.lock_program_funds(&prog_id, &2_000_0000000);
-        client.lock_program_funds(&prog_id, &3_000_0000000);
+        fund_program(&env, &client, &prog_id, 1_000_0000000);
+        fund_program(&env, &client, &prog_id, 2_000_0000000);
+        fund_program(&env, &client, &prog_id, 3_000_0000000);
 
         let info = client.get_program_info(&prog_id);
         assert_eq!(info.total_funds, 6_000_0000000);
         assert_eq!(info.remaining_balance, 6_000_0000000);
     }
 
+    #[test]
+    fn test_lock_funds_transfers_from_funder_and_forwards_fee() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let funder = Address::generate(&env);
+        let fee_recipient = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.set_admin(&admin);
+        client.initialize_program(&prog_id, &Address::generate(&env), &token_client.address);
+
+        // 5% lock fee
+        client.update_fee_config(&Some(500), &None, &Some(fee_recipient.clone()), &Some(true));
+
+        token_admin.mint(&funder, &10_000);
+        let updated = client.lock_program_funds(&prog_id, &funder, &10_000);
+
+        assert_eq!(updated.remaining_balance, 9_500);
+        assert_eq!(token_client.balance(&funder), 0);
+        assert_eq!(token_client.balance(&fee_recipient), 500);
+        assert_eq!(
+            token_client.balance(&contract_id),
+            updated.remaining_balance
+        );
+    }
+
+    #[test]
+    fn test_program_payout_key_cannot_update_fee_config() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let payout_key = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.set_admin(&admin);
+        client.initialize_program(&prog_id, &payout_key, &token_client.address);
+
+        // Only the program's payout key signs: the shared fee config must stay untouched
+        let fee_recipient = Address::generate(&env);
+        env.mock_auths(&[MockAuth {
+            address: &payout_key,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "update_fee_config",
+                args: (
+                    Some(1_000i128),
+                    None::<i128>,
+                    Some(fee_recipient.clone()),
+                    Some(true),
+                )
+                    .into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        let result = client.try_update_fee_config(
+            &Some(1_000),
+            &None,
+            &Some(fee_recipient.clone()),
+            &Some(true),
+        );
+        assert!(result.is_err());
+
+        let fee_config = client.get_fee_config();
+        assert_eq!(fee_config.lock_fee_rate, 0);
+        assert!(!fee_config.fee_enabled);
+    }
+
     #[test]
     #[should_panic(expected = "Amount must be greater than zero")]
     fn test_lock_zero_funds() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
@@ -2843,7 +4509,7 @@ mod test {
         let prog_id = String::from_str(&env, "Hackathon2024");
 
         client.initialize_program(&prog_id, &backend, &token);
-        client.lock_program_funds(&prog_id, &0);
+        client.lock_program_funds(&prog_id, &Address::generate(&env), &0);
     }
 
     // ========================================================================
@@ -2865,12 +4531,12 @@ mod test {
         let prog_id = String::from_str(&env, "Test");
 
         client.initialize_program(&prog_id, &backend, &token_client.address);
-        client.lock_program_funds(&prog_id, &10_000_0000000);
+        fund_program(&env, &client, &prog_id, 10_000_0000000);
 
         let recipients = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
         let amounts = soroban_sdk::vec![&env, 1_000_0000000i128]; // Mismatch!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &recipients, &amounts, &false);
     }
 
     #[test]
@@ -2888,12 +4554,12 @@ mod test {
         let prog_id = String::from_str(&env, "Test");
 
         client.initialize_program(&prog_id, &backend, &token_client.address);
-        client.lock_program_funds(&prog_id, &5_000_0000000);
+        fund_program(&env, &client, &prog_id, 5_000_0000000);
 
         let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
         let amounts = soroban_sdk::vec![&env, 10_000_0000000i128]; // More than available!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &recipients, &amounts, &false);
     }
 
     #[test]
@@ -2946,8 +4612,8 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "Rate limit exceeded")]
-    fn test_anti_abuse_limit_panic() {
+    #[should_panic(expected = "Operation in cooldown period: Symbol(create_p)")]
+    fn test_anti_abuse_cooldown_names_throttled_operation() {
         let env = Env::default();
         env.mock_all_auths();
         env.ledger().set_timestamp(1000);
@@ -2956,18 +4622,22 @@ mod test {
 
         let admin = Address::generate(&env);
         client.set_admin(&admin);
-        client.update_rate_limit_config(&3600, &2, &0); // 2 ops max, no cooldown
+        client.update_rate_limit_config(&3600, &10, &60);
 
         let backend = Address::generate(&env);
-        let token = Address::generate(&env);
-        
-        client.initialize_program(&String::from_str(&env, "P1"), &backend, &token);
-        client.initialize_program(&String::from_str(&env, "P2"), &backend, &token);
-        client.initialize_program(&String::from_str(&env, "P3"), &backend, &token); // Should panic
+        let token_client = create_token_contract(&env, &admin);
+        let prog_id = String::from_str(&env, "P1");
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        fund_program(&env, &client, &prog_id, 1_000);
+        client.create_program_release_schedule(&prog_id, &100, &5_000, &backend);
+
+        env.ledger().with_mut(|li| li.timestamp += 30);
+        client.create_program_release_schedule(&prog_id, &100, &6_000, &backend);
     }
 
     #[test]
-    fn test_anti_abuse_whitelist() {
+    #[should_panic(expected = "Rate limit exceeded")]
+    fn test_anti_abuse_limit_panic() {
         let env = Env::default();
         env.mock_all_auths();
         env.ledger().set_timestamp(1000);
@@ -2976,27 +4646,139 @@ mod test {
 
         let admin = Address::generate(&env);
         client.set_admin(&admin);
-        client.update_rate_limit_config(&3600, &1, &60); // 1 op max
+        client.update_rate_limit_config(&3600, &2, &0); // 2 ops max, no cooldown
 
         let backend = Address::generate(&env);
         let token = Address::generate(&env);
         
-        client.set_whitelist(&backend, &true);
-        
         client.initialize_program(&String::from_str(&env, "P1"), &backend, &token);
-        client.initialize_program(&String::from_str(&env, "P2"), &backend, &token); // Should work because whitelisted
+        client.initialize_program(&String::from_str(&env, "P2"), &backend, &token);
+        client.initialize_program(&String::from_str(&env, "P3"), &backend, &token); // Should panic
     }
 
     #[test]
-    fn test_anti_abuse_config_update() {
+    #[should_panic(expected = "Rate limit exceeded: Symbol(create_p)")]
+    fn test_operation_rate_limits_are_independent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1000);
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        client.set_admin(&Address::generate(&env));
+
+        // Strict schedule creation, permissive payouts for the same backend
+        client.set_operation_rate_limit(&symbol_short!("create_p"), &3600, &1, &60);
+        client.set_operation_rate_limit(&symbol_short!("payout"), &3600, &100, &0);
+        assert_eq!(client.get_operation_rate_limit(&symbol_short!("payout")).max_operations, 100);
+        assert_eq!(client.get_operation_rate_limit(&symbol_short!("lock")).max_operations, 10);
+
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &100, &5_000, &winner);
+        for _ in 0..5 {
+            client.single_payout(&prog_id, &winner, &10);
+        }
+        assert_eq!(client.get_remaining_balance(&prog_id), 950);
+
+        env.ledger().with_mut(|li| li.timestamp += 120);
+        client.create_program_release_schedule(&prog_id, &100, &6_000, &winner);
+    }
+
+    #[test]
+    fn test_whitelist_batch_updates_index() {
         let env = Env::default();
         env.mock_all_auths();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        client.set_admin(&Address::generate(&env));
+
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+        let batch = soroban_sdk::vec![&env, a.clone(), b.clone(), c.clone()];
+        client.set_whitelist_batch(&batch, &true);
+
+        assert_eq!(client.get_whitelist(), batch);
+        assert!(client.is_whitelisted(&b));
+
+        // Re-adding does not duplicate, removal drops the entry
+        client.set_whitelist(&a, &true);
+        client.set_whitelist_batch(&soroban_sdk::vec![&env, b.clone()], &false);
+        assert_eq!(client.get_whitelist(), soroban_sdk::vec![&env, a, c]);
+        assert!(!client.is_whitelisted(&b));
+    }
 
-        let admin = Address::generate(&env);
-        client.set_admin(&admin);
-        
+    #[test]
+    #[should_panic(expected = "Batch exceeds maximum of 50 addresses")]
+    fn test_whitelist_batch_size_is_bounded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        client.set_admin(&Address::generate(&env));
+
+        let mut batch = Vec::new(&env);
+        for _ in 0..=MAX_WHITELIST_BATCH {
+            batch.push_back(Address::generate(&env));
+        }
+        client.set_whitelist_batch(&batch, &true);
+    }
+
+    #[test]
+    fn test_anti_abuse_whitelist() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1000);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.update_rate_limit_config(&3600, &1, &60); // 1 op max
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        
+        client.set_whitelist(&backend, &true);
+        
+        client.initialize_program(&String::from_str(&env, "P1"), &backend, &token);
+        client.initialize_program(&String::from_str(&env, "P2"), &backend, &token); // Should work because whitelisted
+    }
+
+    #[test]
+    fn test_whitelist_until_expires() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1000);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+
+        let backend = Address::generate(&env);
+        client.set_whitelist_until(&backend, &2000);
+
+        assert!(client.is_whitelisted(&backend));
+        env.ledger().set_timestamp(1999);
+        assert!(client.is_whitelisted(&backend));
+        env.ledger().set_timestamp(2000);
+        assert!(!client.is_whitelisted(&backend));
+
+        // A permanent entry replaces the expiring one
+        client.set_whitelist(&backend, &true);
+        assert!(client.is_whitelisted(&backend));
+    }
+
+    #[test]
+    fn test_anti_abuse_config_update() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        
         client.update_rate_limit_config(&7200, &5, &120);
         
         let config = client.get_rate_limit_config();
@@ -3004,4 +4786,1302 @@ mod test {
         assert_eq!(config.max_operations, 5);
         assert_eq!(config.cooldown_period, 120);
     }
+
+    #[test]
+    fn test_rate_limit_config_update_emits_event() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.update_rate_limit_config(&7200, &5, &120);
+
+        let (emitter, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(emitter, contract_id);
+        let expected: Vec<Val> = (RATE_LIMIT_UPDATED,).into_val(&env);
+        assert_eq!(topics, expected);
+        let event = RateLimitConfigUpdated::try_from_val(&env, &data).unwrap();
+        assert_eq!(event.window_size, 7200);
+        assert_eq!(event.max_operations, 5);
+        assert_eq!(event.cooldown_period, 120);
+        assert_eq!(event.updated_by, admin);
+    }
+
+    // ========================================================================
+    // Schedule Release Event Tests
+    // ========================================================================
+
+    fn setup_funded_program<'a>(
+        env: &Env,
+        amount: i128,
+    ) -> (ProgramEscrowContractClient<'a>, String, token::Client<'a>) {
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let backend = Address::generate(env);
+        let token_client = create_token_contract(env, &admin);
+        let prog_id = String::from_str(env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        fund_program(env, &client, &prog_id, amount);
+
+        (client, prog_id, token_client)
+    }
+
+    fn last_schedule_released_event(env: &Env) -> ProgramScheduleReleased {
+        let topics: Vec<Val> = (PROG_SCHEDULE_RELEASED,).into_val(env);
+        let mut found = None;
+        for (_, event_topics, data) in env.events().all().iter() {
+            if event_topics == topics {
+                found = Some(ProgramScheduleReleased::try_from_val(env, &data).unwrap());
+            }
+        }
+        found.expect("no schedule released event")
+    }
+
+    #[test]
+    fn test_schedule_released_event_reports_latency() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let amount = 1_000i128;
+        let (client, prog_id, token_client) = setup_funded_program(&env, amount);
+        let winner = Address::generate(&env);
+
+        client.create_program_release_schedule(&prog_id, &amount, &1_000, &winner);
+
+        // Keeper executes 250 seconds after the scheduled time
+        env.ledger().set_timestamp(1_250);
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+
+        let event = last_schedule_released_event(&env);
+        assert_eq!(event.schedule_id, 1);
+        assert_eq!(event.released_at, 1_250);
+        assert_eq!(event.latency, 250);
+        assert_eq!(token_client.balance(&winner), amount);
+    }
+
+    // ========================================================================
+    // Schedule Reentrancy Tests
+    // ========================================================================
+
+    fn simulate_release_in_progress(env: &Env, client: &ProgramEscrowContractClient) {
+        env.as_contract(&client.address, || {
+            env.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &symbol_short!("rel_auto"));
+        });
+    }
+
+    #[test]
+    fn test_schedule_release_clears_guard() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 2_000);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+
+        env.ledger().set_timestamp(1_000);
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+        client.release_prog_schedule_automatic(&prog_id, &2, &Address::generate(&env));
+
+        assert_eq!(token_client.balance(&winner), 2_000);
+        env.as_contract(&client.address, || {
+            assert!(!env.storage().instance().has(&DataKey::ReentrancyGuard));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Reentrancy detected")]
+    fn test_automatic_schedule_release_blocks_reentry() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 1_000);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+
+        env.ledger().set_timestamp(1_000);
+        simulate_release_in_progress(&env, &client);
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "Reentrancy detected")]
+    fn test_manual_schedule_release_blocks_reentry() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 1_000);
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+
+        simulate_release_in_progress(&env, &client);
+        client.release_program_schedule_manual(&prog_id, &1);
+    }
+
+    // Token whose `transfer` calls back into the escrow to release a schedule
+    // once `arm` has set the target
+    #[contract]
+    pub struct ReentrantToken;
+
+    #[contractimpl]
+    impl ReentrantToken {
+        pub fn arm(env: Env, escrow: Address, program_id: String, schedule_id: u64) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("target"), &(escrow, program_id, schedule_id));
+        }
+
+        pub fn transfer(env: Env, _from: Address, _to: Address, _amount: i128) {
+            let target: Option<(Address, String, u64)> =
+                env.storage().instance().get(&symbol_short!("target"));
+            if let Some((escrow, program_id, schedule_id)) = target {
+                ProgramEscrowContractClient::new(&env, &escrow).release_prog_schedule_automatic(
+                    &program_id,
+                    &schedule_id,
+                    &env.current_contract_address(),
+                );
+            }
+        }
+    }
+
+    // The host refuses to re-enter a contract already on the call stack, so
+    // the nested release fails before the escrow's own guard is consulted
+    #[test]
+    #[should_panic(expected = "Error(Context, InvalidAction)")]
+    fn test_reentrant_token_cannot_release_schedule_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_id = env.register_contract(None, ReentrantToken);
+        let token = ReentrantTokenClient::new(&env, &token_id);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        let winner = Address::generate(&env);
+
+        client.initialize_program(&prog_id, &Address::generate(&env), &token_id);
+        client.lock_program_funds(&prog_id, &Address::generate(&env), &2_000);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+
+        env.ledger().set_timestamp(1_000);
+        token.arm(&contract_id, &prog_id, &2);
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+    }
+
+    // ========================================================================
+    // Fee Accounting Tests
+    // ========================================================================
+
+    #[test]
+    fn test_fees_collected_tracked_per_token() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let fee_recipient = Address::generate(&env);
+        let token_a = create_token_contract(&env, &admin);
+        let token_b = create_token_contract(&env, &admin);
+        let prog_a = String::from_str(&env, "ProgramA");
+        let prog_b = String::from_str(&env, "ProgramB");
+
+        client.set_admin(&admin);
+        client.set_whitelist(&backend, &true);
+        client.initialize_program(&prog_a, &backend, &token_a.address);
+        client.initialize_program(&prog_b, &backend, &token_b.address);
+
+        // 10% payout fee
+        client.update_fee_config(&None, &Some(1_000), &Some(fee_recipient.clone()), &Some(true));
+
+        fund_program(&env, &client, &prog_a, 10_000);
+        fund_program(&env, &client, &prog_b, 10_000);
+
+        client.single_payout(&prog_a, &winner, &1_000);
+        client.single_payout(&prog_b, &winner, &3_000);
+
+        assert_eq!(client.get_fees_collected(&token_a.address), 100);
+        assert_eq!(client.get_fees_collected(&token_b.address), 300);
+        assert_eq!(token_a.balance(&fee_recipient), 100);
+        assert_eq!(token_b.balance(&fee_recipient), 300);
+
+        // Only token B has funds beyond what its program is owed
+        token::StellarAssetClient::new(&env, &token_b.address).mint(&contract_id, &50);
+        assert_eq!(client.sweep_fees(&token_a.address), 0);
+        assert_eq!(client.sweep_fees(&token_b.address), 50);
+        assert_eq!(token_a.balance(&contract_id), 9_000);
+        assert_eq!(token_b.balance(&contract_id), 7_000);
+        assert_eq!(token_b.balance(&fee_recipient), 350);
+    }
+
+    // ========================================================================
+    // Weighted Vesting Tests
+    // ========================================================================
+
+    #[test]
+    fn test_weighted_vesting_back_loaded_curve() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+
+        let timestamps = soroban_sdk::vec![&env, 100u64, 200, 300, 400];
+        let weights = soroban_sdk::vec![&env, 1u32, 2, 3, 4];
+        client.create_weighted_vesting(&prog_id, &recipient, &1_000, &timestamps, &weights, &false);
+
+        let schedules = client.get_all_prog_release_schedules(&prog_id);
+        assert_eq!(schedules.len(), 4);
+        assert_eq!(schedules.get(0).unwrap().amount, 100);
+        assert_eq!(schedules.get(1).unwrap().amount, 200);
+        assert_eq!(schedules.get(2).unwrap().amount, 300);
+        assert_eq!(schedules.get(3).unwrap().amount, 400);
+        assert_eq!(schedules.get(3).unwrap().release_timestamp, 400);
+    }
+
+    #[test]
+    fn test_weighted_vesting_remainder_goes_to_last_period() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+
+        let timestamps = soroban_sdk::vec![&env, 100u64, 200, 300];
+        let weights = soroban_sdk::vec![&env, 1u32, 1, 1];
+        client.create_weighted_vesting(&prog_id, &recipient, &1_000, &timestamps, &weights, &false);
+
+        let schedules = client.get_all_prog_release_schedules(&prog_id);
+        assert_eq!(schedules.get(0).unwrap().amount, 333);
+        assert_eq!(schedules.get(1).unwrap().amount, 333);
+        assert_eq!(schedules.get(2).unwrap().amount, 334);
+    }
+
+    fn count_events(env: &Env, symbol: Symbol) -> u32 {
+        let topics: Vec<Val> = (symbol,).into_val(env);
+        let mut count = 0;
+        for (_, event_topics, _) in env.events().all().iter() {
+            if event_topics == topics {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_weighted_vesting_emits_batch_summary() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+
+        let timestamps = soroban_sdk::vec![&env, 100u64, 200, 300];
+        let weights = soroban_sdk::vec![&env, 1u32, 1, 1];
+        client.create_weighted_vesting(&prog_id, &recipient, &1_000, &timestamps, &weights, &false);
+
+        assert_eq!(count_events(&env, PROG_SCHEDULE_CREATED), 3);
+        assert_eq!(count_events(&env, PROG_SCHEDULES_BATCH), 1);
+
+        let topics: Vec<Val> = (PROG_SCHEDULES_BATCH,).into_val(&env);
+        let (_, _, data) = env
+            .events()
+            .all()
+            .iter()
+            .find(|(_, t, _)| *t == topics)
+            .unwrap();
+        let summary = SchedulesBatchCreated::try_from_val(&env, &data).unwrap();
+        assert_eq!(summary.count, 3);
+        assert_eq!(summary.total_amount, 1_000);
+    }
+
+    #[test]
+    fn test_weighted_vesting_summary_verbosity_skips_per_schedule_events() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.set_admin(&Address::generate(&env));
+
+        assert_eq!(client.get_event_verbosity(), monitoring::VERBOSITY_DETAILED);
+        client.set_event_verbosity(&monitoring::VERBOSITY_SUMMARY);
+
+        let timestamps = soroban_sdk::vec![&env, 100u64, 200, 300];
+        let weights = soroban_sdk::vec![&env, 1u32, 1, 1];
+        client.create_weighted_vesting(&prog_id, &recipient, &1_000, &timestamps, &weights, &false);
+
+        assert_eq!(count_events(&env, PROG_SCHEDULE_CREATED), 0);
+        assert_eq!(count_events(&env, PROG_SCHEDULES_BATCH), 1);
+        assert_eq!(client.get_all_prog_release_schedules(&prog_id).len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Weights must sum to a positive value")]
+    fn test_weighted_vesting_rejects_zero_weights() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+
+        let timestamps = soroban_sdk::vec![&env, 100u64, 200];
+        let weights = soroban_sdk::vec![&env, 0u32, 0];
+        client.create_weighted_vesting(&prog_id, &recipient, &1_000, &timestamps, &weights, &false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #12)")]
+    fn test_weighted_vesting_rejects_overflowing_split() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let total = i128::MAX / 2;
+        let (client, prog_id, _token) = setup_funded_program(&env, total);
+        let recipient = Address::generate(&env);
+
+        let timestamps = soroban_sdk::vec![&env, 100u64, 200];
+        let weights = soroban_sdk::vec![&env, 3u32, 1];
+        client.create_weighted_vesting(&prog_id, &recipient, &total, &timestamps, &weights, &false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #11)")]
+    fn test_weighted_vesting_full_coverage_rejects_residual() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+
+        let timestamps = soroban_sdk::vec![&env, 100u64, 200];
+        let weights = soroban_sdk::vec![&env, 1u32, 1];
+        client.create_weighted_vesting(&prog_id, &recipient, &900, &timestamps, &weights, &true);
+    }
+
+    #[test]
+    fn test_weighted_vesting_full_coverage_accepts_exact_total() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &400, &50, &recipient);
+
+        // The existing 400 plus this 600 covers the whole balance
+        let timestamps = soroban_sdk::vec![&env, 100u64, 200];
+        let weights = soroban_sdk::vec![&env, 1u32, 1];
+        client.create_weighted_vesting(&prog_id, &recipient, &600, &timestamps, &weights, &true);
+
+        let stats = client.get_schedule_stats(&prog_id);
+        assert_eq!(stats.pending_count, 3);
+        assert_eq!(stats.pending_amount, 1_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch exceeds maximum of 100 recipients")]
+    fn test_batch_payout_exceeds_max_recipients() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000_000);
+
+        let mut recipients = Vec::new(&env);
+        let mut amounts = Vec::new(&env);
+        for _ in 0..(MAX_RECIPIENTS + 1) {
+            recipients.push_back(Address::generate(&env));
+            amounts.push_back(1i128);
+        }
+
+        client.batch_payout(&prog_id, &recipients, &amounts, &false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Recipients and amounts vectors must have the same length")]
+    fn test_batch_payout_more_amounts_than_recipients() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000_000);
+
+        let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
+        let amounts = soroban_sdk::vec![&env, 100i128, 200];
+
+        client.batch_payout(&prog_id, &recipients, &amounts, &false);
+    }
+
+    #[test]
+    fn test_get_stats_sums_locked_and_paid_out() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, prog_id, token_client) = setup_funded_program(&env, 10_000);
+
+        let recipient = Address::generate(&env);
+        client.single_payout(&prog_id, &recipient, &4_000);
+
+        let stats = client.get_stats(&token_client.address);
+        assert_eq!(stats.total_locked, 10_000);
+        assert_eq!(stats.total_released, 4_000);
+
+        let other_token = Address::generate(&env);
+        assert_eq!(client.get_stats(&other_token).total_locked, 0);
+    }
+
+    #[test]
+    fn test_get_stats_excludes_reclaimed_funds() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, prog_id, token_client) = setup_funded_program(&env, 10_000);
+
+        let recipient = Address::generate(&env);
+        client.single_payout(&prog_id, &recipient, &4_000);
+        client.reclaim_unspent(&prog_id, &Address::generate(&env));
+
+        let stats = client.get_stats(&token_client.address);
+        assert_eq!(stats.total_locked, 10_000);
+        assert_eq!(stats.total_released, 4_000);
+    }
+
+    #[test]
+    fn test_get_schedules_for_multiple_programs() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, prog_a, token_client) = setup_funded_program(&env, 10_000);
+
+        let prog_b = String::from_str(&env, "Bootcamp2024");
+        client.initialize_program(&prog_b, &Address::generate(&env), &token_client.address);
+        fund_program(&env, &client, &prog_b, 5_000);
+
+        let winner = Address::generate(&env);
+        client.create_program_release_schedule(&prog_a, &1_000, &100, &winner);
+        client.create_program_release_schedule(&prog_a, &2_000, &200, &winner);
+        client.create_program_release_schedule(&prog_b, &500, &300, &winner);
+
+        let unknown = String::from_str(&env, "Unknown");
+        let ids = soroban_sdk::vec![&env, prog_b.clone(), prog_a.clone(), unknown.clone()];
+        let result = client.get_schedules_for(&ids);
+
+        assert_eq!(result.len(), 3);
+        let (id, schedules) = result.get(0).unwrap();
+        assert_eq!(id, prog_b);
+        assert_eq!(schedules.len(), 1);
+        assert_eq!(schedules.get(0).unwrap().amount, 500);
+
+        let (id, schedules) = result.get(1).unwrap();
+        assert_eq!(id, prog_a);
+        assert_eq!(schedules.len(), 2);
+
+        let (id, schedules) = result.get(2).unwrap();
+        assert_eq!(id, unknown);
+        assert!(schedules.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Query exceeds maximum of 20 programs")]
+    fn test_get_schedules_for_rejects_oversized_query() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let mut ids = Vec::new(&env);
+        for _ in 0..(MAX_SCHEDULE_QUERY + 1) {
+            ids.push_back(String::from_str(&env, "P"));
+        }
+        client.get_schedules_for(&ids);
+    }
+
+    #[test]
+    fn test_deposit_cap_allows_locks_within_cap() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = create_token_contract(&env, &Address::generate(&env)).address;
+        let prog_id = String::from_str(&env, "Capped");
+
+        client.initialize_program_with_cap(&prog_id, &backend, &token, &5_000);
+        assert_eq!(client.get_deposit_cap(&prog_id), Some(5_000));
+
+        fund_program(&env, &client, &prog_id, 2_000);
+        fund_program(&env, &client, &prog_id, 3_000);
+
+        let info = client.get_program_info(&prog_id);
+        assert_eq!(info.total_funds, 5_000);
+        assert_eq!(info.remaining_balance, 5_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1)")]
+    fn test_deposit_cap_rejects_lock_beyond_cap() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = create_token_contract(&env, &Address::generate(&env)).address;
+        let prog_id = String::from_str(&env, "Capped");
+
+        client.initialize_program_with_cap(&prog_id, &backend, &token, &5_000);
+        fund_program(&env, &client, &prog_id, 4_000);
+        fund_program(&env, &client, &prog_id, 1_001);
+    }
+
+    #[test]
+    fn test_program_without_cap_is_uncapped() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = create_token_contract(&env, &Address::generate(&env)).address;
+        let prog_id = String::from_str(&env, "Uncapped");
+
+        client.initialize_program(&prog_id, &backend, &token);
+        assert_eq!(client.get_deposit_cap(&prog_id), None);
+
+        fund_program(&env, &client, &prog_id, 10_000_000_000_000);
+        assert_eq!(client.get_program_info(&prog_id).total_funds, 10_000_000_000_000);
+    }
+
+    fn low_balance_events(env: &Env) -> Vec<LowBalance> {
+        let topics: Vec<Val> = (LOW_BALANCE,).into_val(env);
+        let mut found = Vec::new(env);
+        for (_, event_topics, data) in env.events().all().iter() {
+            if event_topics == topics {
+                found.push_back(LowBalance::try_from_val(env, &data).unwrap());
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn test_low_balance_event_emitted_when_payout_crosses_threshold() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 10_000);
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_low_balance_threshold(&prog_id, &5_000);
+        assert_eq!(client.get_low_balance_threshold(&prog_id), Some(5_000));
+
+        // Still at the threshold: no event
+        client.single_payout(&prog_id, &winner, &5_000);
+        assert_eq!(low_balance_events(&env).len(), 0);
+
+        env.ledger().set_timestamp(env.ledger().timestamp() + 120);
+        client.single_payout(&prog_id, &winner, &1_000);
+
+        let events = low_balance_events(&env);
+        assert_eq!(events.len(), 1);
+        let event = events.get(0).unwrap();
+        assert_eq!(event.program_id, prog_id);
+        assert_eq!(event.remaining_balance, 4_000);
+        assert_eq!(event.threshold, 5_000);
+    }
+
+    #[test]
+    fn test_no_low_balance_event_without_threshold() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 10_000);
+        let winner = Address::generate(&env);
+
+        client.single_payout(&prog_id, &winner, &9_000);
+        assert_eq!(client.get_low_balance_threshold(&prog_id), None);
+        assert_eq!(low_balance_events(&env).len(), 0);
+    }
+
+    #[test]
+    fn test_init_programs_registers_batch() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let other_backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog1 = String::from_str(&env, "Program1");
+        let prog2 = String::from_str(&env, "Program2");
+        let prog3 = String::from_str(&env, "Program3");
+
+        let programs = vec![
+            &env,
+            (prog1.clone(), backend.clone(), token.clone()),
+            (prog2.clone(), backend.clone(), token.clone()),
+            (prog3.clone(), other_backend.clone(), token.clone()),
+        ];
+        let registered = client.init_programs(&programs);
+
+        assert_eq!(registered.len(), 3);
+        assert_eq!(registered.get(2).unwrap().authorized_payout_key, other_backend);
+        assert_eq!(client.get_program_count(), 3);
+        assert_eq!(client.get_program_info(&prog2).authorized_payout_key, backend);
+        assert!(client.program_exists(&prog1));
+        assert!(client.program_exists(&prog3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Program already exists")]
+    fn test_init_programs_rejects_existing_id() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let other_backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let existing = String::from_str(&env, "Existing");
+        client.initialize_program(&existing, &backend, &token);
+
+        let programs = vec![
+            &env,
+            (String::from_str(&env, "Fresh"), other_backend.clone(), token.clone()),
+            (existing, other_backend, token),
+        ];
+        client.init_programs(&programs);
+    }
+
+    #[test]
+    #[should_panic(expected = "Program already exists")]
+    fn test_init_programs_rejects_duplicate_within_batch() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Twice");
+
+        let programs = vec![
+            &env,
+            (prog_id.clone(), backend.clone(), token.clone()),
+            (prog_id, backend, token),
+        ];
+        client.init_programs(&programs);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #2)")]
+    fn test_lock_program_funds_overflow_is_typed_error() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = create_token_contract(&env, &Address::generate(&env)).address;
+        let prog_id = String::from_str(&env, "Overflow");
+
+        client.initialize_program(&prog_id, &backend, &token);
+        fund_program(&env, &client, &prog_id, i128::MAX - 1);
+        assert_eq!(client.get_program_info(&prog_id).total_funds, i128::MAX - 1);
+
+        fund_program(&env, &client, &prog_id, i128::MAX - 1);
+    }
+
+    #[test]
+    fn test_batch_payout_allows_duplicates_without_dedup() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 10_000);
+        let winner = Address::generate(&env);
+        let recipients = vec![&env, winner.clone(), winner.clone()];
+        let amounts = vec![&env, 1_000i128, 2_000i128];
+
+        let data = client.batch_payout(&prog_id, &recipients, &amounts, &false);
+
+        assert_eq!(data.remaining_balance, 7_000);
+        assert_eq!(token_client.balance(&winner), 3_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #3)")]
+    fn test_batch_payout_dedup_rejects_duplicates() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 10_000);
+        let winner = Address::generate(&env);
+        let other = Address::generate(&env);
+        let recipients = vec![&env, winner.clone(), other, winner];
+        let amounts = vec![&env, 1_000i128, 1_000i128, 2_000i128];
+
+        client.batch_payout(&prog_id, &recipients, &amounts, &true);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_paused_contract_blocks_ready_schedule() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 1_000);
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+        client.set_admin(&admin);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+
+        env.ledger().set_timestamp(1_000);
+        client.pause();
+        assert!(client.is_paused());
+
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_unpaused_contract_releases_ready_schedule() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 1_000);
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+        client.set_admin(&admin);
+        client.create_program_release_schedule(&prog_id, &1_000, &1_000, &winner);
+
+        env.ledger().set_timestamp(1_000);
+        client.pause();
+        client.unpause();
+        assert!(!client.is_paused());
+
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+        assert_eq!(token_client.balance(&winner), 1_000);
+    }
+
+    // ========================================================================
+    // Minimum Schedule Interval Tests
+    // ========================================================================
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")]
+    fn test_schedule_too_close_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 1_000);
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_min_schedule_interval(&100);
+        assert_eq!(client.get_min_schedule_interval(), 100);
+
+        client.create_program_release_schedule(&prog_id, &500, &1_000, &winner);
+        client.create_program_release_schedule(&prog_id, &500, &1_099, &winner);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")]
+    fn test_weighted_vesting_periods_too_close_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 1_000);
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_min_schedule_interval(&100);
+
+        let timestamps = Vec::from_array(&env, [1_000u64, 1_050]);
+        let weights = Vec::from_array(&env, [1u32, 1]);
+        client.create_weighted_vesting(&prog_id, &winner, &1_000, &timestamps, &weights, &false);
+    }
+
+    #[test]
+    fn test_adequately_spaced_schedules_accepted() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _) = setup_funded_program(&env, 1_000);
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_min_schedule_interval(&100);
+
+        client.create_program_release_schedule(&prog_id, &500, &1_000, &winner);
+        client.create_program_release_schedule(&prog_id, &500, &1_100, &winner);
+
+        assert_eq!(client.get_all_prog_release_schedules(&prog_id).len(), 2);
+    }
+
+    // ========================================================================
+    // Analytics Tests
+    // ========================================================================
+
+    #[test]
+    fn test_analytics_counts_unique_users() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &Address::generate(&env));
+        let backend_a = Address::generate(&env);
+        let backend_b = Address::generate(&env);
+
+        client.initialize_program(&String::from_str(&env, "ProgA"), &backend_a, &token_client.address);
+        client.initialize_program(&String::from_str(&env, "ProgB"), &backend_b, &token_client.address);
+        client.initialize_program(&String::from_str(&env, "ProgC"), &backend_a, &token_client.address);
+
+        let analytics = client.get_analytics();
+        assert_eq!(analytics.operation_count, 3);
+        assert_eq!(analytics.unique_users, 2);
+    }
+
+    // ========================================================================
+    // Schedule Modification Tests
+    // ========================================================================
+
+    #[test]
+    fn test_modify_schedule_pushes_back_timestamp() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &400, &1_000, &recipient);
+
+        let updated = client.modify_program_schedule(&prog_id, &1, &None, &Some(5_000));
+        assert_eq!(updated.schedule_id, 1);
+        assert_eq!(updated.amount, 400);
+        assert_eq!(updated.release_timestamp, 5_000);
+        assert_eq!(client.get_program_release_schedule(&prog_id, &1).release_timestamp, 5_000);
+
+        let log = client.get_schedule_modifications(&prog_id);
+        assert_eq!(log.len(), 1);
+        let record = log.get(0).unwrap();
+        assert_eq!(record.old_timestamp, 1_000);
+        assert_eq!(record.new_timestamp, 5_000);
+        assert_eq!(record.old_amount, 400);
+        assert_eq!(record.new_amount, 400);
+
+        // Not releasable at the original time any more
+        env.ledger().set_timestamp(1_001);
+        let keeper = Address::generate(&env);
+        assert!(client.try_release_prog_schedule_automatic(&prog_id, &1, &keeper).is_err());
+    }
+
+    #[test]
+    fn test_modify_schedule_amount_up_to_remaining() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &400, &1_000, &recipient);
+        client.create_program_release_schedule(&prog_id, &400, &2_000, &recipient);
+
+        // 600 + 400 fits the 1_000 balance exactly
+        client.modify_program_schedule(&prog_id, &1, &Some(600), &None);
+        assert_eq!(client.get_program_release_schedule(&prog_id, &1).amount, 600);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #7)")]
+    fn test_modify_schedule_amount_beyond_remaining_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &400, &1_000, &recipient);
+        client.create_program_release_schedule(&prog_id, &400, &2_000, &recipient);
+
+        client.modify_program_schedule(&prog_id, &1, &Some(601), &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #6)")]
+    fn test_modify_released_schedule_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &400, &1_000, &recipient);
+
+        env.ledger().set_timestamp(1_001);
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+
+        client.modify_program_schedule(&prog_id, &1, &Some(100), &None);
+    }
+
+    // ========================================================================
+    // Keeper Query Tests
+    // ========================================================================
+
+    #[test]
+    fn test_get_all_ready_schedules_across_programs() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_a, token_client) = setup_funded_program(&env, 1_000);
+        let prog_b = String::from_str(&env, "Bounty2024");
+        client.initialize_program(&prog_b, &Address::generate(&env), &token_client.address);
+        fund_program(&env, &client, &prog_b, 1_000);
+
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_a, &100, &100, &recipient);
+        client.create_program_release_schedule(&prog_a, &100, &500, &recipient);
+        client.create_program_release_schedule(&prog_b, &100, &200, &recipient);
+        client.create_program_release_schedule(&prog_b, &100, &900, &recipient);
+
+        env.ledger().set_timestamp(300);
+        let ready = client.get_all_ready_schedules(&0, &10);
+        assert_eq!(ready.len(), 2);
+        assert_eq!(ready.get(0).unwrap(), (prog_a.clone(), 1));
+        assert_eq!(ready.get(1).unwrap(), (prog_b.clone(), 1));
+
+        // Pages walk the registry one program at a time
+        let second_page = client.get_all_ready_schedules(&1, &1);
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page.get(0).unwrap(), (prog_b.clone(), 1));
+        assert_eq!(client.get_all_ready_schedules(&2, &1).len(), 0);
+
+        // Released schedules drop out of the list
+        client.release_prog_schedule_automatic(&prog_a, &1, &Address::generate(&env));
+        let ready = client.get_all_ready_schedules(&0, &10);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready.get(0).unwrap(), (prog_b, 1));
+    }
+
+    // ========================================================================
+    // Reserve Tests
+    // ========================================================================
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #8)")]
+    fn test_payout_blocked_by_reserve() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        client.set_admin(&Address::generate(&env));
+        client.set_reserve(&prog_id, &300);
+        assert_eq!(client.get_reserve(&prog_id), 300);
+
+        let winner = Address::generate(&env);
+        client.single_payout(&prog_id, &winner, &701);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #8)")]
+    fn test_batch_payout_blocked_by_reserve() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        client.set_admin(&Address::generate(&env));
+        client.set_reserve(&prog_id, &300);
+
+        let recipients = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
+        let amounts = soroban_sdk::vec![&env, 400i128, 301];
+        client.batch_payout(&prog_id, &recipients, &amounts, &false);
+    }
+
+    #[test]
+    fn test_release_reserve_allows_full_payout() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 1_000);
+        client.set_admin(&Address::generate(&env));
+        client.set_reserve(&prog_id, &300);
+
+        // Payouts down to the reserve are still allowed
+        let winner = Address::generate(&env);
+        client.single_payout(&prog_id, &winner, &700);
+        assert_eq!(client.get_remaining_balance(&prog_id), 300);
+
+        client.release_reserve(&prog_id);
+        assert_eq!(client.get_reserve(&prog_id), 0);
+
+        env.ledger().set_timestamp(120);
+        client.single_payout(&prog_id, &winner, &300);
+        assert_eq!(client.get_remaining_balance(&prog_id), 0);
+        assert_eq!(token_client.balance(&winner), 1_000);
+    }
+
+    // ========================================================================
+    // Ledger-Based Schedule Tests
+    // ========================================================================
+
+    #[test]
+    fn test_ledger_schedule_releases_only_after_target_sequence() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_sequence_number(100);
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_prog_ledger_schedule(&prog_id, &400, &150, &recipient);
+
+        let schedule = client.get_program_release_schedule(&prog_id, &1);
+        assert_eq!(schedule.release_ledger, Some(150));
+
+        // Time passing alone does not make a block-based schedule due
+        env.ledger().set_timestamp(1_000_000);
+        env.ledger().set_sequence_number(149);
+        assert_eq!(client.get_due_program_schedules(&prog_id).len(), 0);
+        let keeper = Address::generate(&env);
+        assert!(client.try_release_prog_schedule_automatic(&prog_id, &1, &keeper).is_err());
+
+        env.ledger().set_sequence_number(150);
+        assert_eq!(client.get_due_program_schedules(&prog_id).len(), 1);
+        client.release_prog_schedule_automatic(&prog_id, &1, &keeper);
+
+        assert!(client.get_program_release_schedule(&prog_id, &1).released);
+        assert_eq!(token_client.balance(&recipient), 400);
+    }
+
+    #[test]
+    #[should_panic(expected = "Release ledger must be in the future")]
+    fn test_ledger_schedule_rejects_past_sequence() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_sequence_number(100);
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_prog_ledger_schedule(&prog_id, &400, &100, &recipient);
+    }
+
+    // ========================================================================
+    // Payout End-to-End Tests
+    // ========================================================================
+
+    #[test]
+    fn test_single_payout_end_to_end() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 1_000);
+        let winner = Address::generate(&env);
+
+        let data = client.single_payout(&prog_id, &winner, &250);
+        assert_eq!(data.remaining_balance, 750);
+        assert_eq!(data.payout_history.len(), 1);
+        assert_eq!(data.payout_history.get(0).unwrap().recipient, winner);
+        assert_eq!(data.payout_history.get(0).unwrap().amount, 250);
+
+        assert_eq!(token_client.balance(&winner), 250);
+        assert_eq!(client.get_remaining_balance(&prog_id), 750);
+        assert_eq!(client.get_program_info(&prog_id).payout_history.len(), 1);
+        assert_eq!(client.get_performance_stats(&symbol_short!("payout")).call_count, 1);
+    }
+
+    #[test]
+    fn test_batch_payout_end_to_end() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 1_000);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let recipients = soroban_sdk::vec![&env, first.clone(), second.clone()];
+        let amounts = soroban_sdk::vec![&env, 300i128, 200];
+
+        let data = client.batch_payout(&prog_id, &recipients, &amounts, &true);
+        assert_eq!(data.remaining_balance, 500);
+        assert_eq!(data.payout_history.len(), 2);
+        assert_eq!(data.payout_history.get(0).unwrap().recipient, first);
+        assert_eq!(data.payout_history.get(1).unwrap().amount, 200);
+
+        assert_eq!(token_client.balance(&first), 300);
+        assert_eq!(token_client.balance(&second), 200);
+        assert_eq!(token_client.balance(&client.address), 500);
+        assert_eq!(client.get_program_info(&prog_id).remaining_balance, 500);
+        assert_eq!(client.get_performance_stats(&symbol_short!("batch_pay")).call_count, 1);
+    }
+
+    #[test]
+    fn test_schedule_stats_mixed_states() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &100, &100, &recipient);
+        client.create_program_release_schedule(&prog_id, &200, &200, &recipient);
+        client.create_program_release_schedule(&prog_id, &300, &900, &recipient);
+
+        env.ledger().set_timestamp(250);
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+        client.release_prog_schedule_automatic(&prog_id, &2, &Address::generate(&env));
+
+        let stats = client.get_schedule_stats(&prog_id);
+        assert_eq!(stats.released_count, 2);
+        assert_eq!(stats.released_amount, 300);
+        assert_eq!(stats.pending_count, 1);
+        assert_eq!(stats.pending_amount, 300);
+    }
+
+    #[test]
+    fn test_schedule_progress_partially_released() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        assert_eq!(client.get_schedule_progress(&prog_id), (0, 0, 0, 0));
+
+        client.create_program_release_schedule(&prog_id, &100, &100, &recipient);
+        client.create_program_release_schedule(&prog_id, &250, &200, &recipient);
+        client.create_program_release_schedule(&prog_id, &400, &900, &recipient);
+
+        env.ledger().set_timestamp(150);
+        client.release_prog_schedule_automatic(&prog_id, &1, &Address::generate(&env));
+
+        assert_eq!(client.get_schedule_progress(&prog_id), (100, 750, 1, 3));
+        assert_eq!(
+            client.try_get_schedule_progress(&String::from_str(&env, "missing")),
+            Err(Ok(Error::ProgramNotFound))
+        );
+    }
+
+    // ========================================================================
+    // Reclaim Tests
+    // ========================================================================
+
+    #[test]
+    fn test_reclaim_unspent_returns_full_balance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 1_000);
+        let organizer = Address::generate(&env);
+
+        let data = client.reclaim_unspent(&prog_id, &organizer);
+        assert_eq!(data.remaining_balance, 0);
+        assert_eq!(data.payout_history.len(), 0);
+        assert_eq!(client.get_reclaims(&prog_id).get(0).unwrap().amount, 1_000);
+        assert_eq!(token_client.balance(&organizer), 1_000);
+        assert_eq!(token_client.balance(&client.address), 0);
+
+        let result = client.try_reclaim_unspent(&prog_id, &organizer);
+        assert_eq!(result, Err(Ok(Error::NothingToReclaim)));
+    }
+
+    #[test]
+    fn test_reclaim_unspent_after_payouts_returns_remainder() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 1_000);
+        let winner = Address::generate(&env);
+        let organizer = Address::generate(&env);
+        client.single_payout(&prog_id, &winner, &300);
+        client.single_payout(&prog_id, &winner, &150);
+
+        let data = client.reclaim_unspent(&prog_id, &organizer);
+        assert_eq!(data.remaining_balance, 0);
+        assert_eq!(data.payout_history.len(), 2);
+        assert_eq!(client.get_reclaims(&prog_id).get(0).unwrap().amount, 550);
+        assert_eq!(token_client.balance(&winner), 450);
+        assert_eq!(token_client.balance(&organizer), 550);
+        assert_eq!(token_client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_reclaim_unspent_leaves_pending_schedules_funded() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, token_client) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        let organizer = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &400, &100, &recipient);
+
+        let data = client.reclaim_unspent(&prog_id, &organizer);
+        assert_eq!(data.remaining_balance, 400);
+        assert_eq!(token_client.balance(&organizer), 600);
+        assert_eq!(
+            client.try_reclaim_unspent(&prog_id, &organizer),
+            Err(Ok(Error::NothingToReclaim))
+        );
+
+        env.ledger().set_timestamp(100);
+        client.release_prog_schedule_automatic(&prog_id, &1, &recipient);
+        assert_eq!(token_client.balance(&recipient), 400);
+        assert_eq!(client.get_program_info(&prog_id).remaining_balance, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient balance for scheduled amount")]
+    fn test_schedule_release_rejects_underfunded_program() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &600, &100, &recipient);
+        client.single_payout(&prog_id, &Address::generate(&env), &500);
+
+        client.release_program_schedule_manual(&prog_id, &1);
+    }
+
+    #[test]
+    fn test_reclaim_unspent_rejected_while_paused() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        client.set_admin(&Address::generate(&env));
+        client.pause();
+
+        let result = client.try_reclaim_unspent(&prog_id, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(Error::ContractPaused)));
+        assert_eq!(client.get_program_info(&prog_id).remaining_balance, 1_000);
+    }
+
+    // ========================================================================
+    // Release History Archival Tests
+    // ========================================================================
+
+    #[test]
+    fn test_release_history_archives_beyond_retention() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, prog_id, _token) = setup_funded_program(&env, 1_000);
+        client.set_admin(&Address::generate(&env));
+        client.set_history_retention(&2);
+
+        let recipient = Address::generate(&env);
+        client.create_program_release_schedule(&prog_id, &100, &100, &recipient);
+        client.create_program_release_schedule(&prog_id, &200, &200, &recipient);
+        client.create_program_release_schedule(&prog_id, &300, &300, &recipient);
+
+        env.ledger().set_timestamp(300);
+        for schedule_id in 1..=3u64 {
+            client.release_prog_schedule_automatic(&prog_id, &schedule_id, &recipient);
+        }
+
+        let inline = client.get_program_release_history(&prog_id);
+        assert_eq!(inline.len(), 2);
+        assert_eq!(inline.get(0).unwrap().schedule_id, 2);
+        assert_eq!(inline.get(1).unwrap().schedule_id, 3);
+
+        assert_eq!(client.get_archived_history_count(&prog_id), 1);
+        let archived = client.get_archived_schedule_history(&prog_id, &0, &10);
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived.get(0).unwrap().schedule_id, 1);
+        assert_eq!(archived.get(0).unwrap().amount, 100);
+    }
 }